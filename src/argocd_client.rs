@@ -1,23 +1,85 @@
 use crate::models::{
-    Application, ApplicationDetailOutput, ApplicationHistorySummary, ApplicationList,
+    changed_fields, redact_url_credentials, AnalyzedLogEntry, AppProject, AppProjectList,
+    AppProjectSpec, Application, ApplicationCreateSummary, ApplicationDeleteSummary,
+    ApplicationDetailOutput, ApplicationHistorySummary, ApplicationList,
     ApplicationResourceResponse, ApplicationResourceSummary, ApplicationRollbackSummary,
-    ApplicationServerSideDiffResponse, ApplicationSummaryOutput, ApplicationSyncSummary,
-    ApplicationSyncWindowsResponse, ApplicationSyncWindowsSummary, ApplicationTree, EventList,
-    EventListSummary, LogEntry, ManifestResponse, ManifestSummary, PodLogsSummary,
-    RefreshApplicationSummary, ResourceTreeSummary, RetryStrategy, RevisionHistorySummary,
-    RevisionMetadata, RevisionMetadataSummary, ServerSideDiffSummary, SyncResource, SyncStrategy,
-    SyncStrategyApply, SyncStrategyHook,
+    ApplicationServerSideDiffResponse, ApplicationSet, ApplicationSetDeleteSummary,
+    ApplicationSetDetail, ApplicationSetGenerateResponse, ApplicationSetList,
+    ApplicationSetPreviewSummary, ApplicationSetSummary, ApplicationSpec, ApplicationSpecSummary,
+    ApplicationSpecUpdateSummary, ApplicationSummaryOutput, ApplicationSyncSummary,
+    ApplicationSyncWindowsResponse, ApplicationSyncWindowsSummary, ApplicationTree,
+    ApplicationWaitSummary, ApplicationWatchEvent, Cluster, ClusterActionSummary, ClusterDetail,
+    ClusterList, ClusterSummary, EventList, EventListSummary, HelmChartSummary, HelmChartsResponse,
+    JwtToken, LogBatch, LogEntry, LogFollowOptions, LogFollowStop, LogFollowSummary,
+    ManifestResponse, ManifestSummary, ObjectMeta, PodLogsQuery, PodLogsSummary, ProjectDetail,
+    ProjectRole, ProjectRoleChangeSummary, ProjectSummary, ProjectTokenSummary,
+    ProjectUpdateSummary, RefreshApplicationSummary, ReplicaLogs, RepoAccessQuery, RepoAppInfo,
+    RepoAppsResponse, RepoCredsList, RepoCredsSummary, RepoRefs, RepoRefsSummary, Repository,
+    RepositoryList, RepositoryListSummary, RepositorySummary, RepositoryValidationSummary,
+    ResourceActionRunSummary, ResourceActionsListResponse, ResourceActionsSummary,
+    ResourceDeleteSummary, ResourceNode, ResourceNodeSummary, ResourceTreeSummary, RetryStrategy,
+    RevisionHistorySummary, RevisionMetadata, RevisionMetadataSummary, ServerSideDiffSummary,
+    SyncResource, SyncStrategy, SyncStrategyApply, SyncStrategyHook, TerminateOperationSummary,
+    VersionMessage, WaitConditions, WaitOutcome, WaitProgress,
 };
+use crate::auth::{
+    jwt_claims, CredentialProvider, Credentials, ExecToken, SessionToken, StaticToken, TokenFile,
+};
+use crate::error::ArgocdError;
+use crate::retry::{
+    parse_retry_after, record_retry, AttemptFailure, RetryPolicy, RetryRecord, RetrySafety,
+};
+use crate::request::{
+    application_path, applicationset_path, cluster_path, next_request_id, project_path,
+    repository_path, ApiRequest, RequestBody, RequestHook, RequestInfo, ResponseInfo,
+    REQUEST_ID_HEADER,
+};
+use crate::watch::WatchStream;
 use anyhow::{Context, Result};
-use reqwest::Client;
-use serde::Deserialize;
+use futures::StreamExt;
+use reqwest::{Certificate, Client, Identity, Method, NoProxy, Proxy, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
 
 /// ArgoCD API client with robust error handling
 #[derive(Clone)]
 pub struct ArgocdClient {
+    /// Server URL including the API root path
     base_url: String,
-    access_token: String,
+    auth: Auth,
     client: Client,
+    retry: RetryPolicy,
+    hooks: Vec<Arc<dyn RequestHook>>,
+}
+
+/// Credentials as used at request time
+#[derive(Clone)]
+enum Auth {
+    /// Token supplied by a credential provider
+    Provider(Arc<dyn CredentialProvider>),
+    /// Session token obtained by logging in, shared by clones of the client
+    Session {
+        username: String,
+        password: String,
+        session: Arc<tokio::sync::Mutex<Option<SessionToken>>>,
+    },
+}
+
+impl Auth {
+    fn describe(&self) -> String {
+        match self {
+            Self::Provider(provider) => provider.describe(),
+            Self::Session { username, .. } => format!("session login as '{}'", username),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SessionResponse {
+    token: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -26,134 +88,663 @@ struct ErrorResponse {
     error: String,
     #[serde(default)]
     message: String,
+    /// gRPC status code
+    #[serde(default)]
+    code: Option<i32>,
 }
 
-impl ArgocdClient {
-    /// Create a new ArgoCD client
-    pub fn new(base_url: String, access_token: String) -> Result<Self> {
-        // Validate inputs
-        if base_url.is_empty() {
-            anyhow::bail!("base_url cannot be empty");
+/// Raw response returned by the request executor
+#[derive(Debug)]
+struct ApiResponse {
+    status: StatusCode,
+    elapsed: Duration,
+    retry_after: Option<Duration>,
+    body: String,
+}
+
+/// Turn a non-success response into the error reported to callers
+fn decode_error(status: StatusCode, body: String) -> ArgocdError {
+    let (grpc_code, error_msg) = match serde_json::from_str::<ErrorResponse>(&body) {
+        Ok(error_response) if !error_response.message.is_empty() => {
+            (error_response.code, error_response.message)
         }
-        if access_token.is_empty() {
-            anyhow::bail!("access_token cannot be empty");
+        Ok(error_response) if !error_response.error.is_empty() => {
+            (error_response.code, error_response.error)
+        }
+        Ok(error_response) => (error_response.code, body),
+        Err(_) => (None, body),
+    };
+
+    // Git errors echo the repository URL, credentials included
+    let error = ArgocdError::from_response(status, grpc_code, redact_url_credentials(&error_msg));
+    tracing::error!(kind = error.kind(), "{}", error);
+    error
+}
+
+/// Default timeout for ArgoCD API requests
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// Upper bound on how long a watch stream stays open before it is reconnected
+pub const STREAM_TIMEOUT: Duration = Duration::from_secs(3600);
+
+/// Most pods of a workload whose logs are fetched (`workload_logs`)
+pub const MAX_WORKLOAD_PODS: usize = 20;
+
+/// Pod log requests sent at once when fetching a workload's logs
+const WORKLOAD_LOG_CONCURRENCY: usize = 5;
+
+/// HTTP client settings for an ArgoCD connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientOptions {
+    /// Request timeout
+    pub timeout: Duration,
+    /// Skip TLS certificate verification
+    pub insecure: bool,
+    /// PEM bundle of CA certificates trusted in addition to the system roots
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate for mutual TLS (requires `client_key`)
+    pub client_cert: Option<PathBuf>,
+    /// PEM PKCS#8 private key for `client_cert`
+    pub client_key: Option<PathBuf>,
+    /// Proxy URL for all requests; when unset the standard HTTP_PROXY,
+    /// HTTPS_PROXY and NO_PROXY variables apply
+    pub proxy: Option<String>,
+    /// Comma-separated hosts, domains and CIDRs that bypass `proxy`
+    pub no_proxy: Option<String>,
+    /// Path ArgoCD is served under (its `--rootpath`), e.g. `/argocd`
+    pub root_path: Option<String>,
+    /// Retry behaviour for transient failures
+    pub retry: RetryPolicy,
+}
+
+impl Default for ClientOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            insecure: false,
+            ca_cert: None,
+            client_cert: None,
+            client_key: None,
+            proxy: None,
+            no_proxy: None,
+            root_path: None,
+            retry: RetryPolicy::default(),
         }
+    }
+}
 
+impl ClientOptions {
+    /// Default options with ARGOCD_INSECURE and the TLS/proxy variables applied
+    pub fn from_env() -> Self {
         // Check if we should skip TLS verification (useful for self-signed certs)
         let insecure_env = std::env::var("ARGOCD_INSECURE").unwrap_or_else(|_| "false".to_string());
-        let skip_tls_verify = insecure_env.to_lowercase() == "true";
-
         tracing::info!("ARGOCD_INSECURE environment variable: {:?}", insecure_env);
-        tracing::info!("Skip TLS verification: {}", skip_tls_verify);
 
-        let mut client_builder = Client::builder().timeout(std::time::Duration::from_secs(30));
+        let var = |name: &str| std::env::var(name).ok().filter(|v| !v.trim().is_empty());
+        Self {
+            insecure: insecure_env.to_lowercase() == "true",
+            ca_cert: var("ARGOCD_CA_CERT").map(PathBuf::from),
+            client_cert: var("ARGOCD_CLIENT_CERT").map(PathBuf::from),
+            client_key: var("ARGOCD_CLIENT_KEY").map(PathBuf::from),
+            proxy: var("ARGOCD_PROXY"),
+            no_proxy: var("ARGOCD_NO_PROXY"),
+            root_path: var("ARGOCD_ROOT_PATH"),
+            ..Self::default()
+        }
+    }
+
+    /// Build the HTTP client these options describe
+    fn http_client(&self) -> Result<Client> {
+        tracing::info!("Skip TLS verification: {}", self.insecure);
 
-        if skip_tls_verify {
-            tracing::warn!("TLS certificate verification is DISABLED (ARGOCD_INSECURE=true)");
+        let mut client_builder = Client::builder().timeout(self.timeout);
+
+        if self.insecure {
+            tracing::warn!("TLS certificate verification is DISABLED");
             client_builder = client_builder.danger_accept_invalid_certs(true);
         } else {
             tracing::info!("TLS certificate verification is ENABLED");
         }
 
-        let client = client_builder
+        if let Some(path) = &self.ca_cert {
+            let pem = std::fs::read(path)
+                .with_context(|| format!("Failed to read CA bundle {}", path.display()))?;
+            let certificates = Certificate::from_pem_bundle(&pem)
+                .with_context(|| format!("Invalid CA bundle {}", path.display()))?;
+            if certificates.is_empty() {
+                anyhow::bail!("CA bundle {} contains no certificates", path.display());
+            }
+            tracing::info!(
+                "Trusting {} CA certificate(s) from {}",
+                certificates.len(),
+                path.display()
+            );
+            for certificate in certificates {
+                client_builder = client_builder.add_root_certificate(certificate);
+            }
+        }
+
+        match (&self.client_cert, &self.client_key) {
+            (Some(cert_path), Some(key_path)) => {
+                let cert = std::fs::read(cert_path).with_context(|| {
+                    format!("Failed to read client certificate {}", cert_path.display())
+                })?;
+                let key = std::fs::read(key_path)
+                    .with_context(|| format!("Failed to read client key {}", key_path.display()))?;
+                let identity = Identity::from_pkcs8_pem(&cert, &key).with_context(|| {
+                    format!(
+                        "Invalid client certificate {} or key {} (the key must be PKCS#8 PEM)",
+                        cert_path.display(),
+                        key_path.display()
+                    )
+                })?;
+                tracing::info!("Presenting client certificate {}", cert_path.display());
+                client_builder = client_builder.identity(identity);
+            }
+            (None, None) => {}
+            _ => anyhow::bail!("client_cert and client_key must be set together"),
+        }
+
+        if let Some(url) = &self.proxy {
+            let proxy = Proxy::all(url)
+                .with_context(|| format!("Invalid proxy URL '{}'", url))?
+                .no_proxy(self.no_proxy.as_deref().and_then(NoProxy::from_string));
+            tracing::info!("Using proxy {}", url);
+            client_builder = client_builder.proxy(proxy);
+        }
+
+        client_builder
             .build()
-            .context("Failed to create HTTP client")?;
+            .context("Failed to create HTTP client")
+    }
+}
+
+/// Normalize an API root path to `/segment[/segment...]`, or `None` for the server root
+pub fn normalize_root_path(root_path: &str) -> Option<String> {
+    let trimmed = root_path.trim().trim_matches('/');
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(format!("/{}", trimmed))
+    }
+}
+
+impl ArgocdClient {
+    /// Create a new ArgoCD client
+    /// Reads ARGOCD_INSECURE environment variable for TLS verification
+    pub fn new(base_url: String, access_token: String) -> Result<Self> {
+        Self::with_options(base_url, access_token, ClientOptions::from_env())
+    }
+
+    /// Create a new ArgoCD client with explicit HTTP client settings
+    pub fn with_options(
+        base_url: String,
+        access_token: String,
+        options: ClientOptions,
+    ) -> Result<Self> {
+        Self::with_credentials(base_url, Credentials::Token(access_token), options)
+    }
+
+    /// Create a new ArgoCD client authenticating with the given credentials
+    ///
+    /// With `Credentials::Login` no request is made until the first API call,
+    /// which logs in and caches the session token. The token is renewed
+    /// shortly before its JWT `exp`, or when ArgoCD answers 401.
+    pub fn with_credentials(
+        base_url: String,
+        credentials: Credentials,
+        options: ClientOptions,
+    ) -> Result<Self> {
+        let auth = match credentials {
+            Credentials::Token(token) => {
+                if token.is_empty() {
+                    anyhow::bail!("access_token cannot be empty");
+                }
+                Auth::Provider(Arc::new(StaticToken::new(token)))
+            }
+            Credentials::TokenFile(path) => {
+                if path.as_os_str().is_empty() {
+                    anyhow::bail!("token file path cannot be empty");
+                }
+                Auth::Provider(Arc::new(TokenFile::new(path)))
+            }
+            Credentials::Exec(exec) => {
+                if exec.command.is_empty() {
+                    anyhow::bail!("token command cannot be empty");
+                }
+                Auth::Provider(Arc::new(ExecToken::new(exec)))
+            }
+            Credentials::Login { username, password } => {
+                if username.is_empty() || password.is_empty() {
+                    anyhow::bail!("username and password cannot be empty");
+                }
+                Auth::Session {
+                    username,
+                    password,
+                    session: Arc::new(tokio::sync::Mutex::new(None)),
+                }
+            }
+        };
+        Self::build(base_url, auth, options)
+    }
+
+    /// Create a new ArgoCD client taking tokens from a custom credential provider
+    #[allow(dead_code)]
+    pub fn with_provider(
+        base_url: String,
+        provider: Arc<dyn CredentialProvider>,
+        options: ClientOptions,
+    ) -> Result<Self> {
+        Self::build(base_url, Auth::Provider(provider), options)
+    }
+
+    fn build(base_url: String, auth: Auth, options: ClientOptions) -> Result<Self> {
+        // Validate inputs
+        if base_url.is_empty() {
+            anyhow::bail!("base_url cannot be empty");
+        }
+
+        tracing::info!("Authentication: {}", auth.describe());
+        let client = options.http_client()?;
+
+        let mut base_url = base_url.trim_end_matches('/').to_string();
+        if let Some(root_path) = options.root_path.as_deref().and_then(normalize_root_path) {
+            base_url.push_str(&root_path);
+        }
 
         Ok(Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
-            access_token,
+            base_url,
+            auth,
             client,
+            retry: options.retry,
+            hooks: Vec::new(),
         })
     }
 
-    /// List applications with optional filters
-    /// Returns optimized summaries to save context window
-    pub async fn list_applications(
-        &self,
-        name: Option<String>,
-        projects: Option<Vec<String>>,
-        selector: Option<String>,
-        repo: Option<String>,
-        app_namespace: Option<String>,
-    ) -> Result<Vec<ApplicationSummaryOutput>> {
-        let mut url = format!("{}/api/v1/applications", self.base_url);
-        let mut params = Vec::new();
+    /// Base URL of the ArgoCD server this client talks to, including the root path
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
 
-        if let Some(n) = name {
-            params.push(format!("name={}", urlencoding::encode(&n)));
-        }
-        if let Some(projs) = projects {
-            for proj in projs {
-                params.push(format!("projects={}", urlencoding::encode(&proj)));
-            }
-        }
-        if let Some(sel) = selector {
-            params.push(format!("selector={}", urlencoding::encode(&sel)));
+    /// Bearer token for the next request, logging in first if needed
+    async fn bearer_token(&self) -> std::result::Result<String, ArgocdError> {
+        let (username, password, session) = match &self.auth {
+            Auth::Provider(provider) => return Ok(provider.token().await?.token),
+            Auth::Session {
+                username,
+                password,
+                session,
+            } => (username, password, session),
+        };
+
+        // Holding the lock while logging in makes concurrent callers share one login
+        let mut cached = session.lock().await;
+        if let Some(token) = cached.as_ref().filter(|t| t.is_fresh()) {
+            return Ok(token.token.clone());
         }
-        if let Some(r) = repo {
-            params.push(format!("repo={}", urlencoding::encode(&r)));
+
+        let token = self.login(username, password).await?;
+        let bearer = token.token.clone();
+        *cached = Some(token);
+        Ok(bearer)
+    }
+
+    /// Exchange username and password for a session token
+    async fn login(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> std::result::Result<SessionToken, ArgocdError> {
+        tracing::info!("Logging in to ArgoCD as '{}'", username);
+        let request = ApiRequest::post("create_session", "/api/v1/session").json(
+            &serde_json::json!({
+                "username": username,
+                "password": password,
+            }),
+        );
+
+        let response = self
+            .send(&request, &next_request_id(), None)
+            .await
+            .map_err(|(error, _)| error)?;
+        if !response.status.is_success() {
+            return Err(decode_error(response.status, response.body));
         }
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
+
+        let session: SessionResponse =
+            serde_json::from_str(&response.body).map_err(|e| ArgocdError::Decode {
+                type_name: "SessionResponse".to_string(),
+                message: e.to_string(),
+            })?;
+
+        // An empty token has no expiry, so it would be cached and never renewed
+        if session.token.is_empty() {
+            return Err(ArgocdError::Decode {
+                type_name: "SessionResponse".to_string(),
+                message: "response carried no session token".to_string(),
+            });
+        }
+        let token = SessionToken::new(session.token);
+        match token.expires_at {
+            Some(expires_at) => tracing::info!(
+                "ArgoCD session token valid for {}s",
+                expires_at
+                    .duration_since(std::time::SystemTime::now())
+                    .unwrap_or_default()
+                    .as_secs()
+            ),
+            None => tracing::info!("ArgoCD session token has no expiry"),
+        }
+        Ok(token)
+    }
+
+    /// Drop a token ArgoCD rejected, unless it was already renewed.
+    /// Returns whether fetching a new token is worth a retry.
+    async fn invalidate_token(&self, rejected: &str) -> bool {
+        let session = match &self.auth {
+            Auth::Provider(provider) => return provider.invalidate(rejected).await,
+            Auth::Session { session, .. } => session,
+        };
+        let mut cached = session.lock().await;
+        if cached.as_ref().is_some_and(|t| t.token == rejected) {
+            *cached = None;
         }
+        true
+    }
+
+    /// ArgoCD server version from `/api/version`
+    ///
+    /// Used as a startup probe: a wrong base URL or root path fails here
+    /// rather than on the first tool call.
+    pub async fn get_version(&self) -> Result<VersionMessage> {
+        self.execute_json(ApiRequest::get("get_version", "/api/version"), "VersionMessage")
+            .await
+    }
+
+    /// Register a hook invoked around every request
+    #[allow(dead_code)]
+    pub fn with_hook(mut self, hook: Arc<dyn RequestHook>) -> Self {
+        self.hooks.push(hook);
+        self
+    }
+
+    /// Execute a request and return the raw response body
+    ///
+    /// Every endpoint goes through here: it assigns a request ID, opens a
+    /// tracing span, sends the request with authentication, runs hooks and
+    /// decodes ArgoCD error responses into `ArgocdError`.
+    async fn execute(&self, request: ApiRequest) -> Result<String> {
+        let request_id = next_request_id();
+        let span = tracing::info_span!(
+            "argocd_request",
+            request_id = %request_id,
+            operation = request.operation(),
+            method = %request.method(),
+            path = request.path(),
+            status = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        );
+
+        async {
+            let info = RequestInfo {
+                request_id: &request_id,
+                request: &request,
+            };
+
+            for hook in &self.hooks {
+                if let Some(body) = hook.before_request(&info) {
+                    tracing::debug!("Request answered by hook");
+                    return Ok(body);
+                }
+            }
+
+            let mut retries = 0;
+            let mut renewed_token = false;
+            loop {
+                let token = self.bearer_token().await?;
+                let (error, failure, retry_after) = match self
+                    .send(&request, &request_id, Some(&token))
+                    .await
+                {
+                    Ok(response) => {
+                        for hook in &self.hooks {
+                            hook.after_response(
+                                &info,
+                                &ResponseInfo {
+                                    status: response.status.as_u16(),
+                                    elapsed: response.elapsed,
+                                    body: &response.body,
+                                },
+                            );
+                        }
+
+                        if response.status.is_success() {
+                            return Ok(response.body);
+                        }
+                        // An expired or revoked token: fetch a new one once
+                        if response.status == StatusCode::UNAUTHORIZED
+                            && !renewed_token
+                            && self.invalidate_token(&token).await
+                        {
+                            tracing::info!("ArgoCD rejected the token; fetching a new one");
+                            renewed_token = true;
+                            continue;
+                        }
+                        (
+                            decode_error(response.status, response.body),
+                            AttemptFailure::Status(response.status),
+                            response.retry_after,
+                        )
+                    }
+                    Err((error, failure)) => (error, failure, None),
+                };
 
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
+                let Some(delay) =
+                    self.retry
+                        .next_delay(request.retry, failure, retries, retry_after)
+                else {
+                    return Err(error.into());
+                };
+
+                retries += 1;
+                tracing::warn!(
+                    "Retrying {} (retry {}/{}) in {}ms: {}",
+                    request.operation(),
+                    retries,
+                    self.retry.max_retries,
+                    delay.as_millis(),
+                    error
+                );
+                record_retry(RetryRecord {
+                    operation: request.operation(),
+                    attempt: retries,
+                    reason: error.kind().to_string(),
+                    delay,
+                });
+                tokio::time::sleep(delay).await;
+            }
         }
+        .instrument(span)
+        .await
+    }
 
-        tracing::debug!("Fetching applications from: {}", url);
+    /// Send a single HTTP request
+    async fn send(
+        &self,
+        request: &ApiRequest,
+        request_id: &str,
+        bearer_token: Option<&str>,
+    ) -> std::result::Result<ApiResponse, (ArgocdError, AttemptFailure)> {
+        let url = format!("{}{}", self.base_url, request.path_and_query());
+        tracing::debug!(
+            "Sending request to: {}{}",
+            self.base_url,
+            request.redacted_path_and_query()
+        );
 
-        let response = self
+        let mut builder = self
             .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
+            .request(request.method().clone(), &url)
             .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
+            .header(REQUEST_ID_HEADER, request_id);
+        if let Some(token) = bearer_token {
+            builder = builder.header("Authorization", format!("Bearer {}", token));
+        }
 
-        let status = response.status();
+        builder = match &request.body {
+            Some(RequestBody::Json(body)) => builder.json(body),
+            Some(RequestBody::Raw(body)) => builder
+                .header("Content-Type", "application/json")
+                .body(body.clone()),
+            None => builder,
+        };
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
+        let started = Instant::now();
+        let result = builder.send().await;
+        let span = tracing::Span::current();
+
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                // The URL may hold percent-encoded repository credentials
+                let e = e.without_url();
+                span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+                tracing::warn!("Request failed: {}", e);
+                let failure = if e.is_connect() {
+                    AttemptFailure::NotSent
+                } else if e.is_timeout() {
+                    AttemptFailure::Timeout
                 } else {
-                    error_text
+                    AttemptFailure::Interrupted
                 };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
+                return Err((ArgocdError::from_reqwest(&e), failure));
+            }
+        };
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = response.text().await.map_err(|e| {
+            let e = e.without_url();
+            let failure = if e.is_timeout() {
+                AttemptFailure::Timeout
             } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
+                AttemptFailure::Interrupted
+            };
+            (ArgocdError::from_reqwest(&e), failure)
+        })?;
+
+        let elapsed = started.elapsed();
+        span.record("status", status.as_u16());
+        span.record("elapsed_ms", elapsed.as_millis() as u64);
+        tracing::debug!(
+            "ArgoCD responded {} in {}ms",
+            status,
+            elapsed.as_millis()
+        );
+
+        Ok(ApiResponse {
+            status,
+            elapsed,
+            retry_after,
+            body,
+        })
+    }
+
+    /// Execute a request and deserialize the JSON response
+    async fn execute_json<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest,
+        type_name: &str,
+    ) -> Result<T> {
+        let body = self.execute(request).await?;
+        serde_json::from_str(&body).map_err(|e| {
+            ArgocdError::Decode {
+                type_name: type_name.to_string(),
+                message: e.to_string(),
+            }
+            .into()
+        })
+    }
+
+    /// Open a watch stream and return it once ArgoCD has accepted the request
+    ///
+    /// Streams stay open far longer than a normal request, so they use
+    /// `STREAM_TIMEOUT` instead of the client timeout and are not retried:
+    /// callers reconnect when a stream fails or ends.
+    async fn open_stream<T: DeserializeOwned>(
+        &self,
+        request: ApiRequest,
+        type_name: &'static str,
+    ) -> Result<WatchStream<T>> {
+        let request_id = next_request_id();
+        let url = format!("{}{}", self.base_url, request.path_and_query());
+        tracing::debug!(
+            request_id = %request_id,
+            "Opening {} stream: {}{}",
+            request.operation(),
+            self.base_url,
+            request.redacted_path_and_query()
+        );
+
+        let mut renewed_token = false;
+        loop {
+            let token = self.bearer_token().await?;
+            let response = self
+                .client
+                .get(&url)
+                .header("Accept", "text/event-stream")
+                .header(REQUEST_ID_HEADER, &request_id)
+                .header("Authorization", format!("Bearer {}", token))
+                .timeout(STREAM_TIMEOUT)
+                .send()
+                .await
+                .map_err(|e| ArgocdError::from_reqwest(&e))?;
+
+            let status = response.status();
+            if status.is_success() {
+                return Ok(WatchStream::new(response, type_name));
+            }
+            if status == StatusCode::UNAUTHORIZED
+                && !renewed_token
+                && self.invalidate_token(&token).await
+            {
+                tracing::info!("ArgoCD rejected the token; fetching a new one");
+                renewed_token = true;
+                continue;
             }
+            let body = response.text().await.unwrap_or_default();
+            return Err(decode_error(status, body).into());
         }
+    }
 
-        let app_list = response
-            .json::<ApplicationList>()
-            .await
-            .context("Failed to parse ApplicationList response")?;
+    /// List applications with optional filters
+    /// Returns optimized summaries to save context window
+    pub async fn list_applications(
+        &self,
+        name: Option<String>,
+        projects: Option<Vec<String>>,
+        selector: Option<String>,
+        repo: Option<String>,
+        app_namespace: Option<String>,
+    ) -> Result<Vec<ApplicationSummaryOutput>> {
+        let app_list = self
+            .list_applications_full(name, projects, selector, repo, app_namespace)
+            .await?;
 
         // Convert to optimized summaries
-        let summaries = app_list
+        Ok(app_list
             .items
             .into_iter()
             .map(ApplicationSummaryOutput::from)
-            .collect();
-
-        Ok(summaries)
+            .collect())
     }
 
     /// Get full application details (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn list_applications_full(
         &self,
         name: Option<String>,
@@ -162,59 +753,14 @@ impl ArgocdClient {
         repo: Option<String>,
         app_namespace: Option<String>,
     ) -> Result<ApplicationList> {
-        let mut url = format!("{}/api/v1/applications", self.base_url);
-        let mut params = Vec::new();
-
-        if let Some(n) = name {
-            params.push(format!("name={}", urlencoding::encode(&n)));
-        }
-        if let Some(projs) = projects {
-            for proj in projs {
-                params.push(format!("projects={}", urlencoding::encode(&proj)));
-            }
-        }
-        if let Some(sel) = selector {
-            params.push(format!("selector={}", urlencoding::encode(&sel)));
-        }
-        if let Some(r) = repo {
-            params.push(format!("repo={}", urlencoding::encode(&r)));
-        }
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching applications from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
-
-        let app_list = response
-            .json::<ApplicationList>()
-            .await
-            .context("Failed to parse ApplicationList response")?;
-
-        Ok(app_list)
+        let request = ApiRequest::get("list_applications", "/api/v1/applications")
+            .query_opt("name", name)
+            .query_all("projects", projects.unwrap_or_default())
+            .query_opt("selector", selector)
+            .query_opt("repo", repo)
+            .query_opt("appNamespace", app_namespace);
+
+        self.execute_json(request, "ApplicationList").await
     }
 
     /// List only application names (minimal response for name lookup and typo correction)
@@ -226,82 +772,16 @@ impl ArgocdClient {
         repo: Option<String>,
         app_namespace: Option<String>,
     ) -> Result<Vec<String>> {
-        let mut url = format!("{}/api/v1/applications", self.base_url);
-        let mut params = Vec::new();
-
-        if let Some(projs) = projects {
-            for proj in projs {
-                params.push(format!("projects={}", urlencoding::encode(&proj)));
-            }
-        }
-        if let Some(sel) = selector {
-            params.push(format!("selector={}", urlencoding::encode(&sel)));
-        }
-        if let Some(r) = repo {
-            params.push(format!("repo={}", urlencoding::encode(&r)));
-        }
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::info!("Fetching application names from: {}", url);
-        tracing::info!(
-            "ARGOCD_INSECURE env var: {:?}",
-            std::env::var("ARGOCD_INSECURE")
-        );
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .map_err(|e| {
-                anyhow::anyhow!("Failed to send request to ArgoCD API ({}): {}", url, e)
-            })?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let app_list = response
-            .json::<ApplicationList>()
-            .await
-            .context("Failed to parse ApplicationList response")?;
+        let app_list = self
+            .list_applications_full(None, projects, selector, repo, app_namespace)
+            .await?;
 
         // Extract only names
-        let names: Vec<String> = app_list
+        Ok(app_list
             .items
             .into_iter()
             .filter_map(|app| app.metadata.map(|m| m.name))
-            .collect();
-
-        Ok(names)
+            .collect())
     }
 
     /// Perform server-side diff calculation using dry-run apply
@@ -317,85 +797,20 @@ impl ArgocdClient {
         project: Option<String>,
         target_manifests: Option<Vec<String>>,
     ) -> Result<Vec<ServerSideDiffSummary>> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/server-side-diff",
-            self.base_url,
-            urlencoding::encode(&app_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(proj) = project {
-            params.push(format!("project={}", urlencoding::encode(&proj)));
-        }
-        if let Some(manifests) = target_manifests {
-            for manifest in manifests {
-                params.push(format!(
-                    "targetManifests={}",
-                    urlencoding::encode(&manifest)
-                ));
-            }
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
+        let diff_response = self
+            .server_side_diff_full(app_name, app_namespace, project, target_manifests)
+            .await?;
 
-        tracing::debug!("Fetching server-side diff from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let diff_response = response
-            .json::<ApplicationServerSideDiffResponse>()
-            .await
-            .context("Failed to parse ApplicationServerSideDiffResponse")?;
-
-        // Convert to optimized summaries
-        let summaries = diff_response
-            .items
-            .into_iter()
-            .map(ServerSideDiffSummary::from)
-            .collect();
-
-        Ok(summaries)
-    }
+        // Convert to optimized summaries
+        Ok(diff_response
+            .items
+            .into_iter()
+            .map(ServerSideDiffSummary::from)
+            .collect())
+    }
 
     /// Get full server-side diff details (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn server_side_diff_full(
         &self,
         app_name: String,
@@ -403,64 +818,21 @@ impl ArgocdClient {
         project: Option<String>,
         target_manifests: Option<Vec<String>>,
     ) -> Result<ApplicationServerSideDiffResponse> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/server-side-diff",
-            self.base_url,
-            urlencoding::encode(&app_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(proj) = project {
-            params.push(format!("project={}", urlencoding::encode(&proj)));
-        }
-        if let Some(manifests) = target_manifests {
-            for manifest in manifests {
-                params.push(format!(
-                    "targetManifests={}",
-                    urlencoding::encode(&manifest)
-                ));
-            }
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching server-side diff from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
-
-        let diff_response = response
-            .json::<ApplicationServerSideDiffResponse>()
+        let request = ApiRequest::get(
+            "server_side_diff",
+            application_path(&app_name, "/server-side-diff"),
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project)
+        .query_all("targetManifests", target_manifests.unwrap_or_default());
+
+        self.execute_json(request, "ApplicationServerSideDiffResponse")
             .await
-            .context("Failed to parse ApplicationServerSideDiffResponse")?;
-
-        Ok(diff_response)
     }
 
     /// Get resource tree for an application
     /// Returns optimized summary to save context window
+    #[allow(clippy::too_many_arguments)]
     pub async fn resource_tree(
         &self,
         application_name: String,
@@ -472,86 +844,26 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<ResourceTreeSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/resource-tree",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ns) = namespace {
-            params.push(format!("namespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(n) = name {
-            params.push(format!("name={}", urlencoding::encode(&n)));
-        }
-        if let Some(v) = version {
-            params.push(format!("version={}", urlencoding::encode(&v)));
-        }
-        if let Some(g) = group {
-            params.push(format!("group={}", urlencoding::encode(&g)));
-        }
-        if let Some(k) = kind {
-            params.push(format!("kind={}", urlencoding::encode(&k)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching resource tree from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let tree = response
-            .json::<ApplicationTree>()
-            .await
-            .context("Failed to parse ApplicationTree response")?;
+        let tree = self
+            .resource_tree_full(
+                application_name,
+                namespace,
+                name,
+                version,
+                group,
+                kind,
+                app_namespace,
+                project,
+            )
+            .await?;
 
         // Convert to optimized summary
         Ok(ResourceTreeSummary::from(tree))
     }
 
-    /// Get full resource tree for an application (not optimized)
+    /// Get full resource tree (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
+    #[allow(clippy::too_many_arguments)]
     pub async fn resource_tree_full(
         &self,
         application_name: String,
@@ -563,71 +875,23 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<ApplicationTree> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/resource-tree",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ns) = namespace {
-            params.push(format!("namespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(n) = name {
-            params.push(format!("name={}", urlencoding::encode(&n)));
-        }
-        if let Some(v) = version {
-            params.push(format!("version={}", urlencoding::encode(&v)));
-        }
-        if let Some(g) = group {
-            params.push(format!("group={}", urlencoding::encode(&g)));
-        }
-        if let Some(k) = kind {
-            params.push(format!("kind={}", urlencoding::encode(&k)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching resource tree from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
-
-        let tree = response
-            .json::<ApplicationTree>()
-            .await
-            .context("Failed to parse ApplicationTree response")?;
-
-        Ok(tree)
+        let request = ApiRequest::get(
+            "resource_tree",
+            application_path(&application_name, "/resource-tree"),
+        )
+        .query_opt("namespace", namespace)
+        .query_opt("name", name)
+        .query_opt("version", version)
+        .query_opt("group", group)
+        .query_opt("kind", kind)
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project);
+
+        self.execute_json(request, "ApplicationTree").await
     }
 
-    /// Get a single application by name
-    /// Returns optimized detail output to save context window
+    /// Get a specific application by name
+    /// Returns detailed application information
     pub async fn get_application(
         &self,
         name: String,
@@ -636,77 +900,16 @@ impl ArgocdClient {
         refresh: Option<String>,
         resource_version: Option<String>,
     ) -> Result<ApplicationDetailOutput> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}",
-            self.base_url,
-            urlencoding::encode(&name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(proj) = project {
-            params.push(format!("project={}", urlencoding::encode(&proj)));
-        }
-        if let Some(ref_type) = refresh {
-            params.push(format!("refresh={}", urlencoding::encode(&ref_type)));
-        }
-        if let Some(rv) = resource_version {
-            params.push(format!("resourceVersion={}", urlencoding::encode(&rv)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching application from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let app = response
-            .json::<Application>()
-            .await
-            .context("Failed to parse Application response")?;
+        let app = self
+            .get_application_full(name, app_namespace, project, refresh, resource_version)
+            .await?;
 
-        // Convert to optimized detail output
+        // Convert to detailed output
         Ok(ApplicationDetailOutput::from(app))
     }
 
     /// Get full application details (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn get_application_full(
         &self,
         name: String,
@@ -715,61 +918,35 @@ impl ArgocdClient {
         refresh: Option<String>,
         resource_version: Option<String>,
     ) -> Result<Application> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}",
-            self.base_url,
-            urlencoding::encode(&name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ns) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(proj) = project {
-            params.push(format!("project={}", urlencoding::encode(&proj)));
-        }
-        if let Some(ref_type) = refresh {
-            params.push(format!("refresh={}", urlencoding::encode(&ref_type)));
-        }
-        if let Some(rv) = resource_version {
-            params.push(format!("resourceVersion={}", urlencoding::encode(&rv)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching application from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
+        let request = ApiRequest::get("get_application", application_path(&name, ""))
+            .query_opt("appNamespace", app_namespace)
+            .query_opt("project", project)
+            .query_opt("refresh", refresh)
+            .query_opt("resourceVersion", resource_version);
 
-        let app = response
-            .json::<Application>()
-            .await
-            .context("Failed to parse Application response")?;
+        self.execute_json(request, "Application").await
+    }
 
-        Ok(app)
+    fn resource_events_request(
+        application_name: &str,
+        resource_namespace: Option<String>,
+        resource_name: Option<String>,
+        resource_uid: Option<String>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> ApiRequest {
+        ApiRequest::get(
+            "list_resource_events",
+            application_path(application_name, "/events"),
+        )
+        .query_opt("resourceNamespace", resource_namespace)
+        .query_opt("resourceName", resource_name)
+        .query_opt("resourceUID", resource_uid)
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project)
     }
 
-    /// List resource events for an application or specific resource
+    /// List Kubernetes events for an application or specific resource
     /// Returns optimized summary to save context window
     pub async fn list_resource_events(
         &self,
@@ -780,73 +957,15 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<EventListSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/events",
-            self.base_url,
-            urlencoding::encode(&application_name)
+        let request = Self::resource_events_request(
+            &application_name,
+            resource_namespace,
+            resource_name,
+            resource_uid,
+            app_namespace,
+            project,
         );
-        let mut params = Vec::new();
-
-        if let Some(rns) = resource_namespace {
-            params.push(format!("resourceNamespace={}", urlencoding::encode(&rns)));
-        }
-        if let Some(rn) = resource_name {
-            params.push(format!("resourceName={}", urlencoding::encode(&rn)));
-        }
-        if let Some(ruid) = resource_uid {
-            params.push(format!("resourceUID={}", urlencoding::encode(&ruid)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching resource events from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        // Get the response text first for better error handling
-        let response_text = response
-            .text()
-            .await
-            .context("Failed to read response body")?;
+        let response_text = self.execute(request).await?;
 
         // Try to parse the response
         let event_list: EventList = match serde_json::from_str(&response_text) {
@@ -882,65 +1001,41 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<EventList> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/events",
-            self.base_url,
-            urlencoding::encode(&application_name)
+        let request = Self::resource_events_request(
+            &application_name,
+            resource_namespace,
+            resource_name,
+            resource_uid,
+            app_namespace,
+            project,
         );
-        let mut params = Vec::new();
-
-        if let Some(rns) = resource_namespace {
-            params.push(format!("resourceNamespace={}", urlencoding::encode(&rns)));
-        }
-        if let Some(rn) = resource_name {
-            params.push(format!("resourceName={}", urlencoding::encode(&rn)));
-        }
-        if let Some(ruid) = resource_uid {
-            params.push(format!("resourceUID={}", urlencoding::encode(&ruid)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching resource events from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
 
-        let event_list = response
-            .json::<EventList>()
-            .await
-            .context("Failed to parse EventList response")?;
+        self.execute_json(request, "EventList").await
+    }
 
-        Ok(event_list)
+    fn pod_logs_request(query: &PodLogsQuery, follow: bool) -> ApiRequest {
+        ApiRequest::get(
+            "pod_logs",
+            application_path(&query.application_name, "/logs"),
+        )
+        .query_opt("namespace", query.namespace.clone())
+        .query_opt("podName", query.pod_name.clone())
+        .query_opt("container", query.container.clone())
+        .query_opt("sinceSeconds", query.since_seconds)
+        .query_opt("tailLines", query.tail_lines)
+        .query_opt("previous", query.previous.filter(|p| *p))
+        .query_opt("filter", query.filter.clone())
+        .query_opt("kind", query.kind.clone())
+        .query_opt("group", query.group.clone())
+        .query_opt("resourceName", query.resource_name.clone())
+        .query_opt("appNamespace", query.app_namespace.clone())
+        .query_opt("project", query.project.clone())
+        .query("follow", follow)
     }
 
     /// Get pod logs for an application resource
     /// Returns optimized summary with log analysis
+    #[allow(clippy::too_many_arguments)]
     pub async fn pod_logs(
         &self,
         application_name: String,
@@ -958,104 +1053,47 @@ impl ArgocdClient {
         project: Option<String>,
         filter_errors_only: bool,
     ) -> Result<PodLogsSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/logs",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
+        let query = PodLogsQuery {
+            application_name,
+            namespace,
+            pod_name: pod_name.clone(),
+            container: container.clone(),
+            since_seconds,
+            tail_lines,
+            previous,
+            filter,
+            kind,
+            group,
+            resource_name,
+            app_namespace,
+            project,
+        };
+        let log_entries = self.pod_log_entries(&query).await?;
 
-        if let Some(ns) = namespace {
-            params.push(format!("namespace={}", urlencoding::encode(&ns)));
-        }
-        if let Some(pn) = &pod_name {
-            params.push(format!("podName={}", urlencoding::encode(pn)));
-        }
-        if let Some(c) = &container {
-            params.push(format!("container={}", urlencoding::encode(c)));
-        }
-        if let Some(ss) = since_seconds {
-            params.push(format!("sinceSeconds={}", ss));
-        }
-        if let Some(tl) = tail_lines {
-            params.push(format!("tailLines={}", tl));
-        }
-        if let Some(true) = previous {
-            params.push("previous=true".to_string());
-        }
-        if let Some(f) = filter {
-            params.push(format!("filter={}", urlencoding::encode(&f)));
-        }
-        if let Some(k) = kind {
-            params.push(format!("kind={}", urlencoding::encode(&k)));
-        }
-        if let Some(g) = group {
-            params.push(format!("group={}", urlencoding::encode(&g)));
-        }
-        if let Some(rn) = resource_name {
-            params.push(format!("resourceName={}", urlencoding::encode(&rn)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
+        // Convert to optimized summary with analysis
+        Ok(PodLogsSummary::from_entries(
+            log_entries,
+            pod_name,
+            container,
+            tail_lines,
+            filter_errors_only,
+        ))
+    }
 
+    /// Fetch the log lines of a pod or resource without following the stream
+    async fn pod_log_entries(&self, query: &PodLogsQuery) -> Result<Vec<LogEntry>> {
         // Disable follow for non-streaming response
-        params.push("follow=false".to_string());
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching pod logs from: {}", url);
+        let request = Self::pod_logs_request(query, false);
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
+        // The logs endpoint returns newline-delimited JSON (NDJSON/JSON streaming)
+        let text = self.execute(request).await?;
 
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        // The logs endpoint returns newline-delimited JSON (NDJSON/JSON streaming)
-        let text = response
-            .text()
-            .await
-            .context("Failed to read response body")?;
-
-        // Parse NDJSON - each line is a JSON object with either "result" or "error"
-        let mut log_entries = Vec::new();
-        for line in text.lines() {
-            if line.trim().is_empty() {
-                continue;
-            }
+        // Parse NDJSON - each line is a JSON object with either "result" or "error"
+        let mut log_entries = Vec::new();
+        for line in text.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
 
             // Try to parse as streaming response wrapper
             if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(line) {
@@ -1071,16 +1109,235 @@ impl ArgocdClient {
             }
         }
 
-        // Convert to optimized summary with analysis
-        Ok(PodLogsSummary::from_entries(
-            log_entries,
-            pod_name,
-            container,
-            tail_lines,
+        Ok(log_entries)
+    }
+
+    /// Get the logs of every pod under a workload (Deployment, StatefulSet,
+    /// Rollout, ...) identified by `query.kind` and `query.resource_name`
+    ///
+    /// Pods are found through the parent references in the application's
+    /// resource tree, and their logs are fetched concurrently and merged by
+    /// timestamp. Pods whose logs cannot be fetched are reported as
+    /// unavailable; the call only fails when no pod's logs could be fetched.
+    pub async fn workload_logs(
+        &self,
+        query: PodLogsQuery,
+        filter_errors_only: bool,
+    ) -> Result<PodLogsSummary> {
+        let (Some(kind), Some(name)) = (query.kind.clone(), query.resource_name.clone()) else {
+            return Err(ArgocdError::from_response(
+                StatusCode::BAD_REQUEST,
+                None,
+                "kind and resource_name are required to find a workload's pods".to_string(),
+            )
+            .into());
+        };
+
+        let tree = self
+            .resource_tree_full(
+                query.application_name.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                query.app_namespace.clone(),
+                query.project.clone(),
+            )
+            .await?;
+        // Without a namespace, take it from the workload's node in the tree
+        let namespace = query.namespace.clone().or_else(|| {
+            tree.nodes
+                .iter()
+                .find(|node| {
+                    node.kind.as_deref() == Some(kind.as_str())
+                        && node.name.as_deref() == Some(name.as_str())
+                })
+                .and_then(|node| node.namespace.clone())
+        });
+        let mut pods: Vec<&ResourceNode> = tree
+            .descendants(&kind, &name, namespace.as_deref())
+            .into_iter()
+            .filter(|node| node.kind.as_deref() == Some("Pod") && node.name.is_some())
+            .collect();
+        if pods.is_empty() {
+            return Err(ArgocdError::from_response(
+                StatusCode::NOT_FOUND,
+                None,
+                format!(
+                    "no pods of {} '{}' found in the resource tree of application '{}'",
+                    kind, name, query.application_name
+                ),
+            )
+            .into());
+        }
+
+        // Unhealthy pods are fetched first when there are more than the cap
+        let pods_found = pods.len();
+        pods.sort_by_key(|node| {
+            let healthy = node.health.as_ref().is_some_and(|h| h.status == "Healthy");
+            (healthy, node.name.clone())
+        });
+        pods.truncate(MAX_WORKLOAD_PODS);
+
+        // Run the requests in this task so retries and the tool span are kept
+        let requests: Vec<_> = pods
+            .iter()
+            .enumerate()
+            .map(|(index, pod)| {
+                let pod_query = PodLogsQuery {
+                    namespace: pod.namespace.clone(),
+                    pod_name: pod.name.clone(),
+                    kind: None,
+                    group: None,
+                    resource_name: None,
+                    ..query.clone()
+                };
+                async move { (index, self.pod_log_entries(&pod_query).await) }
+            })
+            .collect();
+        let mut results: Vec<_> = futures::stream::iter(requests)
+            .buffer_unordered(WORKLOAD_LOG_CONCURRENCY)
+            .collect()
+            .await;
+        results.sort_by_key(|(index, _)| *index);
+
+        // Surface the error itself when no pod could be read at all
+        if results.iter().all(|(_, result)| result.is_err()) {
+            let (_, first) = results.swap_remove(0);
+            first?;
+        }
+
+        let replicas = pods
+            .iter()
+            .zip(results)
+            .map(|(pod, (_, result))| ReplicaLogs {
+                pod_name: pod.name.clone().unwrap_or_default(),
+                namespace: pod.namespace.clone(),
+                health_status: pod.health.as_ref().map(|h| h.status.clone()),
+                entries: result.map_err(|e| e.to_string()),
+            })
+            .collect();
+
+        Ok(PodLogsSummary::from_replicas(
+            kind,
+            name,
+            pods_found,
+            replicas,
+            query.container,
+            query.tail_lines,
             filter_errors_only,
         ))
     }
 
+    /// Follow pod logs until a time or line limit is reached or a line
+    /// contains the stop pattern
+    ///
+    /// Lines are read from the stream as they arrive and passed to
+    /// `on_batch` in batches; the returned summary holds the same analysis
+    /// as `pod_logs` plus how the stream ended.
+    pub async fn pod_logs_follow<F, Fut>(
+        &self,
+        query: PodLogsQuery,
+        filter_errors_only: bool,
+        options: &LogFollowOptions,
+        mut on_batch: F,
+    ) -> Result<PodLogsSummary>
+    where
+        F: FnMut(LogBatch) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let started = tokio::time::Instant::now();
+        let deadline = started + options.max_duration;
+        let mut stream = self
+            .open_stream::<LogEntry>(Self::pod_logs_request(&query, true), "LogEntry")
+            .await?;
+
+        let mut entries: Vec<LogEntry> = Vec::new();
+        let mut batch = Vec::new();
+        let mut last_batch = started;
+        let mut matched_line = None;
+        let stopped = loop {
+            // Wake up for a pending batch even when no further line arrives
+            let batch_due = last_batch + options.batch_interval;
+            let wait_until = if batch.is_empty() {
+                deadline
+            } else {
+                deadline.min(batch_due)
+            };
+
+            let mut stop = None;
+            match tokio::time::timeout_at(wait_until, stream.next()).await {
+                Err(_) => {
+                    if tokio::time::Instant::now() >= deadline {
+                        stop = Some(LogFollowStop::TimeLimit);
+                    }
+                }
+                // Keep what was received when the stream breaks midway
+                Ok(Err(e)) if !entries.is_empty() => {
+                    tracing::warn!("Log stream failed after {} lines: {}", entries.len(), e);
+                    stop = Some(LogFollowStop::StreamEnded);
+                }
+                Ok(next) => match next? {
+                    None => stop = Some(LogFollowStop::StreamEnded),
+                    // ArgoCD ends a finished stream with an empty `last` entry
+                    Some(entry)
+                        if entry.last == Some(true)
+                            && entry.content.as_deref().unwrap_or_default().is_empty() =>
+                    {
+                        stop = Some(LogFollowStop::StreamEnded)
+                    }
+                    Some(entry) => {
+                        let content = entry.content.clone().unwrap_or_default();
+                        batch.push(AnalyzedLogEntry::from(entry.clone()));
+                        entries.push(entry);
+                        if let Some(pattern) = &options.stop_pattern {
+                            if content.contains(pattern.as_str()) {
+                                matched_line = Some(content);
+                                stop = Some(LogFollowStop::PatternMatched);
+                            }
+                        }
+                        if stop.is_none() && entries.len() >= options.max_lines {
+                            stop = Some(LogFollowStop::LineLimit);
+                        }
+                    }
+                },
+            }
+
+            let now = tokio::time::Instant::now();
+            let full = batch.len() >= options.batch_lines;
+            if !batch.is_empty() && (full || now >= batch_due || stop.is_some()) {
+                on_batch(LogBatch {
+                    entries: std::mem::take(&mut batch),
+                    lines_received: entries.len(),
+                    elapsed_seconds: started.elapsed().as_secs(),
+                })
+                .await;
+                last_batch = now;
+            }
+            if let Some(stop) = stop {
+                break stop;
+            }
+        };
+
+        let lines_received = entries.len();
+        let mut summary = PodLogsSummary::from_entries(
+            entries,
+            query.pod_name,
+            query.container,
+            query.tail_lines,
+            filter_errors_only,
+        );
+        summary.follow = Some(LogFollowSummary {
+            stopped,
+            elapsed_seconds: started.elapsed().as_secs(),
+            lines_received,
+            stop_pattern: options.stop_pattern.clone(),
+            matched_line,
+        });
+        Ok(summary)
+    }
+
     /// Get application manifests
     /// Returns optimized summary with parsed manifests
     pub async fn get_manifests(
@@ -1092,76 +1349,16 @@ impl ArgocdClient {
         source_positions: Option<Vec<i64>>,
         revisions: Option<Vec<String>>,
     ) -> Result<ManifestSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/manifests",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(rev) = revision {
-            params.push(format!("revision={}", urlencoding::encode(&rev)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-        if let Some(positions) = source_positions {
-            for pos in positions {
-                params.push(format!("sourcePositions={}", pos));
-            }
-        }
-        if let Some(revs) = revisions {
-            for rev in revs {
-                params.push(format!("revisions={}", urlencoding::encode(&rev)));
-            }
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching manifests from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let manifest_response = response
-            .json::<ManifestResponse>()
-            .await
-            .context("Failed to parse ManifestResponse")?;
+        let manifest_response = self
+            .get_manifests_full(
+                application_name,
+                revision,
+                app_namespace,
+                project,
+                source_positions,
+                revisions,
+            )
+            .await?;
 
         // Convert to optimized summary
         Ok(ManifestSummary::from(manifest_response))
@@ -1169,7 +1366,6 @@ impl ArgocdClient {
 
     /// Get full manifest response (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn get_manifests_full(
         &self,
         application_name: String,
@@ -1179,69 +1375,21 @@ impl ArgocdClient {
         source_positions: Option<Vec<i64>>,
         revisions: Option<Vec<String>>,
     ) -> Result<ManifestResponse> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/manifests",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(rev) = revision {
-            params.push(format!("revision={}", urlencoding::encode(&rev)));
-        }
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-        if let Some(positions) = source_positions {
-            for pos in positions {
-                params.push(format!("sourcePositions={}", pos));
-            }
-        }
-        if let Some(revs) = revisions {
-            for rev in revs {
-                params.push(format!("revisions={}", urlencoding::encode(&rev)));
-            }
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching manifests from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
-
-        let manifest_response = response
-            .json::<ManifestResponse>()
-            .await
-            .context("Failed to parse ManifestResponse")?;
-
-        Ok(manifest_response)
+        let request = ApiRequest::get(
+            "get_manifests",
+            application_path(&application_name, "/manifests"),
+        )
+        .query_opt("revision", revision)
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project)
+        .query_all("sourcePositions", source_positions.unwrap_or_default())
+        .query_all("revisions", revisions.unwrap_or_default());
+
+        self.execute_json(request, "ManifestResponse").await
     }
 
-    /// Get revision metadata for a specific revision of an application
-    /// Returns optimized summary with revision information
+    /// Get revision metadata (author, date, message, tags)
+    /// Returns optimized summary
     pub async fn revision_metadata(
         &self,
         application_name: String,
@@ -1251,70 +1399,16 @@ impl ArgocdClient {
         source_index: Option<i32>,
         version_id: Option<i32>,
     ) -> Result<RevisionMetadataSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/revisions/{}/metadata",
-            self.base_url,
-            urlencoding::encode(&application_name),
-            urlencoding::encode(&revision)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-        if let Some(si) = source_index {
-            params.push(format!("sourceIndex={}", si));
-        }
-        if let Some(vid) = version_id {
-            params.push(format!("versionId={}", vid));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching revision metadata from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let metadata = response
-            .json::<RevisionMetadata>()
-            .await
-            .context("Failed to parse RevisionMetadata response")?;
+        let metadata = self
+            .revision_metadata_full(
+                application_name,
+                revision,
+                app_namespace,
+                project,
+                source_index,
+                version_id,
+            )
+            .await?;
 
         // Convert to optimized summary
         Ok(RevisionMetadataSummary::from(metadata))
@@ -1322,7 +1416,6 @@ impl ArgocdClient {
 
     /// Get full revision metadata (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn revision_metadata_full(
         &self,
         application_name: String,
@@ -1332,131 +1425,32 @@ impl ArgocdClient {
         source_index: Option<i32>,
         version_id: Option<i32>,
     ) -> Result<RevisionMetadata> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/revisions/{}/metadata",
-            self.base_url,
-            urlencoding::encode(&application_name),
-            urlencoding::encode(&revision)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-        if let Some(si) = source_index {
-            params.push(format!("sourceIndex={}", si));
-        }
-        if let Some(vid) = version_id {
-            params.push(format!("versionId={}", vid));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching revision metadata from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
-
-        let metadata = response
-            .json::<RevisionMetadata>()
-            .await
-            .context("Failed to parse RevisionMetadata response")?;
-
-        Ok(metadata)
+        let request = ApiRequest::get(
+            "revision_metadata",
+            application_path(
+                &application_name,
+                &format!("/revisions/{}/metadata", urlencoding::encode(&revision)),
+            ),
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project)
+        .query_opt("sourceIndex", source_index)
+        .query_opt("versionId", version_id);
+
+        self.execute_json(request, "RevisionMetadata").await
     }
 
     /// Get application sync windows
-    /// Returns optimized summary with sync window information
-    ///
-    /// **Note**: This endpoint may not be available in all ArgoCD versions.
-    /// If you receive a 404 error, your ArgoCD instance may not support application-level
-    /// sync windows, or sync windows may need to be configured at the project level.
-    /// This feature typically requires ArgoCD v2.6+.
+    /// Returns optimized summary
     pub async fn get_application_sync_windows(
         &self,
         application_name: String,
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<ApplicationSyncWindowsSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/sync-windows",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching application sync windows from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let sync_windows_response = response
-            .json::<ApplicationSyncWindowsResponse>()
-            .await
-            .context("Failed to parse ApplicationSyncWindowsResponse")?;
+        let sync_windows_response = self
+            .get_application_sync_windows_full(application_name, app_namespace, project)
+            .await?;
 
         // Convert to optimized summary
         Ok(ApplicationSyncWindowsSummary::from(sync_windows_response))
@@ -1464,63 +1458,25 @@ impl ArgocdClient {
 
     /// Get full application sync windows (not optimized)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn get_application_sync_windows_full(
         &self,
         application_name: String,
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<ApplicationSyncWindowsResponse> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/sync-windows",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(&ans)));
-        }
-        if let Some(p) = project {
-            params.push(format!("project={}", urlencoding::encode(&p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Fetching application sync windows from: {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
-
-        let sync_windows_response = response
-            .json::<ApplicationSyncWindowsResponse>()
+        let request = ApiRequest::get(
+            "get_application_sync_windows",
+            application_path(&application_name, "/sync-windows"),
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project);
+
+        self.execute_json(request, "ApplicationSyncWindowsResponse")
             .await
-            .context("Failed to parse ApplicationSyncWindowsResponse")?;
-
-        Ok(sync_windows_response)
     }
 
-    /// Rollback an application to a previous deployed version by History ID
-    /// Returns optimized summary to save context window
+    /// Rollback an application to a previous deployed version
+    /// Returns optimized summary of the rollback operation
     pub async fn rollback_application(
         &self,
         name: String,
@@ -1530,88 +1486,21 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<ApplicationRollbackSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/rollback",
-            self.base_url,
-            urlencoding::encode(&name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
+        let app = self
+            .rollback_application_full(name, id, dry_run, prune, app_namespace, project)
+            .await?;
 
-        tracing::debug!("Rolling back application at: {}", url);
-
-        // Build request body
-        let request_body = serde_json::json!({
-            "name": name,
-            "id": id,
-            "dryRun": dry_run.unwrap_or(false),
-            "prune": prune.unwrap_or(false),
-            "appNamespace": app_namespace,
-            "project": project,
-        });
-
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let app = response
-            .json::<Application>()
-            .await
-            .context("Failed to parse Application response")?;
-
-        // Convert to optimized summary
-        Ok(ApplicationRollbackSummary::from_application(
-            app,
-            id,
-            dry_run.unwrap_or(false),
-            prune.unwrap_or(false),
-        ))
-    }
+        // Convert to optimized summary
+        Ok(ApplicationRollbackSummary::from_application(
+            app,
+            id,
+            dry_run.unwrap_or(false),
+            prune.unwrap_or(false),
+        ))
+    }
 
     /// Rollback an application (returns full Application object)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     pub async fn rollback_application_full(
         &self,
         name: String,
@@ -1621,27 +1510,6 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<Application> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/rollback",
-            self.base_url,
-            urlencoding::encode(&name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Rolling back application at: {}", url);
-
         // Build request body
         let request_body = serde_json::json!({
             "name": name,
@@ -1652,37 +1520,20 @@ impl ArgocdClient {
             "project": project,
         });
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
+        let request = ApiRequest::post(
+            "rollback_application",
+            application_path(&name, "/rollback"),
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project)
+        .json(&request_body)
+        .retry_safety(RetrySafety::OptIn);
 
-        let app = response
-            .json::<Application>()
-            .await
-            .context("Failed to parse Application response")?;
-
-        Ok(app)
+        self.execute_json(request, "Application").await
     }
 
     /// Sync an application to its target state
-    /// Returns optimized summary to save context window
+    /// Returns optimized summary of the sync operation
     #[allow(clippy::too_many_arguments)]
     pub async fn sync_application(
         &self,
@@ -1697,89 +1548,21 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<ApplicationSyncSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/sync",
-            self.base_url,
-            urlencoding::encode(&name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Syncing application at: {}", url);
-
-        // Build strategy
-        let strategy = if let Some(true) = force {
-            Some(SyncStrategy {
-                apply: Some(SyncStrategyApply { force: Some(true) }),
-                hook: Some(SyncStrategyHook { force: Some(true) }),
-            })
-        } else {
-            None
-        };
-
-        // Build request body
-        let request_body = serde_json::json!({
-            "name": name,
-            "revision": revision,
-            "dryRun": dry_run.unwrap_or(false),
-            "prune": prune.unwrap_or(false),
-            "strategy": strategy,
-            "resources": resources,
-            "syncOptions": sync_options,
-            "retry": retry,
-            "appNamespace": app_namespace,
-            "project": project,
-        });
-
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
-
-        let status = response.status();
-
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
-
-        let app = response
-            .json::<Application>()
-            .await
-            .context("Failed to parse Application response")?;
+        let resource_count = resources.as_ref().map(|r| r.len());
+        let app = self
+            .sync_application_full(
+                name,
+                revision,
+                dry_run,
+                prune,
+                force,
+                resources,
+                sync_options.clone(),
+                retry,
+                app_namespace,
+                project,
+            )
+            .await?;
 
         // Convert to optimized summary
         Ok(ApplicationSyncSummary::from_application(
@@ -1788,13 +1571,12 @@ impl ArgocdClient {
             prune.unwrap_or(false),
             force.unwrap_or(false),
             sync_options.unwrap_or_default(),
-            resources.as_ref().map(|r| r.len()),
+            resource_count,
         ))
     }
 
     /// Sync an application (returns full Application object)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
     #[allow(clippy::too_many_arguments)]
     pub async fn sync_application_full(
         &self,
@@ -1809,27 +1591,6 @@ impl ArgocdClient {
         app_namespace: Option<String>,
         project: Option<String>,
     ) -> Result<Application> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/sync",
-            self.base_url,
-            urlencoding::encode(&name)
-        );
-        let mut params = Vec::new();
-
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        tracing::debug!("Syncing application at: {}", url);
-
         // Build strategy
         let strategy = if let Some(true) = force {
             Some(SyncStrategy {
@@ -1854,377 +1615,1371 @@ impl ArgocdClient {
             "project": project,
         });
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
+        let request = ApiRequest::post("sync_application", application_path(&name, "/sync"))
+            .query_opt("appNamespace", app_namespace)
+            .query_opt("project", project)
             .json(&request_body)
-            .send()
+            .retry_safety(RetrySafety::OptIn);
+
+        self.execute_json(request, "Application").await
+    }
+
+    /// Request for a resource endpoint (`suffix` is e.g. "/resource" or
+    /// "/resource/actions") identifying the resource by query parameters
+    #[allow(clippy::too_many_arguments)]
+    fn resource_request(
+        operation: &'static str,
+        method: Method,
+        suffix: &str,
+        application_name: &str,
+        namespace: Option<String>,
+        resource_name: &str,
+        version: &str,
+        group: Option<String>,
+        kind: &str,
+    ) -> ApiRequest {
+        ApiRequest::new(
+            operation,
+            method,
+            application_path(application_name, suffix),
+        )
+        .query_opt("namespace", namespace)
+        .query("resourceName", resource_name)
+        .query("version", version)
+        .query_opt("group", group)
+        .query("kind", kind)
+    }
+
+    /// Get a specific Kubernetes resource managed by an application
+    /// Returns optimized summary with parsed metadata
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_resource(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationResourceSummary> {
+        let resource_response = self
+            .get_resource_full(
+                application_name.clone(),
+                namespace.clone(),
+                resource_name.clone(),
+                version.clone(),
+                group.clone(),
+                kind.clone(),
+                app_namespace,
+                project,
+            )
+            .await?;
+
+        // Convert to optimized summary
+        let manifest = resource_response.manifest.unwrap_or_default();
+        Ok(ApplicationResourceSummary::from_manifest(
+            application_name,
+            kind,
+            resource_name,
+            namespace,
+            version,
+            group,
+            manifest,
+        ))
+    }
+
+    /// Get full resource response (not optimized)
+    /// This method is part of the public API and used in tests
+    #[allow(clippy::too_many_arguments)]
+    pub async fn get_resource_full(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationResourceResponse> {
+        let request = Self::resource_request(
+            "get_resource",
+            Method::GET,
+            "/resource",
+            &application_name,
+            namespace,
+            &resource_name,
+            &version,
+            group,
+            &kind,
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project);
+
+        self.execute_json(request, "ApplicationResourceResponse")
             .await
-            .context("Failed to send request to ArgoCD API")?;
+    }
 
-        let status = response.status();
+    /// Patch a Kubernetes resource managed by an application
+    /// Returns optimized summary of the patched resource
+    #[allow(clippy::too_many_arguments)]
+    pub async fn patch_resource(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        patch: String,
+        patch_type: Option<String>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationResourceSummary> {
+        let resource_response = self
+            .patch_resource_full(
+                application_name.clone(),
+                namespace.clone(),
+                resource_name.clone(),
+                version.clone(),
+                group.clone(),
+                kind.clone(),
+                patch,
+                patch_type,
+                app_namespace,
+                project,
+            )
+            .await?;
+
+        // Convert to optimized summary
+        let manifest = resource_response.manifest.unwrap_or_default();
+        Ok(ApplicationResourceSummary::from_manifest(
+            application_name,
+            kind,
+            resource_name,
+            namespace,
+            version,
+            group,
+            manifest,
+        ))
+    }
+
+    /// Patch resource (returns full ApplicationResourceResponse)
+    /// This method is part of the public API and used in tests
+    #[allow(clippy::too_many_arguments)]
+    pub async fn patch_resource_full(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        patch: String,
+        patch_type: Option<String>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationResourceResponse> {
+        let request = Self::resource_request(
+            "patch_resource",
+            Method::POST,
+            "/resource",
+            &application_name,
+            namespace,
+            &resource_name,
+            &version,
+            group,
+            &kind,
+        )
+        .query_opt("patchType", patch_type)
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project)
+        .raw_body(patch);
+
+        self.execute_json(request, "ApplicationResourceResponse")
+            .await
+    }
+
+    /// Delete a Kubernetes resource managed by an application
+    ///
+    /// The resource and its children from the resource tree are always read
+    /// first, so dry runs and real deletes report the same preview.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn delete_resource(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        force: Option<bool>,
+        orphan: Option<bool>,
+        dry_run: bool,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ResourceDeleteSummary> {
+        let resource = self
+            .get_resource(
+                application_name.clone(),
+                namespace.clone(),
+                resource_name.clone(),
+                version.clone(),
+                group.clone(),
+                kind.clone(),
+                app_namespace.clone(),
+                project.clone(),
+            )
+            .await?;
+        let tree = self
+            .resource_tree_full(
+                application_name.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                app_namespace.clone(),
+                project.clone(),
+            )
+            .await?;
+        let children = tree
+            .descendants(&kind, &resource_name, namespace.as_deref())
+            .into_iter()
+            .map(|node| ResourceNodeSummary {
+                name: node.name.clone().unwrap_or_default(),
+                kind: node.kind.clone().unwrap_or_default(),
+                namespace: node.namespace.clone(),
+                health_status: node.health.as_ref().map(|h| h.status.clone()),
+                parent_count: node.parent_refs.as_ref().map(|p| p.len()),
+                images: node.images.clone(),
+            })
+            .collect();
+
+        if !dry_run {
+            self.delete_resource_full(
+                application_name,
+                namespace,
+                resource_name,
+                version,
+                group,
+                kind,
+                force,
+                orphan,
+                app_namespace,
+                project,
+            )
+            .await?;
+        }
+
+        Ok(ResourceDeleteSummary {
+            resource,
+            children,
+            dry_run,
+            force: force.unwrap_or(false),
+            orphan: orphan.unwrap_or(false),
+        })
+    }
+
+    /// Delete a resource
+    /// This method is part of the public API and used in tests
+    #[allow(clippy::too_many_arguments)]
+    pub async fn delete_resource_full(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        force: Option<bool>,
+        orphan: Option<bool>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<()> {
+        let request = Self::resource_request(
+            "delete_resource",
+            Method::DELETE,
+            "/resource",
+            &application_name,
+            namespace,
+            &resource_name,
+            &version,
+            group,
+            &kind,
+        )
+        .query_opt("force", force)
+        .query_opt("orphan", orphan)
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project);
+
+        self.execute(request).await.map(|_| ())
+    }
+
+    /// List the actions available on a resource managed by an application
+    /// Returns optimized summary that marks disabled actions
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_resource_actions(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ResourceActionsSummary> {
+        let response = self
+            .list_resource_actions_full(
+                application_name.clone(),
+                namespace.clone(),
+                resource_name.clone(),
+                version,
+                group,
+                kind.clone(),
+                app_namespace,
+                project,
+            )
+            .await?;
+
+        Ok(ResourceActionsSummary::from_response(
+            response,
+            application_name,
+            kind,
+            resource_name,
+            namespace,
+        ))
+    }
+
+    /// List resource actions (returns full ResourceActionsListResponse)
+    /// This method is part of the public API and used in tests
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_resource_actions_full(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ResourceActionsListResponse> {
+        let request = Self::resource_request(
+            "list_resource_actions",
+            Method::GET,
+            "/resource/actions",
+            &application_name,
+            namespace,
+            &resource_name,
+            &version,
+            group,
+            &kind,
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project);
+
+        self.execute_json(request, "ResourceActionsListResponse")
+            .await
+    }
+
+    /// Run an action (e.g. "restart") on a resource managed by an application
+    ///
+    /// Actions without parameters use the original endpoint, which takes the
+    /// action name as the body. Parameterized actions need the v2 endpoint
+    /// (ArgoCD v2.14+).
+    #[allow(clippy::too_many_arguments)]
+    pub async fn run_resource_action(
+        &self,
+        application_name: String,
+        namespace: Option<String>,
+        resource_name: String,
+        version: String,
+        group: Option<String>,
+        kind: String,
+        action: String,
+        parameters: Vec<(String, String)>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ResourceActionRunSummary> {
+        let request = if parameters.is_empty() {
+            Self::resource_request(
+                "run_resource_action",
+                Method::POST,
+                "/resource/actions",
+                &application_name,
+                namespace.clone(),
+                &resource_name,
+                &version,
+                group,
+                &kind,
+            )
+            .query_opt("appNamespace", app_namespace)
+            .query_opt("project", project)
+            .json(&action)
+        } else {
+            let resource_action_parameters: Vec<serde_json::Value> = parameters
+                .iter()
+                .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+                .collect();
+            ApiRequest::post(
+                "run_resource_action",
+                application_path(&application_name, "/resource/actions/v2"),
+            )
+            .json(&serde_json::json!({
+                "name": application_name,
+                "namespace": namespace,
+                "resourceName": resource_name,
+                "version": version,
+                "group": group,
+                "kind": kind,
+                "action": action,
+                "appNamespace": app_namespace,
+                "project": project,
+                "resourceActionParameters": resource_action_parameters,
+            }))
+        };
+
+        self.execute(request).await?;
+
+        Ok(ResourceActionRunSummary {
+            application_name,
+            kind,
+            resource_name,
+            namespace,
+            action,
+            parameters: parameters
+                .into_iter()
+                .map(|(name, value)| format!("{}={}", name, value))
+                .collect(),
+        })
+    }
+
+    /// Terminate the application's running operation (usually a sync stuck on a hook)
+    /// Returns the operation phase before and after; nothing is sent when no
+    /// operation is in progress
+    pub async fn terminate_operation(
+        &self,
+        name: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<TerminateOperationSummary> {
+        let before = self
+            .get_application_full(
+                name.clone(),
+                app_namespace.clone(),
+                project.clone(),
+                None,
+                None,
+            )
+            .await?
+            .status
+            .and_then(|s| s.operation_state);
+
+        let terminated = before.as_ref().is_some_and(|op| op.is_in_progress());
+        let after = if terminated {
+            self.terminate_operation_full(name.clone(), app_namespace.clone(), project.clone())
+                .await?;
+            self.get_application_full(name.clone(), app_namespace, project, None, None)
+                .await?
+                .status
+                .and_then(|s| s.operation_state)
+        } else {
+            before.clone()
+        };
+
+        Ok(TerminateOperationSummary {
+            name,
+            phase_before: before.as_ref().map(|op| op.phase.clone()),
+            message_before: before.as_ref().and_then(|op| op.message.clone()),
+            started_at: before.and_then(|op| op.started_at),
+            terminated,
+            phase_after: after.as_ref().map(|op| op.phase.clone()),
+            message_after: after.and_then(|op| op.message),
+        })
+    }
+
+    /// Terminate the running operation
+    /// This method is part of the public API and used in tests
+    pub async fn terminate_operation_full(
+        &self,
+        name: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<()> {
+        let request = ApiRequest::delete("terminate_operation", application_path(&name, "/operation"))
+            .query_opt("appNamespace", app_namespace)
+            .query_opt("project", project);
+
+        self.execute(request).await.map(|_| ())
+    }
+
+    /// Create an application, or update it in place when `upsert` is set
+    /// Dry runs check the spec locally and look up the existing application
+    /// instead of creating anything, since ArgoCD has no server-side dry run
+    pub async fn create_application(
+        &self,
+        application: Application,
+        upsert: Option<bool>,
+        validate: Option<bool>,
+        dry_run: Option<bool>,
+    ) -> Result<ApplicationCreateSummary> {
+        let metadata = application.metadata.clone();
+        let name = metadata.as_ref().map(|m| m.name.clone()).unwrap_or_default();
+        let namespace = metadata.and_then(|m| m.namespace);
+        let dry_run = dry_run.unwrap_or(false);
+
+        let (spec, already_exists, problems) = if dry_run {
+            let spec = application.spec.unwrap_or_default();
+            let existing = self
+                .get_application_full(name.clone(), namespace.clone(), None, None, None)
+                .await;
+            let already_exists = match existing {
+                Ok(_) => true,
+                Err(e) if matches!(ArgocdError::from_anyhow(&e), Some(ArgocdError::NotFound(_))) => {
+                    false
+                }
+                Err(e) => return Err(e),
+            };
+            let mut problems = spec.problems();
+            if already_exists && !upsert.unwrap_or(false) {
+                problems.push(format!(
+                    "application '{}' already exists; set upsert to update it",
+                    name
+                ));
+            }
+            (spec, Some(already_exists), problems)
+        } else {
+            let created = self
+                .create_application_full(application, upsert, validate)
+                .await?;
+            (created.spec.unwrap_or_default(), None, Vec::new())
+        };
+
+        Ok(ApplicationCreateSummary {
+            name,
+            namespace,
+            dry_run,
+            upsert: upsert.unwrap_or(false),
+            validate: validate.unwrap_or(true),
+            already_exists,
+            problems,
+            spec: ApplicationSpecSummary::from_spec(&spec),
+        })
+    }
+
+    /// Create an application (returns the full Application object)
+    /// This method is part of the public API and used in tests
+    pub async fn create_application_full(
+        &self,
+        application: Application,
+        upsert: Option<bool>,
+        validate: Option<bool>,
+    ) -> Result<Application> {
+        let request = ApiRequest::post("create_application", "/api/v1/applications")
+            .query_opt("upsert", upsert)
+            .query_opt("validate", validate)
+            .json(&application);
+
+        self.execute_json(request, "Application").await
+    }
+
+    /// Replace an application's spec
+    /// Returns which top-level fields changed; dry runs only compare and check
+    pub async fn update_application_spec(
+        &self,
+        name: String,
+        spec: ApplicationSpec,
+        validate: Option<bool>,
+        dry_run: Option<bool>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationSpecUpdateSummary> {
+        let current = self
+            .get_application_full(
+                name.clone(),
+                app_namespace.clone(),
+                project.clone(),
+                None,
+                None,
+            )
+            .await?
+            .spec
+            .unwrap_or_default();
+        let changed_fields = spec.changed_fields(&current);
+        let dry_run = dry_run.unwrap_or(false);
+
+        let (spec, problems) = if dry_run {
+            let problems = spec.problems();
+            (spec, problems)
+        } else {
+            let updated = self
+                .update_application_spec_full(name.clone(), spec, validate, app_namespace, project)
+                .await?;
+            (updated, Vec::new())
+        };
+
+        Ok(ApplicationSpecUpdateSummary {
+            name,
+            dry_run,
+            validate: validate.unwrap_or(true),
+            changed_fields,
+            problems,
+            spec: ApplicationSpecSummary::from_spec(&spec),
+        })
+    }
+
+    /// Replace an application's spec (returns the spec ArgoCD stored)
+    /// This method is part of the public API and used in tests
+    pub async fn update_application_spec_full(
+        &self,
+        name: String,
+        spec: ApplicationSpec,
+        validate: Option<bool>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationSpec> {
+        let request = ApiRequest::put("update_application_spec", application_path(&name, "/spec"))
+            .query_opt("validate", validate)
+            .query_opt("appNamespace", app_namespace)
+            .query_opt("project", project)
+            .json(&spec);
+
+        self.execute_json(request, "ApplicationSpec").await
+    }
+
+    /// Delete an application, by default together with its resources
+    /// Dry runs report the resources a cascading delete would remove
+    pub async fn delete_application(
+        &self,
+        name: String,
+        cascade: Option<bool>,
+        propagation_policy: Option<String>,
+        dry_run: Option<bool>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<ApplicationDeleteSummary> {
+        let dry_run = dry_run.unwrap_or(false);
+        let tree = self
+            .resource_tree_full(
+                name.clone(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                app_namespace.clone(),
+                project.clone(),
+            )
+            .await;
+        // A dry run must fail for a missing application; a real delete goes
+        // ahead without the resource count
+        let tree = match tree {
+            Ok(tree) => Some(tree),
+            Err(e) if dry_run => return Err(e),
+            Err(_) => None,
+        };
+
+        if !dry_run {
+            self.delete_application_full(
+                name.clone(),
+                cascade,
+                propagation_policy.clone(),
+                app_namespace,
+                project,
+            )
+            .await?;
+        }
+
+        Ok(ApplicationDeleteSummary::new(
+            name,
+            dry_run,
+            cascade.unwrap_or(true),
+            propagation_policy,
+            tree.as_ref(),
+        ))
+    }
+
+    /// Delete an application
+    /// This method is part of the public API and used in tests
+    pub async fn delete_application_full(
+        &self,
+        name: String,
+        cascade: Option<bool>,
+        propagation_policy: Option<String>,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<()> {
+        let request = ApiRequest::delete("delete_application", application_path(&name, ""))
+            .query_opt("cascade", cascade)
+            .query_opt("propagationPolicy", propagation_policy)
+            .query_opt("appNamespace", app_namespace)
+            .query_opt("project", project);
+
+        self.execute(request).await.map(|_| ())
+    }
+
+    /// List AppProjects
+    /// Returns optimized summaries to save context window
+    pub async fn list_projects(&self, name: Option<String>) -> Result<Vec<ProjectSummary>> {
+        let list = self.list_projects_full(name).await?;
+        Ok(list.items.iter().map(ProjectSummary::from).collect())
+    }
+
+    /// List AppProjects (returns full AppProjectList)
+    /// This method is part of the public API and used in tests
+    pub async fn list_projects_full(&self, name: Option<String>) -> Result<AppProjectList> {
+        let request = ApiRequest::get("list_projects", "/api/v1/projects").query_opt("name", name);
+
+        self.execute_json(request, "AppProjectList").await
+    }
+
+    /// Get an AppProject with its repositories, destinations, resource lists,
+    /// roles and sync windows
+    pub async fn get_project(&self, name: String) -> Result<ProjectDetail> {
+        let project = self.get_project_full(name).await?;
+        Ok(ProjectDetail::from_project(&project))
+    }
+
+    /// Get an AppProject (returns full AppProject object)
+    /// This method is part of the public API and used in tests
+    pub async fn get_project_full(&self, name: String) -> Result<AppProject> {
+        let request = ApiRequest::get("get_project", project_path(&name, ""));
+
+        self.execute_json(request, "AppProject").await
+    }
+
+    /// List Kubernetes events of an AppProject
+    pub async fn get_project_events(&self, name: String) -> Result<EventListSummary> {
+        let request = ApiRequest::get("get_project_events", project_path(&name, "/events"));
+        let events: EventList = self.execute_json(request, "EventList").await?;
+
+        Ok(EventListSummary::from(events))
+    }
+
+    /// Create an AppProject, or replace its spec when `upsert` is set
+    pub async fn create_project(
+        &self,
+        name: String,
+        spec: AppProjectSpec,
+        upsert: Option<bool>,
+    ) -> Result<ProjectDetail> {
+        let project = AppProject {
+            metadata: Some(ObjectMeta {
+                name,
+                ..ObjectMeta::default()
+            }),
+            spec: Some(spec),
+            status: None,
+        };
+        let request = ApiRequest::post("create_project", "/api/v1/projects").json(
+            &serde_json::json!({ "project": project, "upsert": upsert.unwrap_or(false) }),
+        );
+        let created: AppProject = self.execute_json(request, "AppProject").await?;
+
+        Ok(ProjectDetail::from_project(&created))
+    }
+
+    /// Replace the spec of an AppProject
+    /// Returns which top-level spec fields changed
+    pub async fn update_project(
+        &self,
+        name: String,
+        mut spec: AppProjectSpec,
+    ) -> Result<ProjectUpdateSummary> {
+        let mut project = self.get_project_full(name).await?;
+        let current = project.spec.clone().unwrap_or_default();
+
+        // Roles that are kept keep their tokens
+        for role in spec.roles.iter_mut().flatten() {
+            if role.jwt_tokens.is_none() {
+                role.jwt_tokens = current
+                    .roles
+                    .iter()
+                    .flatten()
+                    .find(|r| r.name == role.name)
+                    .and_then(|r| r.jwt_tokens.clone());
+            }
+        }
+
+        let changed_fields = changed_fields(&current, &spec);
+        project.spec = Some(spec);
+        let updated = self.update_project_full(project).await?;
+
+        Ok(ProjectUpdateSummary {
+            changed_fields,
+            project: ProjectDetail::from_project(&updated),
+        })
+    }
+
+    /// Update an AppProject read earlier (its resourceVersion guards
+    /// against overwriting concurrent changes)
+    /// This method is part of the public API and used in tests
+    pub async fn update_project_full(&self, project: AppProject) -> Result<AppProject> {
+        let request = ApiRequest::put("update_project", project_path(&project.name(), ""))
+            .json(&serde_json::json!({ "project": project }));
+
+        self.execute_json(request, "AppProject").await
+    }
+
+    /// Delete an AppProject (ArgoCD refuses while applications still use it)
+    pub async fn delete_project(&self, name: String) -> Result<()> {
+        let request = ApiRequest::delete("delete_project", project_path(&name, ""));
+
+        self.execute(request).await.map(|_| ())
+    }
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
+    /// Create a project role, or replace its description, policies and
+    /// groups if it exists (its tokens are kept)
+    pub async fn upsert_project_role(
+        &self,
+        project: String,
+        role: ProjectRole,
+    ) -> Result<ProjectRoleChangeSummary> {
+        let mut current = self.get_project_full(project.clone()).await?;
+        let roles = current
+            .spec
+            .get_or_insert_with(AppProjectSpec::default)
+            .roles
+            .get_or_insert_with(Vec::new);
+        let role_name = role.name.clone();
+        let action = match roles.iter_mut().find(|r| r.name == role.name) {
+            Some(existing) => {
+                existing.description = role.description;
+                existing.policies = role.policies;
+                existing.groups = role.groups;
+                "updated"
+            }
+            None => {
+                roles.push(role);
+                "created"
+            }
+        };
+        let updated = self.update_project_full(current).await?;
 
-        let app = response
-            .json::<Application>()
-            .await
-            .context("Failed to parse Application response")?;
+        Ok(ProjectRoleChangeSummary {
+            project,
+            role: role_name,
+            action: action.to_string(),
+            roles: ProjectDetail::from_project(&updated).roles,
+        })
+    }
 
-        Ok(app)
+    /// Delete a project role; tokens issued for it stop working
+    pub async fn delete_project_role(
+        &self,
+        project: String,
+        role: String,
+    ) -> Result<ProjectRoleChangeSummary> {
+        let mut current = self.get_project_full(project.clone()).await?;
+        let roles = current
+            .spec
+            .as_mut()
+            .and_then(|s| s.roles.as_mut())
+            .filter(|roles| roles.iter().any(|r| r.name == role))
+            .ok_or_else(|| {
+                ArgocdError::from_response(
+                    StatusCode::NOT_FOUND,
+                    None,
+                    format!("role '{}' does not exist in project '{}'", role, project),
+                )
+            })?;
+        roles.retain(|r| r.name != role);
+        let updated = self.update_project_full(current).await?;
+
+        Ok(ProjectRoleChangeSummary {
+            project,
+            role,
+            action: "deleted".to_string(),
+            roles: ProjectDetail::from_project(&updated).roles,
+        })
     }
 
-    /// Get a specific resource from an ArgoCD application
-    /// Returns optimized summary to save context window
-    pub async fn get_resource(
+    /// Issue a JWT token for a project role
+    /// The token is only ever returned here; ArgoCD keeps just its metadata
+    pub async fn create_project_token(
         &self,
-        application_name: String,
-        namespace: Option<String>,
-        resource_name: String,
-        version: String,
-        group: Option<String>,
-        kind: String,
-        app_namespace: Option<String>,
-        project: Option<String>,
-    ) -> Result<ApplicationResourceSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/resource",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
+        project: String,
+        role: String,
+        description: Option<String>,
+        expires_in: Option<i64>,
+        id: Option<String>,
+    ) -> Result<ProjectTokenSummary> {
+        let request = ApiRequest::post(
+            "create_project_token",
+            project_path(&project, &format!("/roles/{}/token", urlencoding::encode(&role))),
+        )
+        .json(&serde_json::json!({
+            "project": project,
+            "role": role,
+            "description": description,
+            "expiresIn": expires_in.unwrap_or(0),
+            "id": id,
+        }));
 
-        if let Some(ns) = &namespace {
-            params.push(format!("namespace={}", urlencoding::encode(ns)));
-        }
-        params.push(format!("resourceName={}", urlencoding::encode(&resource_name)));
-        params.push(format!("version={}", urlencoding::encode(&version)));
-        if let Some(g) = &group {
-            params.push(format!("group={}", urlencoding::encode(g)));
-        }
-        params.push(format!("kind={}", urlencoding::encode(&kind)));
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            token: String,
         }
+        let response: TokenResponse = self.execute_json(request, "ProjectTokenResponse").await?;
 
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
+        let claims = jwt_claims(&response.token).unwrap_or_default();
+        let claim = |key: &str| claims.get(key).and_then(|v| v.as_i64());
+        let mut summary = ProjectTokenSummary::from(&JwtToken {
+            iat: claim("iat").unwrap_or_default(),
+            exp: claim("exp"),
+            id: claims.get("jti").and_then(|v| v.as_str()).map(str::to_string),
+        });
+        summary.token = Some(response.token);
+        Ok(summary)
+    }
 
-        tracing::debug!("Fetching resource from: {}", url);
+    /// Revoke a project role token, identified by its issued-at time (and ID if it has one)
+    pub async fn delete_project_token(
+        &self,
+        project: String,
+        role: String,
+        issued_at: i64,
+        id: Option<String>,
+    ) -> Result<()> {
+        let request = ApiRequest::delete(
+            "delete_project_token",
+            project_path(
+                &project,
+                &format!("/roles/{}/token/{}", urlencoding::encode(&role), issued_at),
+            ),
+        )
+        .query_opt("id", id);
+
+        self.execute(request).await.map(|_| ())
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
+    /// List clusters registered in ArgoCD, with the applications targeting each
+    /// Returns optimized summaries to save context window
+    pub async fn list_clusters(&self) -> Result<Vec<ClusterSummary>> {
+        let clusters = self.list_clusters_full().await?;
+        let applications = self
+            .list_applications_full(None, None, None, None, None)
+            .await?;
 
-        let status = response.status();
+        Ok(clusters
+            .items
+            .iter()
+            .map(|cluster| ClusterSummary::new(cluster, &applications.items))
+            .collect())
+    }
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
+    /// List clusters (returns full ClusterList)
+    /// This method is part of the public API and used in tests
+    pub async fn list_clusters_full(&self) -> Result<ClusterList> {
+        let request = ApiRequest::get("list_clusters", "/api/v1/clusters");
 
-        let resource_response = response
-            .json::<ApplicationResourceResponse>()
-            .await
-            .context("Failed to parse ApplicationResourceResponse")?;
+        self.execute_json(request, "ClusterList").await
+    }
 
-        // Convert to optimized summary
-        let manifest = resource_response.manifest.unwrap_or_default();
-        Ok(ApplicationResourceSummary::from_manifest(
-            application_name,
-            kind,
-            resource_name,
-            namespace,
-            version,
-            group,
-            manifest,
-        ))
+    /// Get a cluster by server URL or name, with the applications targeting it
+    pub async fn get_cluster(&self, cluster: String) -> Result<ClusterDetail> {
+        let details = self.get_cluster_full(cluster).await?;
+        let applications = self
+            .list_applications_full(None, None, None, None, None)
+            .await?;
+
+        Ok(ClusterDetail::new(&details, &applications.items))
     }
 
-    /// Get full resource response (not optimized)
+    /// Get a cluster by server URL or name (returns full Cluster object)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
-    pub async fn get_resource_full(
-        &self,
-        application_name: String,
-        namespace: Option<String>,
-        resource_name: String,
-        version: String,
-        group: Option<String>,
-        kind: String,
-        app_namespace: Option<String>,
-        project: Option<String>,
-    ) -> Result<ApplicationResourceResponse> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/resource",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
+    pub async fn get_cluster_full(&self, cluster: String) -> Result<Cluster> {
+        let (path, id_type) = cluster_path(&cluster, "");
+        let request = ApiRequest::get("get_cluster", path).query("id.type", id_type);
 
-        if let Some(ns) = &namespace {
-            params.push(format!("namespace={}", urlencoding::encode(ns)));
-        }
-        params.push(format!("resourceName={}", urlencoding::encode(&resource_name)));
-        params.push(format!("version={}", urlencoding::encode(&version)));
-        if let Some(g) = &group {
-            params.push(format!("group={}", urlencoding::encode(g)));
-        }
-        params.push(format!("kind={}", urlencoding::encode(&kind)));
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
+        self.execute_json(request, "Cluster").await
+    }
 
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
+    /// Drop ArgoCD's cached view of a cluster so it is rebuilt from the API server
+    pub async fn invalidate_cluster_cache(&self, cluster: String) -> Result<ClusterActionSummary> {
+        let (path, id_type) = cluster_path(&cluster, "/invalidate-cache");
+        let request =
+            ApiRequest::post("invalidate_cluster_cache", path).query("id.type", id_type);
+        let updated: Cluster = self.execute_json(request, "Cluster").await?;
+
+        Ok(ClusterActionSummary {
+            cluster,
+            action: "cache invalidated".to_string(),
+            refresh_requested_at: updated.refresh_requested_at,
+        })
+    }
 
-        tracing::debug!("Fetching resource from: {}", url);
+    /// Rotate the bearer token ArgoCD uses for a cluster (clusters added with
+    /// a service account token only)
+    pub async fn rotate_cluster_auth(&self, cluster: String) -> Result<ClusterActionSummary> {
+        let (path, id_type) = cluster_path(&cluster, "/rotate-auth");
+        let request = ApiRequest::post("rotate_cluster_auth", path).query("id.type", id_type);
+        self.execute(request).await?;
+
+        Ok(ClusterActionSummary {
+            cluster,
+            action: "auth rotated".to_string(),
+            refresh_requested_at: None,
+        })
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
+    /// List repositories and credential templates, with credentials redacted
+    pub async fn list_repositories(&self) -> Result<RepositoryListSummary> {
+        let repositories = self.list_repositories_full().await?;
+        let credentials = self.list_repository_credentials_full().await?;
 
-        let status = response.status();
+        Ok(RepositoryListSummary {
+            repositories: repositories.items.iter().map(RepositorySummary::from).collect(),
+            credential_templates: credentials.items.iter().map(RepoCredsSummary::from).collect(),
+        })
+    }
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-        }
+    /// List repositories (returns full RepositoryList)
+    /// This method is part of the public API and used in tests
+    pub async fn list_repositories_full(&self) -> Result<RepositoryList> {
+        let request = ApiRequest::get("list_repositories", "/api/v1/repositories");
 
-        let resource_response = response
-            .json::<ApplicationResourceResponse>()
-            .await
-            .context("Failed to parse ApplicationResourceResponse")?;
+        self.execute_json(request, "RepositoryList").await
+    }
+
+    /// List repository credential templates (returns full RepoCredsList)
+    /// This method is part of the public API and used in tests
+    pub async fn list_repository_credentials_full(&self) -> Result<RepoCredsList> {
+        let request = ApiRequest::get("list_repository_credentials", "/api/v1/repocreds");
 
-        Ok(resource_response)
+        self.execute_json(request, "RepoCredsList").await
     }
 
-    /// Patch a specific resource in an ArgoCD application
-    /// Returns optimized summary to save context window
-    #[allow(clippy::too_many_arguments)]
-    pub async fn patch_resource(
+    /// Get a repository and its connection state, with credentials redacted
+    /// `force_refresh` makes ArgoCD test the connection again instead of
+    /// reporting the cached state
+    pub async fn get_repository(
         &self,
-        application_name: String,
-        namespace: Option<String>,
-        resource_name: String,
-        version: String,
-        group: Option<String>,
-        kind: String,
-        patch: String,
-        patch_type: Option<String>,
-        app_namespace: Option<String>,
-        project: Option<String>,
-    ) -> Result<ApplicationResourceSummary> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/resource",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
+        repo: String,
+        force_refresh: Option<bool>,
+    ) -> Result<RepositorySummary> {
+        let repository = self.get_repository_full(repo, force_refresh).await?;
+        Ok(RepositorySummary::from(&repository))
+    }
 
-        if let Some(ns) = &namespace {
-            params.push(format!("namespace={}", urlencoding::encode(ns)));
-        }
-        params.push(format!("resourceName={}", urlencoding::encode(&resource_name)));
-        params.push(format!("version={}", urlencoding::encode(&version)));
-        if let Some(g) = &group {
-            params.push(format!("group={}", urlencoding::encode(g)));
-        }
-        params.push(format!("kind={}", urlencoding::encode(&kind)));
-        if let Some(pt) = &patch_type {
-            params.push(format!("patchType={}", urlencoding::encode(pt)));
-        }
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
+    /// Get a repository (returns full Repository object)
+    /// This method is part of the public API and used in tests
+    pub async fn get_repository_full(
+        &self,
+        repo: String,
+        force_refresh: Option<bool>,
+    ) -> Result<Repository> {
+        let request = ApiRequest::get("get_repository", repository_path(&repo, ""))
+            .query_opt("forceRefresh", force_refresh);
+
+        self.execute_json(request, "Repository").await
+    }
 
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
+    /// Check whether ArgoCD can access a repository with the given credentials
+    /// A rejection is reported in the summary; server failures are returned as errors
+    pub async fn validate_repository_access(
+        &self,
+        query: RepoAccessQuery,
+    ) -> Result<RepositoryValidationSummary> {
+        let repo = redact_url_credentials(&query.repo);
+        let request = ApiRequest::post(
+            "validate_repository_access",
+            repository_path(&query.repo, "/validate"),
+        )
+        .json(&query);
+
+        match self.execute(request).await {
+            Ok(_) => Ok(RepositoryValidationSummary {
+                repo,
+                valid: true,
+                message: None,
+            }),
+            Err(e) => match ArgocdError::from_anyhow(&e) {
+                // ArgoCD reports failed Git/Helm access as InvalidArgument
+                Some(ArgocdError::BadRequest(failure)) => {
+                    Ok(RepositoryValidationSummary {
+                        repo,
+                        valid: false,
+                        message: Some(redact_url_credentials(&failure.message)),
+                    })
+                }
+                Some(error) => Err(error.clone().map_message(redact_url_credentials).into()),
+                None => Err(anyhow::anyhow!(redact_url_credentials(&e.to_string()))),
+            },
         }
+    }
 
-        tracing::debug!("Patching resource at: {}", url);
+    /// List the branches and tags of a Git repository, keeping at most `limit` of each
+    pub async fn list_repo_refs(&self, repo: String, limit: usize) -> Result<RepoRefsSummary> {
+        let request = ApiRequest::get("list_repo_refs", repository_path(&repo, "/refs"));
+        let refs: RepoRefs = self.execute_json(request, "Refs").await?;
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .body(patch.clone())
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
+        Ok(RepoRefsSummary::new(&repo, refs, limit))
+    }
 
-        let status = response.status();
+    /// Find the applications (Helm charts, Kustomize and plain directories)
+    /// in a Git repository at a revision
+    pub async fn list_repo_apps(
+        &self,
+        repo: String,
+        revision: Option<String>,
+        app_name: Option<String>,
+        app_project: Option<String>,
+    ) -> Result<Vec<RepoAppInfo>> {
+        let request = ApiRequest::get("list_repo_apps", repository_path(&repo, "/apps"))
+            .query_opt("revision", revision)
+            .query_opt("appName", app_name)
+            .query_opt("appProject", app_project);
+        let response: RepoAppsResponse = self.execute_json(request, "RepoAppsResponse").await?;
+
+        Ok(response.items)
+    }
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-
-            // Try to parse as JSON error
-            if let Ok(err) = serde_json::from_str::<ErrorResponse>(&error_text) {
-                let msg = if !err.message.is_empty() {
-                    err.message
-                } else if !err.error.is_empty() {
-                    err.error
-                } else {
-                    error_text
-                };
-                anyhow::bail!("ArgoCD API error ({}): {}", status, msg);
-            } else {
-                anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
-            }
-        }
+    /// List the charts of a Helm repository with their most recent versions
+    pub async fn get_helm_charts(&self, repo: String) -> Result<Vec<HelmChartSummary>> {
+        let request = ApiRequest::get("get_helm_charts", repository_path(&repo, "/helmcharts"));
+        let response: HelmChartsResponse =
+            self.execute_json(request, "HelmChartsResponse").await?;
 
-        let resource_response = response
-            .json::<ApplicationResourceResponse>()
-            .await
-            .context("Failed to parse ApplicationResourceResponse")?;
+        Ok(response.items.iter().map(HelmChartSummary::from).collect())
+    }
 
-        // Convert to optimized summary
-        let manifest = resource_response.manifest.unwrap_or_default();
-        Ok(ApplicationResourceSummary::from_manifest(
-            application_name,
-            kind,
-            resource_name,
-            namespace,
-            version,
-            group,
-            manifest,
-        ))
+    /// List ApplicationSets
+    /// Returns optimized summaries to save context window
+    pub async fn list_applicationsets(
+        &self,
+        projects: Option<Vec<String>>,
+        selector: Option<String>,
+        appset_namespace: Option<String>,
+    ) -> Result<Vec<ApplicationSetSummary>> {
+        let list = self
+            .list_applicationsets_full(projects, selector, appset_namespace)
+            .await?;
+        Ok(list.items.iter().map(ApplicationSetSummary::from).collect())
     }
 
-    /// Patch resource (returns full ApplicationResourceResponse)
+    /// List ApplicationSets (returns full ApplicationSetList)
     /// This method is part of the public API and used in tests
-    #[allow(dead_code)]
-    #[allow(clippy::too_many_arguments)]
-    pub async fn patch_resource_full(
+    pub async fn list_applicationsets_full(
         &self,
-        application_name: String,
-        namespace: Option<String>,
-        resource_name: String,
-        version: String,
-        group: Option<String>,
-        kind: String,
-        patch: String,
-        patch_type: Option<String>,
-        app_namespace: Option<String>,
-        project: Option<String>,
-    ) -> Result<ApplicationResourceResponse> {
-        let mut url = format!(
-            "{}/api/v1/applications/{}/resource",
-            self.base_url,
-            urlencoding::encode(&application_name)
-        );
-        let mut params = Vec::new();
+        projects: Option<Vec<String>>,
+        selector: Option<String>,
+        appset_namespace: Option<String>,
+    ) -> Result<ApplicationSetList> {
+        let request = ApiRequest::get("list_applicationsets", "/api/v1/applicationsets")
+            .query_all("projects", projects.unwrap_or_default())
+            .query_opt("selector", selector)
+            .query_opt("appsetNamespace", appset_namespace);
+
+        self.execute_json(request, "ApplicationSetList").await
+    }
 
-        if let Some(ns) = &namespace {
-            params.push(format!("namespace={}", urlencoding::encode(ns)));
-        }
-        params.push(format!("resourceName={}", urlencoding::encode(&resource_name)));
-        params.push(format!("version={}", urlencoding::encode(&version)));
-        if let Some(g) = &group {
-            params.push(format!("group={}", urlencoding::encode(g)));
-        }
-        params.push(format!("kind={}", urlencoding::encode(&kind)));
-        if let Some(pt) = &patch_type {
-            params.push(format!("patchType={}", urlencoding::encode(pt)));
-        }
-        if let Some(ans) = &app_namespace {
-            params.push(format!("appNamespace={}", urlencoding::encode(ans)));
-        }
-        if let Some(p) = &project {
-            params.push(format!("project={}", urlencoding::encode(p)));
-        }
+    /// Get an ApplicationSet with its generators, template, conditions and
+    /// the status of each generated application
+    pub async fn get_applicationset(
+        &self,
+        name: String,
+        appset_namespace: Option<String>,
+    ) -> Result<ApplicationSetDetail> {
+        let appset = self.get_applicationset_full(name, appset_namespace).await?;
+        Ok(ApplicationSetDetail::from_applicationset(&appset))
+    }
 
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
+    /// Get an ApplicationSet (returns full ApplicationSet object)
+    /// This method is part of the public API and used in tests
+    pub async fn get_applicationset_full(
+        &self,
+        name: String,
+        appset_namespace: Option<String>,
+    ) -> Result<ApplicationSet> {
+        let request = ApiRequest::get("get_applicationset", applicationset_path(&name, ""))
+            .query_opt("appsetNamespace", appset_namespace);
 
-        tracing::debug!("Patching resource at: {}", url);
+        self.execute_json(request, "ApplicationSet").await
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.access_token))
-            .header("Accept", "application/json")
-            .header("Content-Type", "application/json")
-            .body(patch)
-            .send()
-            .await
-            .context("Failed to send request to ArgoCD API")?;
+    /// Render the applications an ApplicationSet spec would generate, without
+    /// creating anything
+    pub async fn generate_applicationset_preview(
+        &self,
+        name: String,
+        appset_namespace: Option<String>,
+        spec: serde_json::Value,
+    ) -> Result<ApplicationSetPreviewSummary> {
+        let metadata = ObjectMeta {
+            name: name.clone(),
+            namespace: appset_namespace,
+            ..ObjectMeta::default()
+        };
+        let request = ApiRequest::post(
+            "generate_applicationset_preview",
+            "/api/v1/applicationsets/generate",
+        )
+        .json(&serde_json::json!({
+            "applicationSet": { "metadata": metadata, "spec": spec }
+        }));
+        let response: ApplicationSetGenerateResponse = self
+            .execute_json(request, "ApplicationSetGenerateResponse")
+            .await?;
 
-        let status = response.status();
+        Ok(ApplicationSetPreviewSummary::new(name, &response))
+    }
 
-        if !status.is_success() {
-            let error_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Unknown error".to_string());
-            anyhow::bail!("ArgoCD API error ({}): {}", status, error_text);
+    /// Create an ApplicationSet, or replace it when `upsert` is set
+    /// With `dry_run`, ArgoCD validates the spec and reports the applications
+    /// it would generate without saving anything
+    pub async fn create_applicationset(
+        &self,
+        name: String,
+        appset_namespace: Option<String>,
+        spec: serde_json::Value,
+        upsert: Option<bool>,
+        dry_run: Option<bool>,
+    ) -> Result<ApplicationSetDetail> {
+        let metadata = ObjectMeta {
+            name,
+            namespace: appset_namespace,
+            ..ObjectMeta::default()
+        };
+        let request = ApiRequest::post("create_applicationset", "/api/v1/applicationsets")
+            .query_opt("upsert", upsert)
+            .query_opt("dryRun", dry_run)
+            .json(&serde_json::json!({ "metadata": metadata, "spec": spec }));
+        let created: ApplicationSet = self.execute_json(request, "ApplicationSet").await?;
+
+        Ok(ApplicationSetDetail::from_applicationset(&created))
+    }
+
+    /// Delete an ApplicationSet and the applications it generated
+    /// The ApplicationSet is read first to list those applications; with
+    /// `dry_run` nothing is deleted
+    pub async fn delete_applicationset(
+        &self,
+        name: String,
+        appset_namespace: Option<String>,
+        dry_run: bool,
+    ) -> Result<ApplicationSetDeleteSummary> {
+        let appset = self
+            .get_applicationset(name.clone(), appset_namespace.clone())
+            .await?;
+
+        if !dry_run {
+            let request =
+                ApiRequest::delete("delete_applicationset", applicationset_path(&name, ""))
+                    .query_opt("appsetNamespace", appset_namespace);
+            self.execute(request).await?;
         }
 
-        let resource_response = response
-            .json::<ApplicationResourceResponse>()
-            .await
-            .context("Failed to parse ApplicationResourceResponse")?;
+        Ok(ApplicationSetDeleteSummary {
+            name,
+            dry_run,
+            applications: appset.applications.into_iter().map(|a| a.name).collect(),
+            preserve_resources_on_deletion: appset.preserve_resources_on_deletion,
+        })
+    }
+
+    /// Poll an application until the wait conditions hold, its operation
+    /// fails, or the timeout elapses
+    ///
+    /// `on_progress` is awaited after every poll, including the last one.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn wait_for_application<F, Fut>(
+        &self,
+        name: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+        conditions: &WaitConditions,
+        timeout: Duration,
+        poll_interval: Duration,
+        mut on_progress: F,
+    ) -> Result<ApplicationWaitSummary>
+    where
+        F: FnMut(WaitProgress) -> Fut,
+        Fut: std::future::Future<Output = ()>,
+    {
+        let started = Instant::now();
+        let mut polls = 0;
+        loop {
+            let app = self
+                .get_application_full(
+                    name.clone(),
+                    app_namespace.clone(),
+                    project.clone(),
+                    None,
+                    None,
+                )
+                .await?;
+            polls += 1;
+
+            let elapsed = started.elapsed();
+            let progress = WaitProgress {
+                polls,
+                elapsed_seconds: elapsed.as_secs(),
+                timeout_seconds: timeout.as_secs(),
+                unmet: conditions.unmet(&app),
+            };
+            let outcome = if progress.unmet.is_empty() {
+                Some(WaitOutcome::Succeeded)
+            } else if conditions.failure(&app).is_some() {
+                Some(WaitOutcome::OperationFailed)
+            } else if elapsed + poll_interval > timeout {
+                Some(WaitOutcome::TimedOut)
+            } else {
+                None
+            };
+            on_progress(progress.clone()).await;
+
+            if let Some(outcome) = outcome {
+                return Ok(ApplicationWaitSummary::new(app, conditions, outcome, &progress));
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Watch applications through the streaming API
+    ///
+    /// ArgoCD first sends an ADDED event for every matching application,
+    /// then one event per change until the stream is closed.
+    pub async fn watch_applications(
+        &self,
+        name: Option<String>,
+        app_namespace: Option<String>,
+        projects: Option<Vec<String>>,
+        selector: Option<String>,
+    ) -> Result<WatchStream<ApplicationWatchEvent>> {
+        let request = ApiRequest::get("watch_applications", "/api/v1/stream/applications")
+            .query_opt("name", name)
+            .query_opt("appNamespace", app_namespace)
+            .query_all("projects", projects.unwrap_or_default())
+            .query_opt("selector", selector);
+
+        self.open_stream(request, "ApplicationWatchEvent").await
+    }
 
-        Ok(resource_response)
+    /// Watch an application's resource tree through the streaming API
+    ///
+    /// Every message is the complete tree, starting with the current one.
+    pub async fn watch_resource_tree(
+        &self,
+        name: String,
+        app_namespace: Option<String>,
+        project: Option<String>,
+    ) -> Result<WatchStream<ApplicationTree>> {
+        let request = ApiRequest::get(
+            "watch_resource_tree",
+            format!(
+                "/api/v1/stream/applications/{}/resource-tree",
+                urlencoding::encode(&name)
+            ),
+        )
+        .query_opt("appNamespace", app_namespace)
+        .query_opt("project", project);
+
+        self.open_stream(request, "ApplicationTree").await
     }
 
     /// Get application deployment history
@@ -2253,7 +3008,7 @@ impl ArgocdClient {
             .collect();
 
         // Sort by ID descending (newest first)
-        entries.sort_by(|a, b| b.id.cmp(&a.id));
+        entries.sort_by_key(|e| std::cmp::Reverse(e.id));
 
         let total_entries = entries.len();
 
@@ -2338,21 +3093,27 @@ impl ArgocdClient {
             .and_then(|s| s.sync.as_ref())
             .and_then(|sync| sync.revision.clone());
 
-        // Extract source info
-        let (repo_url, target_revision) = if let Some(spec) = &app_after.spec {
-            if let Some(source) = &spec.source {
-                (Some(source.repo_url.clone()), source.target_revision.clone())
-            } else {
-                (None, None)
-            }
-        } else {
-            (None, None)
-        };
+        // Extract source info (first source of a multi-source application)
+        let (repo_url, target_revision) = app_after
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.primary_source())
+            .map(|source| (Some(source.repo_url), source.target_revision))
+            .unwrap_or((None, None));
+        let sources = app_after.multi_source_summaries();
 
         // Calculate what changed
         let sync_status_changed = sync_status_before != sync_status_after;
         let health_status_changed = health_status_before != health_status_after;
-        let revision_changed = sync_revision_before != sync_revision_after;
+        let source_revisions = |app: &Application| {
+            app.status
+                .as_ref()
+                .and_then(|s| s.sync.as_ref())
+                .map(|sync| sync.source_revisions().to_vec())
+                .unwrap_or_default()
+        };
+        let revision_changed = sync_revision_before != sync_revision_after
+            || source_revisions(&app_before) != source_revisions(&app_after);
 
         Ok(RefreshApplicationSummary {
             application_name,
@@ -2368,6 +3129,7 @@ impl ArgocdClient {
             revision_changed,
             repo_url: repo_url.or_else(|| Some("Unknown".to_string())),
             target_revision,
+            sources,
         })
     }
 }
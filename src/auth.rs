@@ -0,0 +1,445 @@
+use async_trait::async_trait;
+use base64::Engine;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::error::ArgocdError;
+
+/// Session tokens are renewed this long before they expire
+pub const SESSION_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+/// Longest a token command may run before it is killed
+pub const EXEC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How a context authenticates against ArgoCD
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credentials {
+    /// Static bearer token
+    Token(String),
+    /// Username and password exchanged for a session token via `POST /api/v1/session`
+    Login { username: String, password: String },
+    /// Token read from a file, re-read whenever the file changes
+    TokenFile(PathBuf),
+    /// Token printed by a command
+    Exec(ExecConfig),
+}
+
+/// Command that prints a token, in the spirit of kubectl's credential plugins
+#[derive(Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExecConfig {
+    pub command: String,
+    pub args: Vec<String>,
+    /// Extra environment variables for the command
+    pub env: BTreeMap<String, String>,
+}
+
+impl fmt::Debug for ExecConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Env values are often secrets handed to the command; show the names only
+        f.debug_struct("ExecConfig")
+            .field("command", &self.command)
+            .field("args", &self.args)
+            .field("env", &self.env.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl Credentials {
+    /// Short description safe to show to users (never includes secrets)
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Token(_) => "token".to_string(),
+            Self::Login { username, .. } => format!("session login as '{}'", username),
+            Self::TokenFile(path) => format!("token file {}", path.display()),
+            Self::Exec(exec) => format!("token command '{}'", exec.command),
+        }
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Token(_) => f.write_str("Token(<redacted>)"),
+            Self::Login { username, .. } => f
+                .debug_struct("Login")
+                .field("username", username)
+                .field("password", &"<redacted>")
+                .finish(),
+            Self::TokenFile(path) => f.debug_tuple("TokenFile").field(path).finish(),
+            Self::Exec(exec) => f.debug_tuple("Exec").field(exec).finish(),
+        }
+    }
+}
+
+/// A bearer token with its expiry, when known
+#[derive(Clone, PartialEq, Eq)]
+pub struct SessionToken {
+    pub token: String,
+    pub expires_at: Option<SystemTime>,
+}
+
+impl SessionToken {
+    /// Wrap a token, reading the expiry from its JWT `exp` claim
+    pub fn new(token: String) -> Self {
+        let expires_at = jwt_expiry(&token);
+        Self { token, expires_at }
+    }
+
+    /// Whether the token can still be used without renewing it first
+    pub fn is_fresh(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => SystemTime::now() + SESSION_REFRESH_MARGIN < expires_at,
+            None => true,
+        }
+    }
+}
+
+impl fmt::Debug for SessionToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SessionToken")
+            .field("token", &"<redacted>")
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Source of bearer tokens for a context
+///
+/// The client asks for a token before every request, so providers decide
+/// themselves how long a token may be cached.
+#[async_trait]
+pub trait CredentialProvider: Send + Sync {
+    /// Token to send with the next request
+    async fn token(&self) -> Result<SessionToken, ArgocdError>;
+
+    /// Called when ArgoCD rejects `rejected` with 401. Returns whether asking
+    /// for a token again may give a different one.
+    async fn invalidate(&self, _rejected: &str) -> bool {
+        false
+    }
+
+    /// Short description safe to show to users (never includes secrets)
+    fn describe(&self) -> String;
+}
+
+/// A fixed token, e.g. from `ARGOCD_ACCESS_TOKEN`
+pub struct StaticToken(String);
+
+impl StaticToken {
+    pub fn new(token: String) -> Self {
+        Self(token)
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for StaticToken {
+    async fn token(&self) -> Result<SessionToken, ArgocdError> {
+        Ok(SessionToken {
+            token: self.0.clone(),
+            expires_at: None,
+        })
+    }
+
+    fn describe(&self) -> String {
+        "token".to_string()
+    }
+}
+
+/// Token kept in a file by an external agent
+///
+/// The file's modification time and size are checked on every request and
+/// the token is re-read when either changes, so rotations are picked up
+/// without a restart.
+pub struct TokenFile {
+    path: PathBuf,
+    cached: Mutex<Option<CachedFile>>,
+}
+
+struct CachedFile {
+    modified: Option<SystemTime>,
+    len: u64,
+    token: String,
+}
+
+impl TokenFile {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            cached: Mutex::new(None),
+        }
+    }
+
+    fn read_error(&self, error: impl fmt::Display) -> ArgocdError {
+        ArgocdError::Credentials(format!(
+            "Failed to read token file {}: {}",
+            self.path.display(),
+            error
+        ))
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for TokenFile {
+    async fn token(&self) -> Result<SessionToken, ArgocdError> {
+        let metadata = tokio::fs::metadata(&self.path)
+            .await
+            .map_err(|e| self.read_error(e))?;
+        let modified = metadata.modified().ok();
+        let len = metadata.len();
+
+        {
+            let cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(file) = cached
+                .as_ref()
+                .filter(|f| f.modified == modified && f.len == len)
+            {
+                return Ok(SessionToken::new(file.token.clone()));
+            }
+        }
+
+        let token = tokio::fs::read_to_string(&self.path)
+            .await
+            .map_err(|e| self.read_error(e))?
+            .trim()
+            .to_string();
+        if token.is_empty() {
+            return Err(self.read_error("file is empty"));
+        }
+
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        if cached.is_some() {
+            tracing::info!("Token file {} changed; using the new token", self.path.display());
+        }
+        *cached = Some(CachedFile {
+            modified,
+            len,
+            token: token.clone(),
+        });
+        Ok(SessionToken::new(token))
+    }
+
+    async fn invalidate(&self, rejected: &str) -> bool {
+        // The file may have been rewritten within the timestamp resolution
+        let mut cached = self.cached.lock().unwrap_or_else(|e| e.into_inner());
+        if cached.as_ref().is_some_and(|f| f.token == rejected) {
+            *cached = None;
+        }
+        true
+    }
+
+    fn describe(&self) -> String {
+        format!("token file {}", self.path.display())
+    }
+}
+
+/// Token printed by a command, cached until it is about to expire
+///
+/// The command prints either a kubectl-style `ExecCredential` JSON object
+/// (`status.token`, optional `status.expirationTimestamp` in RFC 3339) or a
+/// bare token. Without an explicit expiry the JWT `exp` claim is used.
+pub struct ExecToken {
+    config: ExecConfig,
+    cached: tokio::sync::Mutex<Option<SessionToken>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExecCredential {
+    status: ExecCredentialStatus,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ExecCredentialStatus {
+    token: String,
+    #[serde(default)]
+    expiration_timestamp: Option<String>,
+}
+
+impl ExecToken {
+    pub fn new(config: ExecConfig) -> Self {
+        Self {
+            config,
+            cached: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    async fn run(&self) -> Result<SessionToken, ArgocdError> {
+        let command = &self.config.command;
+        tracing::info!("Running token command '{}'", command);
+
+        let output = tokio::process::Command::new(command)
+            .args(&self.config.args)
+            .envs(&self.config.env)
+            .stdin(Stdio::null())
+            .kill_on_drop(true)
+            .output();
+        let output = tokio::time::timeout(EXEC_TIMEOUT, output)
+            .await
+            .map_err(|_| {
+                ArgocdError::Credentials(format!(
+                    "Token command '{}' did not finish within {}s",
+                    command,
+                    EXEC_TIMEOUT.as_secs()
+                ))
+            })?
+            .map_err(|e| {
+                ArgocdError::Credentials(format!("Failed to run token command '{}': {}", command, e))
+            })?;
+
+        if !output.status.success() {
+            return Err(ArgocdError::Credentials(format!(
+                "Token command '{}' failed ({}): {}",
+                command,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        parse_exec_output(&String::from_utf8_lossy(&output.stdout)).map_err(|e| {
+            ArgocdError::Credentials(format!("Token command '{}' {}", command, e))
+        })
+    }
+}
+
+#[async_trait]
+impl CredentialProvider for ExecToken {
+    async fn token(&self) -> Result<SessionToken, ArgocdError> {
+        // Holding the lock while the command runs makes concurrent callers share one run
+        let mut cached = self.cached.lock().await;
+        if let Some(token) = cached.as_ref().filter(|t| t.is_fresh()) {
+            return Ok(token.clone());
+        }
+
+        let token = self.run().await?;
+        *cached = Some(token.clone());
+        Ok(token)
+    }
+
+    async fn invalidate(&self, rejected: &str) -> bool {
+        let mut cached = self.cached.lock().await;
+        if cached.as_ref().is_some_and(|t| t.token == rejected) {
+            *cached = None;
+        }
+        true
+    }
+
+    fn describe(&self) -> String {
+        format!("token command '{}'", self.config.command)
+    }
+}
+
+/// Parse what a token command printed
+fn parse_exec_output(stdout: &str) -> Result<SessionToken, String> {
+    let stdout = stdout.trim();
+    if stdout.starts_with('{') {
+        let credential: ExecCredential = serde_json::from_str(stdout)
+            .map_err(|e| format!("printed invalid ExecCredential JSON: {}", e))?;
+        let token = credential.status.token.trim().to_string();
+        if token.is_empty() {
+            return Err("printed an empty status.token".to_string());
+        }
+        return match credential.status.expiration_timestamp {
+            Some(timestamp) => {
+                let expires_at = chrono::DateTime::parse_from_rfc3339(&timestamp)
+                    .map_err(|e| format!("printed an invalid expirationTimestamp '{}': {}", timestamp, e))?;
+                Ok(SessionToken {
+                    token,
+                    expires_at: Some(SystemTime::from(expires_at)),
+                })
+            }
+            None => Ok(SessionToken::new(token)),
+        };
+    }
+
+    match stdout.lines().next().map(str::trim) {
+        Some(token) if !token.is_empty() => Ok(SessionToken::new(token.to_string())),
+        _ => Err("printed no token".to_string()),
+    }
+}
+
+/// Unverified claims of a JWT; `None` for opaque tokens
+pub fn jwt_claims(token: &str) -> Option<serde_json::Value> {
+    let payload = token.split('.').nth(1)?;
+    let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload.trim_end_matches('='))
+        .ok()?;
+    serde_json::from_slice(&decoded).ok()
+}
+
+/// Expiry of a JWT from its `exp` claim; `None` for opaque or non-expiring tokens
+pub fn jwt_expiry(token: &str) -> Option<SystemTime> {
+    let exp = jwt_claims(token)?.get("exp")?.as_u64()?;
+    Some(UNIX_EPOCH + Duration::from_secs(exp))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jwt(claims: serde_json::Value) -> String {
+        let encode = |value: &[u8]| base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value);
+        format!(
+            "{}.{}.signature",
+            encode(br#"{"alg":"HS256","typ":"JWT"}"#),
+            encode(claims.to_string().as_bytes())
+        )
+    }
+
+    #[test]
+    fn test_jwt_expiry() {
+        let token = jwt(serde_json::json!({ "sub": "admin", "exp": 1_700_000_000u64 }));
+        assert_eq!(
+            jwt_expiry(&token),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_000))
+        );
+        assert!(!SessionToken::new(token).is_fresh());
+
+        assert_eq!(jwt_expiry(&jwt(serde_json::json!({ "sub": "admin" }))), None);
+        assert_eq!(jwt_expiry("opaque-token"), None);
+        assert!(SessionToken::new("opaque-token".to_string()).is_fresh());
+    }
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let credentials = Credentials::Login {
+            username: "admin".to_string(),
+            password: "hunter2".to_string(),
+        };
+        assert!(!format!("{:?}", credentials).contains("hunter2"));
+        assert!(!format!("{:?}", Credentials::Token("secret".to_string())).contains("secret"));
+
+        let exec = Credentials::Exec(ExecConfig {
+            command: "vault".to_string(),
+            env: BTreeMap::from([("VAULT_TOKEN".to_string(), "s.hunter2".to_string())]),
+            ..ExecConfig::default()
+        });
+        let debug = format!("{:?}", exec);
+        assert!(debug.contains("VAULT_TOKEN"), "{}", debug);
+        assert!(!debug.contains("hunter2"), "{}", debug);
+    }
+
+    #[test]
+    fn test_parse_exec_output() {
+        let token = parse_exec_output(
+            r#"{"apiVersion":"client.authentication.k8s.io/v1","kind":"ExecCredential","status":{"token":"abc","expirationTimestamp":"2030-01-01T00:00:00Z"}}"#,
+        )
+        .unwrap();
+        assert_eq!(token.token, "abc");
+        assert_eq!(
+            token.expires_at,
+            Some(UNIX_EPOCH + Duration::from_secs(1_893_456_000))
+        );
+
+        let token = parse_exec_output("plain-token\n").unwrap();
+        assert_eq!(token.token, "plain-token");
+        assert_eq!(token.expires_at, None);
+
+        assert!(parse_exec_output("").is_err());
+        assert!(parse_exec_output(r#"{"status":{"token":""}}"#).is_err());
+    }
+}
@@ -0,0 +1,1054 @@
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::argocd_client::{normalize_root_path, ClientOptions, DEFAULT_TIMEOUT_SECS};
+use crate::auth::{Credentials, ExecConfig};
+use crate::context::{ContextSettings, ContextsConfig, DEFAULT_CONTEXT_NAME};
+use crate::retry::RetryPolicy;
+use crate::transport::{parse_bind_address, TransportConfig, TransportMode};
+
+/// Limits applied to tool output to keep responses context-efficient
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputLimits {
+    /// `tail_lines` used by pod_logs when the caller does not pass one
+    pub default_tail_lines: i64,
+    /// Upper bound for `tail_lines` requested by the caller
+    pub max_tail_lines: i64,
+    /// Events listed individually by list_resource_events
+    pub max_events: usize,
+    /// Applications listed by list_applications / list_application_names (unlimited when unset)
+    pub max_applications: Option<usize>,
+}
+
+impl Default for OutputLimits {
+    fn default() -> Self {
+        Self {
+            default_tail_lines: 100,
+            max_tail_lines: 5000,
+            max_events: 20,
+            max_applications: None,
+        }
+    }
+}
+
+/// Fully resolved server configuration
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub transport: TransportConfig,
+    pub contexts: ContextsConfig,
+    pub read_only: bool,
+    /// Probe every context with `/api/version` before serving
+    pub startup_probe: bool,
+    pub output: OutputLimits,
+    /// Configuration file the settings were loaded from, if any
+    pub config_file: Option<PathBuf>,
+}
+
+/// Every problem found while loading the configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigError {
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Invalid configuration ({} problem(s)):", self.problems.len())?;
+        for problem in &self.problems {
+            writeln!(f, "  - {}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+// Configuration file structures
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileConfig {
+    read_only: Option<bool>,
+    default_context: Option<String>,
+    server: FileServer,
+    client: FileClient,
+    output: FileOutput,
+    contexts: BTreeMap<String, FileContext>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileServer {
+    transport: Option<String>,
+    bind: Option<String>,
+    startup_probe: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileClient {
+    timeout_secs: Option<u64>,
+    insecure: Option<bool>,
+    ca_cert: Option<PathBuf>,
+    client_cert: Option<PathBuf>,
+    client_key: Option<PathBuf>,
+    proxy: Option<String>,
+    no_proxy: Option<String>,
+    root_path: Option<String>,
+    max_retries: Option<u32>,
+    retry_initial_backoff_ms: Option<u64>,
+    retry_max_backoff_ms: Option<u64>,
+    retry_mutations: Option<bool>,
+}
+
+impl FileClient {
+    /// Fill the fields this layer leaves unset from a lower-priority layer
+    fn or(self, fallback: &FileClient) -> FileClient {
+        FileClient {
+            timeout_secs: self.timeout_secs.or(fallback.timeout_secs),
+            insecure: self.insecure.or(fallback.insecure),
+            ca_cert: self.ca_cert.or_else(|| fallback.ca_cert.clone()),
+            client_cert: self.client_cert.or_else(|| fallback.client_cert.clone()),
+            client_key: self.client_key.or_else(|| fallback.client_key.clone()),
+            proxy: self.proxy.or_else(|| fallback.proxy.clone()),
+            no_proxy: self.no_proxy.or_else(|| fallback.no_proxy.clone()),
+            root_path: self.root_path.or_else(|| fallback.root_path.clone()),
+            max_retries: self.max_retries.or(fallback.max_retries),
+            retry_initial_backoff_ms: self
+                .retry_initial_backoff_ms
+                .or(fallback.retry_initial_backoff_ms),
+            retry_max_backoff_ms: self.retry_max_backoff_ms.or(fallback.retry_max_backoff_ms),
+            retry_mutations: self.retry_mutations.or(fallback.retry_mutations),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileOutput {
+    default_tail_lines: Option<i64>,
+    max_tail_lines: Option<i64>,
+    max_events: Option<usize>,
+    max_applications: Option<usize>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FileContext {
+    base_url: Option<String>,
+    access_token: Option<String>,
+    access_token_env: Option<String>,
+    access_token_file: Option<PathBuf>,
+    token_command: Option<ExecConfig>,
+    username: Option<String>,
+    password: Option<String>,
+    password_env: Option<String>,
+    password_file: Option<PathBuf>,
+    timeout_secs: Option<u64>,
+    insecure: Option<bool>,
+    ca_cert: Option<PathBuf>,
+    client_cert: Option<PathBuf>,
+    client_key: Option<PathBuf>,
+    proxy: Option<String>,
+    no_proxy: Option<String>,
+    root_path: Option<String>,
+}
+
+/// Settings accumulated layer by layer (file, then env, then CLI)
+#[derive(Debug, Default)]
+struct Layered {
+    mode: Option<TransportMode>,
+    bind: Option<std::net::SocketAddr>,
+    read_only: Option<bool>,
+    startup_probe: Option<bool>,
+    default_context: Option<String>,
+    /// Client settings from the file's `[client]` table
+    client: FileClient,
+    /// Client settings from env and CLI; they beat per-context file values too
+    client_overrides: FileClient,
+    output: FileOutput,
+    contexts: BTreeMap<String, LayeredContext>,
+}
+
+#[derive(Debug, Default)]
+struct LayeredContext {
+    base_url: Option<String>,
+    access_token: Option<String>,
+    access_token_file: Option<PathBuf>,
+    token_command: Option<ExecConfig>,
+    username: Option<String>,
+    password: Option<String>,
+    client: FileClient,
+}
+
+/// Command line flags understood by the server
+#[derive(Debug, Default)]
+struct CliArgs {
+    config: Option<String>,
+    transport: Option<String>,
+    bind: Option<String>,
+    read_only: Option<String>,
+    insecure: Option<String>,
+    timeout_secs: Option<String>,
+    max_retries: Option<String>,
+    root_path: Option<String>,
+    startup_probe: Option<String>,
+    default_context: Option<String>,
+}
+
+impl Config {
+    /// Load configuration from an optional file, environment variables and
+    /// command line flags (later layers win).
+    ///
+    /// The file is taken from `--config <path>` or `ARGOCD_MCP_CONFIG` and is
+    /// parsed as TOML (`.toml`) or YAML (`.yaml`/`.yml`). All problems are
+    /// collected and returned together.
+    pub fn load<I>(args: I) -> Result<Self, ConfigError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut problems = Vec::new();
+        let cli = parse_cli(args, &mut problems);
+        let mut layered = Layered::default();
+
+        let config_file = cli
+            .config
+            .clone()
+            .or_else(|| env_var("ARGOCD_MCP_CONFIG"))
+            .map(PathBuf::from);
+        if let Some(path) = &config_file {
+            if let Some(file) = read_file(path, &mut problems) {
+                apply_file(&mut layered, file, path, &mut problems);
+            }
+        }
+
+        apply_env(&mut layered, &mut problems);
+        apply_cli(&mut layered, cli, &mut problems);
+
+        let config = resolve(layered, config_file, &mut problems);
+        if problems.is_empty() {
+            Ok(config)
+        } else {
+            Err(ConfigError { problems })
+        }
+    }
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// Environment variable prefix for a named context (`prod-eu` -> `ARGOCD_CONTEXT_PROD_EU`)
+pub fn context_env_prefix(name: &str) -> String {
+    let key: String = name
+        .chars()
+        .map(|c| match c {
+            '-' | '.' => '_',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect();
+    format!("ARGOCD_CONTEXT_{}", key)
+}
+
+fn parse_value<T>(origin: &str, raw: &str, problems: &mut Vec<String>) -> Option<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    match raw.trim().parse::<T>() {
+        Ok(value) => Some(value),
+        Err(e) => {
+            problems.push(format!("{}: invalid value '{}': {}", origin, raw, e));
+            None
+        }
+    }
+}
+
+fn parse_bool(origin: &str, raw: &str, problems: &mut Vec<String>) -> Option<bool> {
+    parse_value::<bool>(origin, &raw.to_lowercase(), problems)
+}
+
+fn parse_cli<I>(args: I, problems: &mut Vec<String>) -> CliArgs
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut cli = CliArgs::default();
+    let mut args = args.into_iter().peekable();
+
+    while let Some(arg) = args.next() {
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (flag.to_string(), Some(value.to_string())),
+            None => (arg.clone(), None),
+        };
+
+        // Boolean switches may be passed without a value, or with an explicit
+        // true/false to override an earlier layer
+        let is_switch = matches!(
+            flag.as_str(),
+            "--read-only" | "--insecure" | "--startup-probe"
+        );
+        let mut value = || -> Option<String> {
+            if inline_value.is_some() {
+                return inline_value.clone();
+            }
+            if is_switch {
+                let explicit =
+                    args.next_if(|next| matches!(next.to_lowercase().as_str(), "true" | "false"));
+                return Some(explicit.unwrap_or_else(|| "true".to_string()));
+            }
+            match args.next_if(|next| !next.starts_with("--")) {
+                Some(next) => Some(next),
+                None => {
+                    problems.push(format!("{} requires a value", flag));
+                    None
+                }
+            }
+        };
+
+        match flag.as_str() {
+            "--config" => cli.config = value(),
+            "--transport" => cli.transport = value(),
+            "--bind" => cli.bind = value(),
+            "--read-only" => cli.read_only = value(),
+            "--insecure" => cli.insecure = value(),
+            "--timeout" => cli.timeout_secs = value(),
+            "--max-retries" => cli.max_retries = value(),
+            "--rootpath" => cli.root_path = value(),
+            "--startup-probe" => cli.startup_probe = value(),
+            "--default-context" => cli.default_context = value(),
+            other => problems.push(format!(
+                "Unknown argument '{}'. Supported flags: --config, --transport, --bind, --read-only, --insecure, --timeout, --max-retries, --rootpath, --startup-probe, --default-context",
+                other
+            )),
+        }
+    }
+
+    cli
+}
+
+fn read_file(path: &Path, problems: &mut Vec<String>) -> Option<FileConfig> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            problems.push(format!("Failed to read config file {}: {}", path.display(), e));
+            return None;
+        }
+    };
+
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase);
+    let parsed = match extension.as_deref() {
+        Some("toml") => toml::from_str::<FileConfig>(&contents).map_err(|e| e.to_string()),
+        Some("yaml") | Some("yml") => {
+            serde_yaml::from_str::<FileConfig>(&contents).map_err(|e| e.to_string())
+        }
+        _ => Err("unsupported file extension (expected .toml, .yaml or .yml)".to_string()),
+    };
+
+    match parsed {
+        Ok(file) => Some(file),
+        Err(e) => {
+            problems.push(format!("Invalid config file {}: {}", path.display(), e.trim()));
+            None
+        }
+    }
+}
+
+fn apply_file(layered: &mut Layered, file: FileConfig, path: &Path, problems: &mut Vec<String>) {
+    let origin = path.display().to_string();
+
+    if let Some(transport) = &file.server.transport {
+        layered.mode = parse_value(&format!("{} server.transport", origin), transport, problems);
+    }
+    if let Some(bind) = &file.server.bind {
+        match parse_bind_address(bind) {
+            Ok(addr) => layered.bind = Some(addr),
+            Err(e) => problems.push(format!("{} server.bind: {}", origin, e)),
+        }
+    }
+    layered.read_only = file.read_only;
+    layered.startup_probe = file.server.startup_probe;
+    layered.default_context = file.default_context;
+    layered.client = file.client;
+    layered.output = file.output;
+
+    for (name, context) in file.contexts {
+        // access_token_file is read at request time so rotated tokens are picked up
+        let access_token = read_secret(
+            &format!("{} contexts.{}", origin, name),
+            "access_token",
+            context.access_token,
+            context.access_token_env.as_deref(),
+            None,
+            problems,
+        );
+        let password = read_secret(
+            &format!("{} contexts.{}", origin, name),
+            "password",
+            context.password,
+            context.password_env.as_deref(),
+            context.password_file.as_deref(),
+            problems,
+        );
+
+        layered.contexts.insert(
+            name,
+            LayeredContext {
+                base_url: context.base_url,
+                access_token,
+                access_token_file: context.access_token_file,
+                token_command: context.token_command,
+                username: context.username,
+                password,
+                client: FileClient {
+                    timeout_secs: context.timeout_secs,
+                    insecure: context.insecure,
+                    ca_cert: context.ca_cert,
+                    client_cert: context.client_cert,
+                    client_key: context.client_key,
+                    proxy: context.proxy,
+                    no_proxy: context.no_proxy,
+                    root_path: context.root_path,
+                    ..FileClient::default()
+                },
+            },
+        );
+    }
+}
+
+/// Read a secret given inline, through `<key>_env` or through `<key>_file`
+fn read_secret(
+    origin: &str,
+    key: &str,
+    inline: Option<String>,
+    env: Option<&str>,
+    file: Option<&Path>,
+    problems: &mut Vec<String>,
+) -> Option<String> {
+    let sources = [inline.is_some(), env.is_some(), file.is_some()];
+    if sources.iter().filter(|set| **set).count() > 1 {
+        problems.push(format!(
+            "{}: set only one of {key}, {key}_env, {key}_file",
+            origin,
+            key = key
+        ));
+    }
+
+    if inline.is_some() {
+        return inline;
+    }
+    if let Some(var) = env {
+        let value = env_var(var);
+        if value.is_none() {
+            problems.push(format!(
+                "{}: environment variable {} ({}_env) is not set",
+                origin, var, key
+            ));
+        }
+        return value;
+    }
+    if let Some(path) = file {
+        return match std::fs::read_to_string(path) {
+            Ok(value) => Some(value.trim().to_string()),
+            Err(e) => {
+                problems.push(format!(
+                    "{}: failed to read {}_file {}: {}",
+                    origin,
+                    key,
+                    path.display(),
+                    e
+                ));
+                None
+            }
+        };
+    }
+    None
+}
+
+fn apply_env(layered: &mut Layered, problems: &mut Vec<String>) {
+    if let Some(raw) = env_var("ARGOCD_MCP_TRANSPORT") {
+        if let Some(mode) = parse_value("ARGOCD_MCP_TRANSPORT", &raw, problems) {
+            layered.mode = Some(mode);
+        }
+    }
+    if let Some(raw) = env_var("ARGOCD_MCP_BIND") {
+        match parse_bind_address(&raw) {
+            Ok(addr) => layered.bind = Some(addr),
+            Err(e) => problems.push(format!("ARGOCD_MCP_BIND: {}", e)),
+        }
+    }
+    if let Some(raw) = env_var("ARGOCD_READ_ONLY") {
+        if let Some(read_only) = parse_bool("ARGOCD_READ_ONLY", &raw, problems) {
+            layered.read_only = Some(read_only);
+        }
+    }
+    if let Some(raw) = env_var("ARGOCD_INSECURE") {
+        if let Some(insecure) = parse_bool("ARGOCD_INSECURE", &raw, problems) {
+            layered.client_overrides.insecure = Some(insecure);
+        }
+    }
+    if let Some(raw) = env_var("ARGOCD_TIMEOUT_SECS") {
+        if let Some(timeout) = parse_value("ARGOCD_TIMEOUT_SECS", &raw, problems) {
+            layered.client_overrides.timeout_secs = Some(timeout);
+        }
+    }
+    let path_vars: [(&str, &mut Option<PathBuf>); 3] = [
+        ("ARGOCD_CA_CERT", &mut layered.client_overrides.ca_cert),
+        ("ARGOCD_CLIENT_CERT", &mut layered.client_overrides.client_cert),
+        ("ARGOCD_CLIENT_KEY", &mut layered.client_overrides.client_key),
+    ];
+    for (var, target) in path_vars {
+        if let Some(path) = env_var(var) {
+            *target = Some(PathBuf::from(path));
+        }
+    }
+    if let Some(proxy) = env_var("ARGOCD_PROXY") {
+        layered.client_overrides.proxy = Some(proxy);
+    }
+    if let Some(no_proxy) = env_var("ARGOCD_NO_PROXY") {
+        layered.client_overrides.no_proxy = Some(no_proxy);
+    }
+    if let Some(root_path) = env_var("ARGOCD_ROOT_PATH") {
+        layered.client_overrides.root_path = Some(root_path);
+    }
+    // Only the REST gateway is supported; refuse rather than ignore a request
+    // for gRPC-web left over from older configurations
+    if let Some(raw) = env_var("ARGOCD_GRPC_WEB") {
+        if parse_bool("ARGOCD_GRPC_WEB", &raw, problems) == Some(true) {
+            problems.push(
+                "ARGOCD_GRPC_WEB: gRPC-web is not supported; the server needs ArgoCD's REST gateway (/api/v1)"
+                    .to_string(),
+            );
+        }
+    }
+    if let Some(raw) = env_var("ARGOCD_STARTUP_PROBE") {
+        if let Some(probe) = parse_bool("ARGOCD_STARTUP_PROBE", &raw, problems) {
+            layered.startup_probe = Some(probe);
+        }
+    }
+    if let Some(name) = env_var("ARGOCD_DEFAULT_CONTEXT") {
+        layered.default_context = Some(name);
+    }
+    if let Some(raw) = env_var("ARGOCD_MAX_RETRIES") {
+        if let Some(retries) = parse_value("ARGOCD_MAX_RETRIES", &raw, problems) {
+            layered.client_overrides.max_retries = Some(retries);
+        }
+    }
+    let backoff_vars: [(&str, &mut Option<u64>); 2] = [
+        (
+            "ARGOCD_RETRY_INITIAL_BACKOFF_MS",
+            &mut layered.client_overrides.retry_initial_backoff_ms,
+        ),
+        (
+            "ARGOCD_RETRY_MAX_BACKOFF_MS",
+            &mut layered.client_overrides.retry_max_backoff_ms,
+        ),
+    ];
+    for (var, target) in backoff_vars {
+        if let Some(raw) = env_var(var) {
+            if let Some(value) = parse_value(var, &raw, problems) {
+                *target = Some(value);
+            }
+        }
+    }
+    if let Some(raw) = env_var("ARGOCD_RETRY_MUTATIONS") {
+        if let Some(retry) = parse_bool("ARGOCD_RETRY_MUTATIONS", &raw, problems) {
+            layered.client_overrides.retry_mutations = Some(retry);
+        }
+    }
+
+    let output_vars: [(&str, &mut Option<i64>); 2] = [
+        ("ARGOCD_DEFAULT_TAIL_LINES", &mut layered.output.default_tail_lines),
+        ("ARGOCD_MAX_TAIL_LINES", &mut layered.output.max_tail_lines),
+    ];
+    for (var, target) in output_vars {
+        if let Some(raw) = env_var(var) {
+            if let Some(value) = parse_value(var, &raw, problems) {
+                *target = Some(value);
+            }
+        }
+    }
+    let output_vars: [(&str, &mut Option<usize>); 2] = [
+        ("ARGOCD_MAX_EVENTS", &mut layered.output.max_events),
+        ("ARGOCD_MAX_APPLICATIONS", &mut layered.output.max_applications),
+    ];
+    for (var, target) in output_vars {
+        if let Some(raw) = env_var(var) {
+            if let Some(value) = parse_value(var, &raw, problems) {
+                *target = Some(value);
+            }
+        }
+    }
+
+    // ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN (or another credential
+    // variable) configure the "default" context
+    let base_url = env_var("ARGOCD_BASE_URL");
+    let credentials = EnvCredentials::read("ARGOCD");
+    if base_url.is_some() || credentials.is_set() {
+        let context = layered
+            .contexts
+            .entry(DEFAULT_CONTEXT_NAME.to_string())
+            .or_default();
+        if base_url.is_some() {
+            context.base_url = base_url;
+        }
+        override_credentials(context, credentials);
+    }
+
+    // ARGOCD_CONTEXTS registers additional contexts; per-context variables
+    // also override contexts defined in the config file
+    if let Some(names) = env_var("ARGOCD_CONTEXTS") {
+        for name in names.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            layered.contexts.entry(name.to_string()).or_default();
+        }
+    }
+    for (name, context) in layered.contexts.iter_mut() {
+        let prefix = context_env_prefix(name);
+        if let Some(base_url) = env_var(&format!("{}_BASE_URL", prefix)) {
+            context.base_url = Some(base_url);
+        }
+        override_credentials(context, EnvCredentials::read(&prefix));
+    }
+}
+
+/// Credential variables for one context
+#[derive(Debug, Default)]
+struct EnvCredentials {
+    access_token: Option<String>,
+    access_token_file: Option<PathBuf>,
+    token_command: Option<ExecConfig>,
+    username: Option<String>,
+    password: Option<String>,
+}
+
+impl EnvCredentials {
+    /// Read `<prefix>_ACCESS_TOKEN`, `_ACCESS_TOKEN_FILE`, `_TOKEN_COMMAND`,
+    /// `_USERNAME` and `_PASSWORD`
+    fn read(prefix: &str) -> Self {
+        Self {
+            access_token: env_var(&format!("{}_ACCESS_TOKEN", prefix)),
+            access_token_file: env_var(&format!("{}_ACCESS_TOKEN_FILE", prefix)).map(PathBuf::from),
+            // Split on whitespace; use the config file for arguments containing spaces
+            token_command: env_var(&format!("{}_TOKEN_COMMAND", prefix)).map(|raw| {
+                let mut words = raw.split_whitespace().map(str::to_string);
+                ExecConfig {
+                    command: words.next().unwrap_or_default(),
+                    args: words.collect(),
+                    ..ExecConfig::default()
+                }
+            }),
+            username: env_var(&format!("{}_USERNAME", prefix)),
+            password: env_var(&format!("{}_PASSWORD", prefix)),
+        }
+    }
+
+    fn has_token_source(&self) -> bool {
+        self.access_token.is_some()
+            || self.access_token_file.is_some()
+            || self.token_command.is_some()
+    }
+
+    fn is_set(&self) -> bool {
+        self.has_token_source() || self.username.is_some() || self.password.is_some()
+    }
+}
+
+/// Apply credentials from the environment; a token source replaces the
+/// file's credentials entirely, and username/password replace a token source
+fn override_credentials(context: &mut LayeredContext, env: EnvCredentials) {
+    if env.has_token_source() {
+        context.access_token = env.access_token;
+        context.access_token_file = env.access_token_file;
+        context.token_command = env.token_command;
+        context.username = None;
+        context.password = None;
+    } else if env.username.is_some() || env.password.is_some() {
+        context.access_token = None;
+        context.access_token_file = None;
+        context.token_command = None;
+        if env.username.is_some() {
+            context.username = env.username;
+        }
+        if env.password.is_some() {
+            context.password = env.password;
+        }
+    }
+}
+
+fn apply_cli(layered: &mut Layered, cli: CliArgs, problems: &mut Vec<String>) {
+    if let Some(raw) = cli.transport {
+        if let Some(mode) = parse_value("--transport", &raw, problems) {
+            layered.mode = Some(mode);
+        }
+    }
+    if let Some(raw) = cli.bind {
+        match parse_bind_address(&raw) {
+            Ok(addr) => layered.bind = Some(addr),
+            Err(e) => problems.push(format!("--bind: {}", e)),
+        }
+    }
+    if let Some(raw) = cli.read_only {
+        if let Some(read_only) = parse_bool("--read-only", &raw, problems) {
+            layered.read_only = Some(read_only);
+        }
+    }
+    if let Some(raw) = cli.insecure {
+        if let Some(insecure) = parse_bool("--insecure", &raw, problems) {
+            layered.client_overrides.insecure = Some(insecure);
+        }
+    }
+    if let Some(raw) = cli.timeout_secs {
+        if let Some(timeout) = parse_value("--timeout", &raw, problems) {
+            layered.client_overrides.timeout_secs = Some(timeout);
+        }
+    }
+    if let Some(raw) = cli.max_retries {
+        if let Some(retries) = parse_value("--max-retries", &raw, problems) {
+            layered.client_overrides.max_retries = Some(retries);
+        }
+    }
+    if let Some(root_path) = cli.root_path {
+        layered.client_overrides.root_path = Some(root_path);
+    }
+    if let Some(raw) = cli.startup_probe {
+        if let Some(probe) = parse_bool("--startup-probe", &raw, problems) {
+            layered.startup_probe = Some(probe);
+        }
+    }
+    if let Some(name) = cli.default_context {
+        layered.default_context = Some(name);
+    }
+}
+
+fn resolve(layered: Layered, config_file: Option<PathBuf>, problems: &mut Vec<String>) -> Config {
+    // File-global < file-context < env < CLI
+    let global_client = layered.client_overrides.clone().or(&layered.client);
+    let defaults = TransportConfig::default();
+    let transport = TransportConfig {
+        mode: layered.mode.unwrap_or(defaults.mode),
+        bind: layered.bind.unwrap_or(defaults.bind),
+    };
+
+    let default_limits = OutputLimits::default();
+    let output = OutputLimits {
+        default_tail_lines: layered
+            .output
+            .default_tail_lines
+            .unwrap_or(default_limits.default_tail_lines),
+        max_tail_lines: layered
+            .output
+            .max_tail_lines
+            .unwrap_or(default_limits.max_tail_lines),
+        max_events: layered.output.max_events.unwrap_or(default_limits.max_events),
+        max_applications: layered.output.max_applications,
+    };
+    if output.default_tail_lines <= 0 || output.max_tail_lines <= 0 {
+        problems.push("output: tail line limits must be greater than 0".to_string());
+    } else if output.default_tail_lines > output.max_tail_lines {
+        problems.push(format!(
+            "output: default_tail_lines ({}) exceeds max_tail_lines ({})",
+            output.default_tail_lines, output.max_tail_lines
+        ));
+    }
+    if output.max_events == 0 || output.max_applications == Some(0) {
+        problems.push("output: max_events and max_applications must be greater than 0".to_string());
+    }
+
+    let retry_defaults = RetryPolicy::default();
+    let retry = RetryPolicy {
+        max_retries: global_client
+            .max_retries
+            .unwrap_or(retry_defaults.max_retries),
+        initial_backoff: global_client
+            .retry_initial_backoff_ms
+            .map(Duration::from_millis)
+            .unwrap_or(retry_defaults.initial_backoff),
+        max_backoff: global_client
+            .retry_max_backoff_ms
+            .map(Duration::from_millis)
+            .unwrap_or(retry_defaults.max_backoff),
+        retry_mutations: global_client.retry_mutations.unwrap_or(false),
+    };
+    if retry.initial_backoff > retry.max_backoff {
+        problems.push(format!(
+            "client: retry_initial_backoff_ms ({}) exceeds retry_max_backoff_ms ({})",
+            retry.initial_backoff.as_millis(),
+            retry.max_backoff.as_millis()
+        ));
+    }
+
+    let mut contexts = Vec::new();
+    for (name, context) in layered.contexts {
+        let prefix = context_env_prefix(&name);
+        let base_url = context.base_url.unwrap_or_default();
+
+        if base_url.is_empty() {
+            problems.push(format!(
+                "context '{}': missing base_url (set it in the config file or {}_BASE_URL)",
+                name, prefix
+            ));
+        } else if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            problems.push(format!(
+                "context '{}': base_url '{}' must start with http:// or https://",
+                name, base_url
+            ));
+        }
+        let sources: Vec<&str> = [
+            (context.access_token.is_some(), "an access token"),
+            (context.access_token_file.is_some(), "a token file"),
+            (context.token_command.is_some(), "a token command"),
+            (
+                context.username.is_some() || context.password.is_some(),
+                "username/password",
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(set, source)| set.then_some(source))
+        .collect();
+
+        let credentials = if sources.len() > 1 {
+            problems.push(format!(
+                "context '{}': set either {} or {}, not both",
+                name, sources[0], sources[1]
+            ));
+            Credentials::Token(String::new())
+        } else if let Some(token) = context.access_token {
+            Credentials::Token(token)
+        } else if let Some(path) = context.access_token_file {
+            if let Err(e) = std::fs::metadata(&path) {
+                problems.push(format!(
+                    "context '{}': cannot read access_token_file {}: {}",
+                    name,
+                    path.display(),
+                    e
+                ));
+            }
+            Credentials::TokenFile(path)
+        } else if let Some(exec) = context.token_command {
+            if exec.command.trim().is_empty() {
+                problems.push(format!("context '{}': token_command.command is empty", name));
+            }
+            Credentials::Exec(exec)
+        } else {
+            match (context.username, context.password) {
+                (Some(username), Some(password)) => Credentials::Login { username, password },
+                (Some(_), None) => {
+                    problems.push(format!(
+                        "context '{}': username is set but password is missing (set password, password_env or password_file, or {}_PASSWORD)",
+                        name, prefix
+                    ));
+                    Credentials::Token(String::new())
+                }
+                (None, Some(_)) => {
+                    problems.push(format!(
+                        "context '{}': password is set but username is missing (set username or {}_USERNAME)",
+                        name, prefix
+                    ));
+                    Credentials::Token(String::new())
+                }
+                (None, None) => {
+                    problems.push(format!(
+                        "context '{}': missing access token (set access_token, access_token_env, access_token_file or token_command, or {}_ACCESS_TOKEN; or username and password)",
+                        name, prefix
+                    ));
+                    Credentials::Token(String::new())
+                }
+            }
+        };
+
+        let client = layered
+            .client_overrides
+            .clone()
+            .or(&context.client.or(&layered.client));
+        let timeout_secs = client.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS);
+        if timeout_secs == 0 {
+            problems.push(format!("context '{}': timeout must be greater than 0", name));
+        }
+
+        let options = ClientOptions {
+            timeout: Duration::from_secs(timeout_secs),
+            insecure: client.insecure.unwrap_or(false),
+            ca_cert: client.ca_cert,
+            client_cert: client.client_cert,
+            client_key: client.client_key,
+            proxy: client.proxy,
+            no_proxy: client.no_proxy,
+            root_path: client
+                .root_path
+                .and_then(|root_path| normalize_root_path(&root_path)),
+            retry: retry.clone(),
+        };
+        check_client_options(&name, &options, problems);
+
+        contexts.push(ContextSettings {
+            name,
+            base_url,
+            credentials,
+            options,
+        });
+    }
+
+    if contexts.is_empty() {
+        problems.push(
+            "No ArgoCD instance configured. Set ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN, list contexts in ARGOCD_CONTEXTS, or add [contexts] to a config file"
+                .to_string(),
+        );
+    }
+    if let Some(default) = &layered.default_context {
+        if !contexts.iter().any(|c| &c.name == default) {
+            problems.push(format!(
+                "default context '{}' is not a registered context (available: {})",
+                default,
+                contexts
+                    .iter()
+                    .map(|c| c.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+
+    Config {
+        transport,
+        contexts: ContextsConfig {
+            contexts,
+            default_context: layered.default_context,
+        },
+        read_only: layered.read_only.unwrap_or(false),
+        startup_probe: layered.startup_probe.unwrap_or(true),
+        output,
+        config_file,
+    }
+}
+
+/// Catch TLS, proxy and root path mistakes at startup rather than on the first request
+fn check_client_options(name: &str, options: &ClientOptions, problems: &mut Vec<String>) {
+    let files = [
+        ("ca_cert", &options.ca_cert),
+        ("client_cert", &options.client_cert),
+        ("client_key", &options.client_key),
+    ];
+    for (key, path) in files {
+        if let Some(path) = path {
+            if let Err(e) = std::fs::metadata(path) {
+                problems.push(format!(
+                    "context '{}': cannot read {} {}: {}",
+                    name,
+                    key,
+                    path.display(),
+                    e
+                ));
+            }
+        }
+    }
+    if options.client_cert.is_some() != options.client_key.is_some() {
+        problems.push(format!(
+            "context '{}': client_cert and client_key must be set together",
+            name
+        ));
+    }
+
+    if let Some(root_path) = &options.root_path {
+        if root_path.contains(['?', '#']) || root_path.starts_with("//") {
+            problems.push(format!(
+                "context '{}': root_path '{}' must be a plain path such as /argocd",
+                name, root_path
+            ));
+        }
+    }
+
+    match &options.proxy {
+        Some(proxy) => match reqwest::Url::parse(proxy) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            Ok(url) => problems.push(format!(
+                "context '{}': proxy '{}' has unsupported scheme '{}' (expected http or https)",
+                name,
+                proxy,
+                url.scheme()
+            )),
+            Err(e) => problems.push(format!(
+                "context '{}': invalid proxy URL '{}': {}",
+                name, proxy, e
+            )),
+        },
+        None if options.no_proxy.is_some() => problems.push(format!(
+            "context '{}': no_proxy only applies together with proxy (use NO_PROXY with HTTP_PROXY/HTTPS_PROXY)",
+            name
+        )),
+        None => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_context_env_prefix() {
+        assert_eq!(context_env_prefix("prod-eu"), "ARGOCD_CONTEXT_PROD_EU");
+        assert_eq!(context_env_prefix("staging.v2"), "ARGOCD_CONTEXT_STAGING_V2");
+    }
+
+    #[test]
+    fn test_parse_cli_flags() {
+        let mut problems = Vec::new();
+        let cli = parse_cli(
+            [
+                "--config=/etc/argocd-mcp.toml",
+                "--read-only",
+                "--bind",
+                "0.0.0.0:9000",
+                "--bogus",
+            ]
+            .map(String::from),
+            &mut problems,
+        );
+        assert_eq!(cli.config.as_deref(), Some("/etc/argocd-mcp.toml"));
+        assert_eq!(cli.read_only.as_deref(), Some("true"));
+        assert_eq!(cli.bind.as_deref(), Some("0.0.0.0:9000"));
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("--bogus"));
+    }
+
+    #[test]
+    fn test_parse_cli_explicit_booleans() {
+        let mut problems = Vec::new();
+        let cli = parse_cli(
+            [
+                "--read-only",
+                "false",
+                "--insecure=FALSE",
+                "--startup-probe",
+                "TRUE",
+                "--read-only",
+                "--timeout",
+                "5",
+            ]
+            .map(String::from),
+            &mut problems,
+        );
+        assert!(problems.is_empty(), "{:?}", problems);
+        assert_eq!(cli.read_only.as_deref(), Some("true"));
+        assert_eq!(cli.insecure.as_deref(), Some("FALSE"));
+        assert_eq!(cli.startup_probe.as_deref(), Some("TRUE"));
+        assert_eq!(cli.timeout_secs.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn test_error_report_lists_all_problems() {
+        let err = ConfigError {
+            problems: vec!["first".to_string(), "second".to_string()],
+        };
+        let report = err.to_string();
+        assert!(report.contains("2 problem(s)"));
+        assert!(report.contains("  - first\n"));
+        assert!(report.contains("  - second\n"));
+    }
+}
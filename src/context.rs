@@ -0,0 +1,138 @@
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+use crate::argocd_client::{ArgocdClient, ClientOptions};
+use crate::auth::Credentials;
+
+/// Name of the context registered from `ARGOCD_BASE_URL`/`ARGOCD_ACCESS_TOKEN`
+pub const DEFAULT_CONTEXT_NAME: &str = "default";
+
+/// Connection settings for one named ArgoCD instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextSettings {
+    pub name: String,
+    pub base_url: String,
+    pub credentials: Credentials,
+    pub options: ClientOptions,
+}
+
+/// Contexts to register at startup plus the context used when a tool call
+/// does not name one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextsConfig {
+    pub contexts: Vec<ContextSettings>,
+    pub default_context: Option<String>,
+}
+
+/// Registered ArgoCD clients keyed by context name
+#[derive(Clone, Default)]
+pub struct ContextRegistry {
+    clients: BTreeMap<String, ArgocdClient>,
+    default_context: Option<String>,
+}
+
+/// Outcome of resolving a context name against the registry
+pub enum ContextLookup<'a> {
+    /// The context exists
+    Found(&'a ArgocdClient),
+    /// No contexts are registered
+    Empty,
+    /// No context was requested and no default is set
+    NoDefault,
+    /// The requested context does not exist
+    Unknown(String),
+}
+
+impl ContextRegistry {
+    /// Register (or replace) a context
+    pub fn insert(&mut self, name: String, client: ArgocdClient) {
+        self.clients.insert(name, client);
+    }
+
+    /// Set the context used when a tool call does not name one
+    pub fn set_default(&mut self, name: &str) -> Result<()> {
+        if !self.clients.contains_key(name) {
+            anyhow::bail!("Unknown ArgoCD context '{}'", name);
+        }
+        self.default_context = Some(name.to_string());
+        Ok(())
+    }
+
+    /// The default context; a single registered context is the implicit default
+    pub fn default_name(&self) -> Option<&str> {
+        match &self.default_context {
+            Some(name) => Some(name.as_str()),
+            None if self.clients.len() == 1 => self.clients.keys().next().map(String::as_str),
+            None => None,
+        }
+    }
+
+    /// Resolve a client by context name, falling back to the default context
+    pub fn resolve(&self, context: Option<&str>) -> ContextLookup<'_> {
+        if self.clients.is_empty() {
+            return ContextLookup::Empty;
+        }
+
+        let name = match context.map(str::trim).filter(|c| !c.is_empty()) {
+            Some(name) => name,
+            None => match self.default_name() {
+                Some(name) => name,
+                None => return ContextLookup::NoDefault,
+            },
+        };
+
+        match self.clients.get(name) {
+            Some(client) => ContextLookup::Found(client),
+            None => ContextLookup::Unknown(name.to_string()),
+        }
+    }
+
+    /// Registered context names in sorted order
+    pub fn names(&self) -> Vec<&str> {
+        self.clients.keys().map(String::as_str).collect()
+    }
+
+    /// Registered contexts with their clients in sorted order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ArgocdClient)> {
+        self.clients.iter().map(|(name, client)| (name.as_str(), client))
+    }
+
+    #[allow(dead_code)]
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client() -> ArgocdClient {
+        ArgocdClient::new("https://argocd.example.com".to_string(), "token".to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_single_context_is_implicit_default() {
+        let mut registry = ContextRegistry::default();
+        registry.insert("staging".to_string(), client());
+        assert_eq!(registry.default_name(), Some("staging"));
+        assert!(matches!(registry.resolve(None), ContextLookup::Found(_)));
+    }
+
+    #[test]
+    fn test_resolve_requires_default_with_multiple_contexts() {
+        let mut registry = ContextRegistry::default();
+        registry.insert("prod-eu".to_string(), client());
+        registry.insert("prod-us".to_string(), client());
+        assert!(matches!(registry.resolve(None), ContextLookup::NoDefault));
+        assert!(matches!(
+            registry.resolve(Some("prod-us")),
+            ContextLookup::Found(_)
+        ));
+        assert!(matches!(registry.resolve(Some("dev")), ContextLookup::Unknown(_)));
+
+        registry.set_default("prod-eu").unwrap();
+        assert!(matches!(registry.resolve(None), ContextLookup::Found(_)));
+        assert!(registry.set_default("dev").is_err());
+    }
+}
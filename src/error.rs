@@ -0,0 +1,372 @@
+use reqwest::StatusCode;
+use rmcp::model::ErrorCode;
+use serde::Serialize;
+use std::fmt;
+
+/// gRPC status codes returned by the ArgoCD API in the `code` field of error bodies
+pub mod grpc_code {
+    pub const INVALID_ARGUMENT: i32 = 3;
+    pub const DEADLINE_EXCEEDED: i32 = 4;
+    pub const NOT_FOUND: i32 = 5;
+    pub const ALREADY_EXISTS: i32 = 6;
+    pub const PERMISSION_DENIED: i32 = 7;
+    pub const FAILED_PRECONDITION: i32 = 9;
+    pub const ABORTED: i32 = 10;
+    pub const UNAVAILABLE: i32 = 14;
+    pub const UNAUTHENTICATED: i32 = 16;
+}
+
+/// Details of an error response returned by the ArgoCD API
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiFailure {
+    pub status: StatusCode,
+    /// gRPC status code reported by ArgoCD, if any
+    pub grpc_code: Option<i32>,
+    pub message: String,
+}
+
+/// Errors returned by `ArgocdClient`
+///
+/// Client methods return `anyhow::Result`; the underlying error is always an
+/// `ArgocdError` and can be recovered with `ArgocdError::from_anyhow`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArgocdError {
+    /// The request was rejected as invalid (400, gRPC InvalidArgument)
+    BadRequest(ApiFailure),
+    /// The token is missing, invalid or expired (401, gRPC Unauthenticated)
+    Unauthenticated(ApiFailure),
+    /// The token is not allowed to perform the action (403, gRPC PermissionDenied)
+    PermissionDenied(ApiFailure),
+    /// The application or resource does not exist (404, gRPC NotFound)
+    NotFound(ApiFailure),
+    /// The object already exists or another operation is in progress (409, gRPC AlreadyExists/Aborted/FailedPrecondition)
+    Conflict(ApiFailure),
+    /// A sync window forbids syncing right now
+    SyncWindowBlocked(ApiFailure),
+    /// ArgoCD or a backend it depends on is unavailable (502/503, gRPC Unavailable)
+    Unavailable(ApiFailure),
+    /// ArgoCD or its gateway gave up waiting (408/504, gRPC DeadlineExceeded)
+    Timeout(ApiFailure),
+    /// No response arrived within the client timeout
+    ClientTimeout(String),
+    /// The request could not be sent or the response could not be read
+    Transport(String),
+    /// The response body did not match the expected type
+    Decode { type_name: String, message: String },
+    /// No token could be obtained from the context's credential provider
+    Credentials(String),
+    /// Any other error response
+    Api(ApiFailure),
+}
+
+/// Machine-readable error details attached to MCP errors
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorDetails {
+    pub kind: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_status: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grpc_code: Option<i32>,
+    pub retryable: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub suggestions: Vec<String>,
+}
+
+impl ArgocdError {
+    /// Classify an error response from its HTTP status and body fields
+    pub fn from_response(status: StatusCode, grpc_code: Option<i32>, message: String) -> Self {
+        let failure = ApiFailure {
+            status,
+            grpc_code,
+            message,
+        };
+
+        // ArgoCD reports sync windows as PermissionDenied/FailedPrecondition; the message is the only signal
+        if failure.message.to_lowercase().contains("sync window") {
+            return Self::SyncWindowBlocked(failure);
+        }
+
+        // The gRPC code is more specific than the gateway's HTTP status, so a
+        // recognised code decides; the status is only the fallback
+        let class = match grpc_code {
+            Some(grpc_code::UNAUTHENTICATED) => 401,
+            Some(grpc_code::PERMISSION_DENIED) => 403,
+            Some(grpc_code::NOT_FOUND) => 404,
+            Some(
+                grpc_code::ALREADY_EXISTS | grpc_code::ABORTED | grpc_code::FAILED_PRECONDITION,
+            ) => 409,
+            Some(grpc_code::INVALID_ARGUMENT) => 400,
+            Some(grpc_code::DEADLINE_EXCEEDED) => 504,
+            Some(grpc_code::UNAVAILABLE) => 503,
+            _ => status.as_u16(),
+        };
+
+        match class {
+            401 => Self::Unauthenticated(failure),
+            403 => Self::PermissionDenied(failure),
+            404 => Self::NotFound(failure),
+            409 => Self::Conflict(failure),
+            400 => Self::BadRequest(failure),
+            408 | 504 => Self::Timeout(failure),
+            502 | 503 => Self::Unavailable(failure),
+            _ => Self::Api(failure),
+        }
+    }
+
+    /// Classify a failure to send a request or read its response
+    pub fn from_reqwest(error: &reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Self::ClientTimeout(format!("Request to ArgoCD API timed out: {}", error))
+        } else {
+            Self::Transport(format!("Failed to send request to ArgoCD API: {}", error))
+        }
+    }
+
+    /// Rewrite the message, e.g. to redact it, keeping the kind and status
+    pub fn map_message(mut self, f: impl FnOnce(&str) -> String) -> Self {
+        match &mut self {
+            Self::BadRequest(failure)
+            | Self::Unauthenticated(failure)
+            | Self::PermissionDenied(failure)
+            | Self::NotFound(failure)
+            | Self::Conflict(failure)
+            | Self::SyncWindowBlocked(failure)
+            | Self::Unavailable(failure)
+            | Self::Timeout(failure)
+            | Self::Api(failure) => failure.message = f(&failure.message),
+            Self::ClientTimeout(message)
+            | Self::Transport(message)
+            | Self::Credentials(message)
+            | Self::Decode { message, .. } => *message = f(message),
+        }
+        self
+    }
+
+    /// Recover the typed error from an `anyhow::Error` returned by the client
+    pub fn from_anyhow(error: &anyhow::Error) -> Option<&Self> {
+        error.downcast_ref::<Self>()
+    }
+
+    /// Stable identifier for the error kind
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::BadRequest(_) => "bad_request",
+            Self::Unauthenticated(_) => "unauthenticated",
+            Self::PermissionDenied(_) => "permission_denied",
+            Self::NotFound(_) => "not_found",
+            Self::Conflict(_) => "conflict",
+            Self::SyncWindowBlocked(_) => "sync_window_blocked",
+            Self::Unavailable(_) => "unavailable",
+            Self::Timeout(_) | Self::ClientTimeout(_) => "timeout",
+            Self::Transport(_) => "transport",
+            Self::Decode { .. } => "decode",
+            Self::Credentials(_) => "credentials",
+            Self::Api(_) => "api_error",
+        }
+    }
+
+    /// API error details, when the server answered
+    pub fn failure(&self) -> Option<&ApiFailure> {
+        match self {
+            Self::BadRequest(f)
+            | Self::Unauthenticated(f)
+            | Self::PermissionDenied(f)
+            | Self::NotFound(f)
+            | Self::Conflict(f)
+            | Self::SyncWindowBlocked(f)
+            | Self::Unavailable(f)
+            | Self::Timeout(f)
+            | Self::Api(f) => Some(f),
+            Self::ClientTimeout(_)
+            | Self::Transport(_)
+            | Self::Decode { .. }
+            | Self::Credentials(_) => None,
+        }
+    }
+
+    /// Whether repeating the same request may succeed
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Unavailable(_)
+            | Self::Timeout(_)
+            | Self::ClientTimeout(_)
+            | Self::Transport(_) => true,
+            Self::Api(f) => f.status.is_server_error() || f.status == StatusCode::TOO_MANY_REQUESTS,
+            _ => false,
+        }
+    }
+
+    /// MCP error code reported to the client
+    pub fn mcp_code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound(_) => ErrorCode::RESOURCE_NOT_FOUND,
+            Self::BadRequest(_) => ErrorCode::INVALID_PARAMS,
+            Self::Unauthenticated(_)
+            | Self::PermissionDenied(_)
+            | Self::Conflict(_)
+            | Self::SyncWindowBlocked(_) => ErrorCode::INVALID_REQUEST,
+            _ => ErrorCode::INTERNAL_ERROR,
+        }
+    }
+
+    /// Suggested next step for the caller
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            Self::Unauthenticated(_) => Some(
+                "The access token for this context is missing, invalid or expired; refresh it and try again",
+            ),
+            Self::PermissionDenied(_) => {
+                Some("The token's RBAC policy does not allow this action on this application or project")
+            }
+            Self::NotFound(_) => Some(
+                "Check the name, project and appNamespace; use list_applications to see what exists",
+            ),
+            Self::Conflict(_) => Some(
+                "The object already exists or another operation is running; check the application's current operation and retry later",
+            ),
+            Self::SyncWindowBlocked(_) => Some(
+                "Use get_application_sync_windows to see when syncs are allowed, or retry inside an allowed window",
+            ),
+            Self::Unavailable(_)
+            | Self::Timeout(_)
+            | Self::ClientTimeout(_)
+            | Self::Transport(_) => Some(
+                "ArgoCD could not be reached reliably; retry shortly",
+            ),
+            Self::Credentials(_) => Some(
+                "The server could not obtain a token for this context; check its token file or token command",
+            ),
+            Self::BadRequest(_) | Self::Decode { .. } | Self::Api(_) => None,
+        }
+    }
+
+    /// Details attached to the MCP error as `data`
+    pub fn details(&self) -> ErrorDetails {
+        ErrorDetails {
+            kind: self.kind(),
+            http_status: self.failure().map(|f| f.status.as_u16()),
+            grpc_code: self.failure().and_then(|f| f.grpc_code),
+            retryable: self.is_retryable(),
+            hint: self.hint().map(str::to_string),
+            suggestions: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for ArgocdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ClientTimeout(message)
+            | Self::Transport(message)
+            | Self::Credentials(message) => write!(f, "{}", message),
+            Self::Decode { type_name, message } => {
+                write!(f, "Failed to parse {} response: {}", type_name, message)
+            }
+            _ => {
+                let failure = self.failure().expect("API errors carry a failure");
+                write!(f, "ArgoCD API error ({}): {}", failure.status, failure.message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgocdError {}
+
+/// Names from `candidates` that look like likely intended spellings of `target`, closest first
+pub fn suggest_names(target: &str, candidates: &[String], limit: usize) -> Vec<String> {
+    let target = target.to_lowercase();
+    let max_distance = (target.chars().count() / 3).max(2);
+
+    let mut scored: Vec<(usize, &String)> = candidates
+        .iter()
+        .filter_map(|candidate| {
+            let lower = candidate.to_lowercase();
+            let distance = edit_distance(&target, &lower);
+            let related = lower.contains(&target) || target.contains(&lower);
+            (distance <= max_distance || related).then_some((distance, candidate))
+        })
+        .collect();
+
+    scored.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(_, name)| name.clone())
+        .collect()
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classification_prefers_grpc_code() {
+        let err = ArgocdError::from_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Some(grpc_code::NOT_FOUND),
+            "applications.argoproj.io \"guestbok\" not found".to_string(),
+        );
+        assert_eq!(err.kind(), "not_found");
+
+        let err = ArgocdError::from_response(
+            StatusCode::FORBIDDEN,
+            Some(grpc_code::PERMISSION_DENIED),
+            "Cannot sync: Blocked by sync window".to_string(),
+        );
+        assert_eq!(err.kind(), "sync_window_blocked");
+    }
+
+    #[test]
+    fn test_grpc_code_beats_conflicting_http_status() {
+        let err = ArgocdError::from_response(
+            StatusCode::NOT_FOUND,
+            Some(grpc_code::PERMISSION_DENIED),
+            "permission denied".to_string(),
+        );
+        assert_eq!(err.kind(), "permission_denied");
+
+        let err = ArgocdError::from_response(
+            StatusCode::UNAUTHORIZED,
+            Some(grpc_code::NOT_FOUND),
+            "application not found".to_string(),
+        );
+        assert_eq!(err.kind(), "not_found");
+
+        // An unrecognised code falls back to the HTTP status
+        let err = ArgocdError::from_response(StatusCode::CONFLICT, Some(2), "unknown".to_string());
+        assert_eq!(err.kind(), "conflict");
+    }
+
+    #[test]
+    fn test_suggest_names() {
+        let names = vec![
+            "guestbook".to_string(),
+            "guestbook-staging".to_string(),
+            "payments".to_string(),
+        ];
+        assert_eq!(
+            suggest_names("guestbok", &names, 3),
+            vec!["guestbook".to_string()]
+        );
+        assert!(suggest_names("inventory", &names, 3).is_empty());
+    }
+}
@@ -1,4 +1,12 @@
 // Library exports for testing and potential reuse
 pub mod argocd_client;
+pub mod auth;
+pub mod config;
+pub mod context;
+pub mod error;
 pub mod models;
+pub mod request;
+pub mod retry;
 pub mod tools;
+pub mod transport;
+pub mod watch;
@@ -1,11 +1,19 @@
 mod argocd_client;
+mod auth;
+mod config;
+mod context;
+mod error;
 mod models;
+mod request;
+mod retry;
 mod tools;
+mod transport;
+mod watch;
 
 use anyhow::{Context, Result};
-use rmcp::ServiceExt;
 use std::env;
 
+use config::Config;
 use tools::ArgocdMcpHandler;
 
 #[tokio::main]
@@ -18,37 +26,42 @@ async fn main() -> Result<()> {
 
     tracing::info!("Starting ArgoCD MCP Server");
 
-    // Read environment variables
-    let base_url = env::var("ARGOCD_BASE_URL")
-        .context("ARGOCD_BASE_URL environment variable must be set")?;
-    let access_token = env::var("ARGOCD_ACCESS_TOKEN")
-        .context("ARGOCD_ACCESS_TOKEN environment variable must be set")?;
-    let read_only = env::var("ARGOCD_READ_ONLY")
-        .ok()
-        .and_then(|v| v.parse::<bool>().ok())
-        .unwrap_or(false);
-
-    tracing::info!("Connecting to ArgoCD at: {}", base_url);
-    if read_only {
+    // Load configuration: config file < environment variables < CLI flags
+    let config = match Config::load(env::args().skip(1)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprint!("{}", e);
+            anyhow::bail!("Invalid configuration");
+        }
+    };
+
+    if let Some(path) = &config.config_file {
+        tracing::info!("Loaded configuration file: {}", path.display());
+    }
+    for settings in &config.contexts.contexts {
+        tracing::info!(
+            "Context '{}': connecting to ArgoCD at {} ({})",
+            settings.name,
+            settings.base_url,
+            settings.credentials.describe()
+        );
+    }
+    if config.read_only {
         tracing::info!("Running in READ-ONLY mode - only GET requests allowed");
     }
 
-    // Create handler with read-only mode from environment
-    let handler = ArgocdMcpHandler::from_env();
-    handler.initialize(base_url, access_token).await
+    // Create handler with read-only mode and output limits from the configuration
+    let handler = ArgocdMcpHandler::from_config(&config);
+    handler.initialize_contexts(config.contexts).await
         .context("Failed to initialize ArgoCD client")?;
+    if config.startup_probe {
+        handler.probe_contexts().await?;
+    }
 
-    tracing::info!("MCP Server initialized, starting stdio transport");
-
-    // Start MCP server with stdio transport
-    let service = handler.serve(rmcp::transport::stdio()).await.inspect_err(|e| {
-        tracing::error!("Failed to serve: {:?}", e);
-    })?;
-
-    tracing::info!("MCP Server running");
+    tracing::info!("MCP Server initialized, starting {} transport", config.transport.mode.as_str());
 
-    // Wait for completion
-    service.waiting().await?;
+    // Serve until the client disconnects or a shutdown signal arrives
+    transport::serve(handler, config.transport).await?;
 
     tracing::info!("MCP Server shutdown gracefully");
     Ok(())
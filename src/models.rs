@@ -1,5 +1,7 @@
+use rmcp::schemars;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 
 /// Optimized Application model containing only essential fields
 /// to minimize context window usage
@@ -14,7 +16,26 @@ pub struct Application {
     pub status: Option<ApplicationStatus>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl Application {
+    /// Sources of a multi-source application paired with their synced
+    /// revisions; empty for single-source applications
+    pub fn multi_source_summaries(&self) -> Vec<SourceSummary> {
+        match &self.spec {
+            Some(spec) if spec.has_multiple_sources() => {
+                let revisions = self
+                    .status
+                    .as_ref()
+                    .and_then(|s| s.sync.as_ref())
+                    .map(|sync| sync.source_revisions())
+                    .unwrap_or_default();
+                SourceSummary::list(&spec.effective_sources(), revisions)
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ObjectMeta {
     pub name: String,
@@ -26,59 +47,330 @@ pub struct ObjectMeta {
     pub annotations: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creation_timestamp: Option<String>,
+    /// Required by ArgoCD when updating an object read earlier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_version: Option<String>,
+    /// Every other field (finalizers, uid, generation, ownerReferences, ...),
+    /// kept so a read-modify-write update does not strip them
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Desired state of an application (ArgoCD `v1alpha1.ApplicationSpec`)
+///
+/// Modelled in full so a spec read from ArgoCD can be sent back on create or
+/// update without dropping fields.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationSpec {
+    /// Single source of manifests (use either `source` or `sources`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<ApplicationSource>,
+    /// Multiple sources of manifests (ArgoCD v2.6+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<ApplicationSource>>,
+    /// Dry source rendered into a hydrated branch (ArgoCD v2.14+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_hydrator: Option<SourceHydrator>,
+    /// Cluster and namespace to deploy to
     #[serde(skip_serializing_if = "Option::is_none")]
     pub destination: Option<ApplicationDestination>,
+    /// AppProject the application belongs to (e.g. "default")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// Automated sync, sync options and retry behaviour
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sync_policy: Option<SyncPolicy>,
+    /// Differences to ignore when comparing live and desired state
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_differences: Option<Vec<ResourceIgnoreDifferences>>,
+    /// Extra information shown in the ArgoCD UI
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<Vec<Info>>,
+    /// Number of history entries to keep (ArgoCD defaults to 10)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision_history_limit: Option<i64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Where an application's manifests come from
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationSource {
+    /// Git repository, Helm repository or OCI registry URL
     #[serde(rename = "repoURL")]
     pub repo_url: String,
+    /// Directory within the repository (Git sources)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub path: Option<String>,
+    /// Branch, tag, commit or chart version
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_revision: Option<String>,
+    /// Chart name (Helm repository sources)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub chart: Option<String>,
+    /// Reference name other sources can use for `$ref/` value files
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<String>,
+    /// Display name of the source (ArgoCD v2.14+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub helm: Option<ApplicationSourceHelm>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kustomize: Option<ApplicationSourceKustomize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub directory: Option<ApplicationSourceDirectory>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin: Option<ApplicationSourcePlugin>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Hydrates manifests from a dry source and syncs from the rendered branch
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceHydrator {
+    /// Source holding the unrendered (dry) manifests
+    pub dry_source: DrySource,
+    /// Branch and path ArgoCD syncs the hydrated manifests from
+    pub sync_source: SyncSource,
+    /// Staging branch to push to before promoting to the sync source
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hydrate_to: Option<HydrateTo>,
+}
+
+/// Unrendered manifests of a hydrated application
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DrySource {
+    #[serde(rename = "repoURL")]
+    pub repo_url: String,
+    pub target_revision: String,
+    pub path: String,
+}
+
+/// Branch the hydrated manifests are written to and synced from
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSource {
+    pub target_branch: String,
+    pub path: String,
+}
+
+/// Branch hydrated manifests are staged on
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HydrateTo {
+    pub target_branch: String,
+}
+
+/// Helm-specific source options
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSourceHelm {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_files: Option<Vec<String>>,
+    /// Inline values.yaml content
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values: Option<String>,
+    /// Inline values as an object (takes precedence over `values`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub values_object: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<HelmParameter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_parameters: Option<Vec<HelmFileParameter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub release_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ignore_missing_value_files: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_crds: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pass_credentials: Option<bool>,
+    /// Helm version to render with (e.g. "v3")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kube_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_versions: Option<Vec<String>>,
+}
+
+/// Helm `--set` parameter
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HelmParameter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// Pass the value with `--set-string`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_string: Option<bool>,
+}
+
+/// Helm `--set-file` parameter
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HelmFileParameter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// Kustomize-specific source options
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSourceKustomize {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_suffix: Option<String>,
+    /// Image overrides (e.g. "nginx=nginx:1.27")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_labels: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_annotations: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_common_labels: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force_common_annotations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Replica count overrides (`count` may be a number or a string)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<Vec<serde_json::Value>>,
+    /// Inline Kustomize patches
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patches: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<String>>,
+    /// Kustomize version to build with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+/// Options for plain directories of manifests
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSourceDirectory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurse: Option<bool>,
+    /// Glob of files to include
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include: Option<String>,
+    /// Glob of files to exclude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<String>,
+    /// Jsonnet options (extVars, tlas, libs)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jsonnet: Option<serde_json::Value>,
+}
+
+/// Config management plugin options
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSourcePlugin {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<EnvEntry>>,
+    /// Plugin parameters (string, array or map valued)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Vec<serde_json::Value>>,
+}
+
+/// Environment variable passed to a config management plugin
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EnvEntry {
+    pub name: String,
+    pub value: String,
+}
+
+/// Target cluster and namespace (set either `server` or `name`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicationDestination {
+    /// Cluster API server URL (e.g. "https://kubernetes.default.svc")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub server: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub namespace: Option<String>,
+    /// Cluster name as registered in ArgoCD
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncPolicy {
+    /// Sync automatically when the desired state changes
     #[serde(skip_serializing_if = "Option::is_none")]
     pub automated: Option<AutomatedSyncPolicy>,
+    /// Sync options (e.g. ["CreateNamespace=true", "ServerSideApply=true"])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_options: Option<Vec<String>>,
+    /// Retry behaviour for failed syncs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryStrategy>,
+    /// Labels and annotations for a namespace created by CreateNamespace=true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub managed_namespace_metadata: Option<ManagedNamespaceMetadata>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AutomatedSyncPolicy {
+    /// Delete resources no longer defined in the source
     #[serde(skip_serializing_if = "Option::is_none")]
     pub prune: Option<bool>,
+    /// Revert changes made to live resources
     #[serde(skip_serializing_if = "Option::is_none")]
     pub self_heal: Option<bool>,
+    /// Allow syncing when the source renders no resources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_empty: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedNamespaceMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<HashMap<String, String>>,
+}
+
+/// Fields of matching resources to leave out of the diff
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceIgnoreDifferences {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_pointers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jq_path_expressions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub managed_fields_managers: Option<Vec<String>>,
+}
+
+/// Name/value pair shown in the application's info panel
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Info {
+    pub name: String,
+    pub value: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,6 +384,143 @@ pub struct ApplicationStatus {
     pub summary: Option<ApplicationSummary>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub history: Option<Vec<RevisionHistory>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation_state: Option<OperationState>,
+    /// Errors and warnings ArgoCD found while reconciling
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<ApplicationCondition>>,
+    /// Sync and health status of every managed resource
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceStatus>>,
+    /// When the application state was last compared with the sources
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciled_at: Option<String>,
+    /// Helm, Kustomize, Directory or Plugin
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    /// Type of each source of a multi-source application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_types: Option<Vec<String>>,
+}
+
+/// Error or warning condition of an application (e.g. `ComparisonError`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationCondition {
+    #[serde(rename = "type")]
+    pub condition_type: String,
+    #[serde(default)]
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+}
+
+impl ApplicationCondition {
+    /// ArgoCD names error conditions `*Error` and warnings `*Warning`
+    pub fn is_error(&self) -> bool {
+        self.condition_type.ends_with("Error")
+    }
+}
+
+/// State of the application's current or last operation (sync, rollback)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationState {
+    /// Running, Terminating, Succeeded, Failed or Error
+    pub phase: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    /// The operation that was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_result: Option<SyncOperationResult>,
+    /// Number of attempts made after the first one failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_count: Option<i64>,
+}
+
+impl OperationState {
+    /// Whether the operation is still in progress and can be terminated
+    pub fn is_in_progress(&self) -> bool {
+        self.phase == "Running" || self.phase == "Terminating"
+    }
+}
+
+/// Operation requested on an application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Operation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<SyncOperation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiated_by: Option<OperationInitiator>,
+}
+
+/// Parameters of a requested sync
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncOperation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revisions: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prune: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+}
+
+/// Outcome of a sync operation
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncOperationResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revisions: Option<Vec<String>>,
+    #[serde(default)]
+    pub resources: Vec<ResourceResult>,
+}
+
+/// Result of syncing a single resource or running a hook
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceResult {
+    #[serde(default)]
+    pub group: String,
+    #[serde(default)]
+    pub version: String,
+    pub kind: String,
+    #[serde(default)]
+    pub namespace: String,
+    pub name: String,
+    /// Synced, SyncFailed, Pruned, PruneSkipped or Unknown
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// PreSync, Sync, PostSync, SyncFail, PostDelete or Skip, for hooks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook_type: Option<String>,
+    /// Running, Succeeded, Failed, Error or Terminating, for hooks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook_phase: Option<String>,
+    /// PreSync, Sync, PostSync or SyncFail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_phase: Option<String>,
+}
+
+impl ResourceResult {
+    /// Whether the resource failed to sync or its hook did not succeed
+    pub fn is_failure(&self) -> bool {
+        self.status.as_deref() == Some("SyncFailed")
+            || matches!(self.hook_phase.as_deref(), Some("Failed" | "Error"))
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -108,6 +537,16 @@ pub struct SyncStatus {
     pub status: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revision: Option<String>,
+    /// Revision of each source, in `spec.sources` order (multi-source)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revisions: Option<Vec<String>>,
+}
+
+impl SyncStatus {
+    /// Synced revisions of a multi-source application (empty otherwise)
+    pub fn source_revisions(&self) -> &[String] {
+        self.revisions.as_deref().unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -127,6 +566,15 @@ pub struct ApplicationList {
     pub items: Vec<Application>,
 }
 
+/// Event from the application watch stream (`/api/v1/stream/applications`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationWatchEvent {
+    /// ADDED, MODIFIED or DELETED
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub application: Application,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ListMetadata {
@@ -134,53 +582,133 @@ pub struct ListMetadata {
     pub resource_version: Option<String>,
 }
 
-/// Optimized summary for context efficiency
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ApplicationSummaryOutput {
-    pub name: String,
+/// ArgoCD server version as returned by `/api/version`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VersionMessage {
+    #[serde(default)]
+    pub version: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub namespace: Option<String>,
+    pub build_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub project: Option<String>,
+    pub git_commit: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub repo_url: Option<String>,
+    pub platform: Option<String>,
+}
+
+/// Configured ArgoCD context (named ArgoCD instance)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextSummary {
+    pub name: String,
+    pub server: String,
+    pub is_default: bool,
+}
+
+/// One source of an application together with the revision it resolved to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceSummary {
+    /// 1-based position, as used by the `sourcePositions` parameter
+    pub position: usize,
+    pub repo_url: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub target_revision: Option<String>,
+    pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_server: Option<String>,
+    pub path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_namespace: Option<String>,
+    pub chart: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub sync_status: Option<String>,
+    pub ref_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub health_status: Option<String>,
+    pub target_revision: Option<String>,
+    /// Revision the source was synced or deployed at
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub auto_sync: Option<bool>,
+    pub revision: Option<String>,
 }
 
-impl From<Application> for ApplicationSummaryOutput {
-    fn from(app: Application) -> Self {
-        let name = app
-            .metadata
-            .as_ref()
-            .map(|m| m.name.clone())
+impl SourceSummary {
+    /// Summarize sources, pairing each with the revision at the same index
+    pub fn list(sources: &[ApplicationSource], revisions: &[String]) -> Vec<Self> {
+        sources
+            .iter()
+            .enumerate()
+            .map(|(idx, source)| SourceSummary {
+                position: idx + 1,
+                repo_url: source.repo_url.clone(),
+                name: source.name.clone(),
+                path: source.path.clone(),
+                chart: source.chart.clone(),
+                ref_name: source.ref_name.clone(),
+                target_revision: source.target_revision.clone(),
+                revision: revisions.get(idx).filter(|r| !r.is_empty()).cloned(),
+            })
+            .collect()
+    }
+
+    /// One-line description, e.g. `values: https://github.com/org/values@main (ref: values)`
+    pub fn describe(&self) -> String {
+        let mut text = self.repo_url.clone();
+        if let Some(target) = self.chart.as_ref().or(self.path.as_ref()) {
+            text.push(' ');
+            text.push_str(target);
+        }
+        if let Some(target_revision) = &self.target_revision {
+            text.push('@');
+            text.push_str(target_revision);
+        }
+        if let Some(ref_name) = &self.ref_name {
+            text.push_str(&format!(" (ref: {})", ref_name));
+        }
+        match &self.name {
+            Some(name) => format!("{}: {}", name, text),
+            None => text,
+        }
+    }
+}
+
+/// Optimized summary for context efficiency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSummaryOutput {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_sync: Option<bool>,
+    /// Every source of a multi-source application
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceSummary>,
+}
+
+impl From<Application> for ApplicationSummaryOutput {
+    fn from(app: Application) -> Self {
+        let name = app
+            .metadata
+            .as_ref()
+            .map(|m| m.name.clone())
             .unwrap_or_default();
 
         let namespace = app.metadata.as_ref().and_then(|m| m.namespace.clone());
 
         let project = app.spec.as_ref().and_then(|s| s.project.clone());
 
-        let repo_url = app
-            .spec
-            .as_ref()
-            .and_then(|s| s.source.as_ref())
-            .map(|src| src.repo_url.clone());
+        let primary_source = app.spec.as_ref().and_then(|s| s.primary_source());
+        let repo_url = primary_source.as_ref().map(|src| src.repo_url.clone());
+        let target_revision = primary_source.and_then(|src| src.target_revision);
 
-        let target_revision = app
-            .spec
-            .as_ref()
-            .and_then(|s| s.source.as_ref())
-            .and_then(|src| src.target_revision.clone());
+        let sources = app.multi_source_summaries();
 
         let destination_server = app
             .spec
@@ -224,6 +752,7 @@ impl From<Application> for ApplicationSummaryOutput {
             sync_status,
             health_status,
             auto_sync: if auto_sync { Some(true) } else { None },
+            sources,
         }
     }
 }
@@ -367,6 +896,55 @@ pub struct ApplicationTree {
     pub hosts: Option<Vec<HostInfo>>,
 }
 
+impl ApplicationTree {
+    /// Nodes owned, directly or transitively, by the resource identified by
+    /// kind, name and namespace (e.g. the ReplicaSets and Pods of a Deployment)
+    pub fn descendants(
+        &self,
+        kind: &str,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Vec<&ResourceNode> {
+        let is_node = |node: &ResourceNode, kind: &str, name: &str, namespace: Option<&str>| {
+            node.kind.as_deref() == Some(kind)
+                && node.name.as_deref() == Some(name)
+                && node.namespace.as_deref().unwrap_or("") == namespace.unwrap_or("")
+        };
+        let is_parent = |parent: &ResourceRef, node: &ResourceNode| match (&parent.uid, &node.uid) {
+            (Some(parent_uid), Some(uid)) => parent_uid == uid,
+            _ => {
+                parent.kind == node.kind
+                    && parent.name == node.name
+                    && parent.namespace.as_deref().unwrap_or("")
+                        == node.namespace.as_deref().unwrap_or("")
+            }
+        };
+
+        let mut found: Vec<&ResourceNode> = self
+            .nodes
+            .iter()
+            .filter(|node| is_node(node, kind, name, namespace))
+            .collect();
+        let roots = found.len();
+        let mut next = 0;
+        while next < found.len() {
+            let owner = found[next];
+            next += 1;
+            for node in &self.nodes {
+                let owned = node
+                    .parent_refs
+                    .iter()
+                    .flatten()
+                    .any(|parent| is_parent(parent, owner));
+                if owned && !found.iter().any(|f| std::ptr::eq(*f, node)) {
+                    found.push(node);
+                }
+            }
+        }
+        found.split_off(roots)
+    }
+}
+
 /// Optimized summary for ResourceTree output (context-efficient)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceTreeSummary {
@@ -551,6 +1129,109 @@ pub struct ApplicationDetailOutput {
     pub labels: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub creation_timestamp: Option<String>,
+    /// Every source of a multi-source application, with its synced revision
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_hydrator: Option<SourceHydrator>,
+    /// Helm, Kustomize, Directory or Plugin (comma separated for multi-source)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciled_at: Option<String>,
+    /// Application conditions, errors before warnings
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub conditions: Vec<ApplicationCondition>,
+    /// Current or last operation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<OperationSummary>,
+    /// Number of resources the application manages
+    #[serde(default)]
+    pub resources_total: usize,
+    /// Managed resources that are out of sync or not healthy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource_issues: Vec<ResourceStatus>,
+}
+
+/// Current or last operation of an application with its per-resource results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperationSummary {
+    /// Running, Terminating, Succeeded, Failed or Error
+    pub phase: String,
+    pub in_progress: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finished_at: Option<String>,
+    /// User who started the operation, or "Automated"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiated_by: Option<String>,
+    /// Revision synced to (requested revision while still running)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// Revision of each source (multi-source)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revisions: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_count: Option<i64>,
+    /// Number of resource results by status (Synced, SyncFailed, Pruned, ...)
+    #[serde(default)]
+    pub result_counts: std::collections::BTreeMap<String, usize>,
+    /// Failed resources and every hook, with their hook phase
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource_results: Vec<ResourceResult>,
+}
+
+impl From<OperationState> for OperationSummary {
+    fn from(state: OperationState) -> Self {
+        let in_progress = state.is_in_progress();
+        let operation = state.operation.as_ref();
+        let requested = operation.and_then(|op| op.sync.as_ref());
+        let initiated_by = operation.and_then(|op| op.initiated_by.as_ref()).and_then(|by| {
+            by.username.clone().or_else(|| {
+                by.automated
+                    .unwrap_or(false)
+                    .then(|| "Automated".to_string())
+            })
+        });
+
+        let result = state.sync_result.unwrap_or_default();
+        let revision = result
+            .revision
+            .filter(|r| !r.is_empty())
+            .or_else(|| requested.and_then(|sync| sync.revision.clone()));
+        let revisions = result
+            .revisions
+            .or_else(|| requested.and_then(|sync| sync.revisions.clone()))
+            .unwrap_or_default();
+
+        let mut result_counts = std::collections::BTreeMap::new();
+        for resource in &result.resources {
+            let status = resource.status.as_deref().unwrap_or("Unknown");
+            *result_counts.entry(status.to_string()).or_insert(0) += 1;
+        }
+        let resource_results = result
+            .resources
+            .into_iter()
+            .filter(|r| r.is_failure() || r.hook_type.is_some())
+            .collect();
+
+        OperationSummary {
+            phase: state.phase,
+            in_progress,
+            message: state.message,
+            started_at: state.started_at,
+            finished_at: state.finished_at,
+            initiated_by,
+            revision,
+            revisions,
+            retry_count: state.retry_count,
+            result_counts,
+            resource_results,
+        }
+    }
 }
 
 impl From<Application> for ApplicationDetailOutput {
@@ -569,11 +1250,12 @@ impl From<Application> for ApplicationDetailOutput {
 
         let project = spec.and_then(|s| s.project.clone());
 
-        let source = spec.and_then(|s| s.source.as_ref());
-        let repo_url = source.map(|src| src.repo_url.clone());
-        let path = source.and_then(|src| src.path.clone());
-        let chart = source.and_then(|src| src.chart.clone());
-        let target_revision = source.and_then(|src| src.target_revision.clone());
+        let source = spec.and_then(|s| s.primary_source());
+        let repo_url = source.as_ref().map(|src| src.repo_url.clone());
+        let path = source.as_ref().and_then(|src| src.path.clone());
+        let chart = source.as_ref().and_then(|src| src.chart.clone());
+        let target_revision = source.and_then(|src| src.target_revision);
+        let source_hydrator = spec.and_then(|s| s.source_hydrator.clone());
 
         let destination = spec.and_then(|s| s.destination.as_ref());
         let destination_server = destination.and_then(|d| d.server.clone());
@@ -583,6 +1265,7 @@ impl From<Application> for ApplicationDetailOutput {
         let sync = status.and_then(|s| s.sync.as_ref());
         let sync_status = sync.map(|s| s.status.clone());
         let sync_revision = sync.and_then(|s| s.revision.clone());
+        let sources = app.multi_source_summaries();
 
         let health = status.and_then(|s| s.health.as_ref());
         let health_status = health.map(|h| h.status.clone());
@@ -594,6 +1277,33 @@ impl From<Application> for ApplicationDetailOutput {
         let auto_sync_prune = automated.and_then(|a| a.prune);
         let auto_sync_self_heal = automated.and_then(|a| a.self_heal);
 
+        let source_type = status.and_then(|s| match &s.source_types {
+            Some(types) if !types.is_empty() => Some(types.join(", ")),
+            _ => s.source_type.clone(),
+        });
+        let reconciled_at = status.and_then(|s| s.reconciled_at.clone());
+
+        let mut conditions = status
+            .and_then(|s| s.conditions.clone())
+            .unwrap_or_default();
+        conditions.sort_by_key(|c| !c.is_error());
+
+        let operation = status
+            .and_then(|s| s.operation_state.clone())
+            .map(OperationSummary::from);
+
+        let resources = status.and_then(|s| s.resources.as_ref());
+        let resources_total = resources.map_or(0, |r| r.len());
+        let resource_issues = resources
+            .into_iter()
+            .flatten()
+            .filter(|r| {
+                r.status.as_deref().is_some_and(|s| s != "Synced")
+                    || r.health.as_ref().is_some_and(|h| h.status != "Healthy")
+            })
+            .cloned()
+            .collect();
+
         ApplicationDetailOutput {
             name,
             namespace,
@@ -614,11 +1324,19 @@ impl From<Application> for ApplicationDetailOutput {
             auto_sync_self_heal,
             labels,
             creation_timestamp,
+            sources,
+            source_hydrator,
+            source_type,
+            reconciled_at,
+            conditions,
+            operation,
+            resources_total,
+            resource_issues,
         }
     }
 }
 
-/// Pod Logs structures for PodLogs2
+// Pod Logs structures for PodLogs2
 
 /// LogEntry represents a single log line from a container
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -685,6 +1403,9 @@ pub struct AnalyzedLogEntry {
     pub content: String,
     pub level: LogLevel,
     pub pod_name: Option<String>,
+    /// Container the line came from, when it was requested explicitly
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
     pub timestamp: Option<String>,
     pub is_error: bool,
     pub is_warning: bool,
@@ -715,6 +1436,7 @@ impl From<LogEntry> for AnalyzedLogEntry {
             content,
             level,
             pod_name: entry.pod_name,
+            container: None,
             timestamp: entry.time_stamp_str.or(entry.time_stamp),
             is_error,
             is_warning,
@@ -736,6 +1458,15 @@ pub struct PodLogsSummary {
     pub tail_lines: Option<i64>,
     pub filtered: bool,
     pub log_entries: Vec<AnalyzedLogEntry>,
+    /// How a followed stream ended (`pod_logs` with `follow`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow: Option<LogFollowSummary>,
+    /// Pods the lines were merged from (`workload_logs`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workload: Option<WorkloadLogsSummary>,
+    /// Repeated lines collapsed into patterns; `log_entries` is then empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clusters: Option<LogClusterSummary>,
 }
 
 impl PodLogsSummary {
@@ -750,11 +1481,22 @@ impl PodLogsSummary {
             entries.into_iter().map(AnalyzedLogEntry::from).collect();
 
         // Apply error filtering if requested
-        let filtered = filter_errors_only;
         if filter_errors_only {
             analyzed.retain(|entry| entry.potential_issue);
         }
 
+        let mut summary = Self::from_analyzed(analyzed, pod_name, container, tail_lines);
+        summary.filtered = filter_errors_only;
+        summary
+    }
+
+    /// Count the levels and issues of already analyzed lines
+    fn from_analyzed(
+        analyzed: Vec<AnalyzedLogEntry>,
+        pod_name: Option<String>,
+        container: Option<String>,
+        tail_lines: Option<i64>,
+    ) -> Self {
         let total_lines = analyzed.len();
         let error_count = analyzed.iter().filter(|e| e.is_error).count();
         let warning_count = analyzed.iter().filter(|e| e.is_warning).count();
@@ -776,13 +1518,509 @@ impl PodLogsSummary {
             pod_name,
             container,
             tail_lines,
-            filtered,
+            filtered: false,
             log_entries: analyzed,
+            follow: None,
+            workload: None,
+            clusters: None,
+        }
+    }
+
+    /// Collapse the log entries into clusters of lines sharing a template
+    /// (see `log_template`), most frequent first, keeping only the `top`
+    /// most frequent when given
+    ///
+    /// The counts of the summary are unchanged; the entries are replaced by
+    /// the clusters.
+    pub fn cluster(&mut self, top: Option<usize>) {
+        let mut clusters: Vec<LogCluster> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        for entry in std::mem::take(&mut self.log_entries) {
+            let pattern = log_template(&entry.content);
+            let position = *index.entry(pattern.clone()).or_insert_with(|| {
+                clusters.push(LogCluster {
+                    pattern,
+                    level: entry.level.clone(),
+                    count: 0,
+                    first_seen: entry.timestamp.clone(),
+                    last_seen: None,
+                    example: entry.content.clone(),
+                    pods: Vec::new(),
+                });
+                clusters.len() - 1
+            });
+            let cluster = &mut clusters[position];
+            cluster.count += 1;
+            if entry.timestamp.is_some() {
+                cluster.last_seen = entry.timestamp;
+            }
+            if let Some(pod) = entry.pod_name {
+                if !cluster.pods.contains(&pod) {
+                    cluster.pods.push(pod);
+                }
+            }
+        }
+
+        // Pods are only worth listing when the lines came from several
+        for cluster in &mut clusters {
+            if cluster.pods.len() < 2 {
+                cluster.pods.clear();
+            }
+        }
+
+        // Stable, so equally frequent clusters stay in order of appearance
+        clusters.sort_by_key(|c| std::cmp::Reverse(c.count));
+        let total_clusters = clusters.len();
+        let mut omitted_lines = 0;
+        if let Some(top) = top {
+            omitted_lines = clusters.iter().skip(top).map(|c| c.count).sum();
+            clusters.truncate(top);
+        }
+
+        self.clusters = Some(LogClusterSummary {
+            total_clusters,
+            omitted_lines,
+            clusters,
+        });
+    }
+
+    /// Merge the logs of a workload's pods into one summary, interleaving
+    /// the lines by timestamp and labelling each with its pod and container
+    ///
+    /// The per-pod counts are taken before `errors_only` filtering so that
+    /// healthy replicas are still reported as such.
+    pub fn from_replicas(
+        kind: String,
+        name: String,
+        pods_found: usize,
+        replicas: Vec<ReplicaLogs>,
+        container: Option<String>,
+        tail_lines: Option<i64>,
+        filter_errors_only: bool,
+    ) -> Self {
+        let mut merged = Vec::new();
+        let mut summaries = Vec::new();
+        for replica in replicas {
+            let mut summary = ReplicaLogSummary {
+                pod_name: replica.pod_name.clone(),
+                namespace: replica.namespace,
+                health_status: replica.health_status,
+                state: ReplicaLogState::Healthy,
+                total_lines: 0,
+                error_count: 0,
+                warning_count: 0,
+                last_error: None,
+                fetch_error: None,
+            };
+            match replica.entries {
+                Ok(entries) => {
+                    for entry in entries {
+                        let mut entry = AnalyzedLogEntry::from(entry);
+                        entry.pod_name.get_or_insert_with(|| replica.pod_name.clone());
+                        entry.container = container.clone();
+                        summary.total_lines += 1;
+                        if entry.is_error {
+                            summary.error_count += 1;
+                            summary.last_error = Some(entry.content.clone());
+                        } else if entry.is_warning {
+                            summary.warning_count += 1;
+                        }
+                        merged.push(entry);
+                    }
+                    summary.state = if summary.error_count > 0 {
+                        ReplicaLogState::Erroring
+                    } else if summary.warning_count > 0 {
+                        ReplicaLogState::Warning
+                    } else {
+                        ReplicaLogState::Healthy
+                    };
+                }
+                Err(message) => {
+                    summary.state = ReplicaLogState::Unavailable;
+                    summary.fetch_error = Some(message);
+                }
+            }
+            summaries.push(summary);
+        }
+
+        // Lines without a parseable timestamp go last, in the order received
+        merged.sort_by_key(|entry| {
+            let time = entry
+                .timestamp
+                .as_deref()
+                .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok());
+            (time.is_none(), time)
+        });
+        summaries.sort_by(|a, b| a.state.cmp(&b.state).then(a.pod_name.cmp(&b.pod_name)));
+
+        let total = merged.len();
+        if filter_errors_only {
+            merged.retain(|entry| entry.potential_issue);
+        }
+        let mut summary = PodLogsSummary::from_analyzed(merged, None, container, tail_lines);
+        summary.filtered = filter_errors_only;
+        summary.workload = Some(WorkloadLogsSummary {
+            kind,
+            name,
+            pods_found,
+            lines_received: total,
+            replicas: summaries,
+        });
+        summary
+    }
+}
+
+/// Logs fetched from one pod of a workload
+#[derive(Debug, Clone)]
+pub struct ReplicaLogs {
+    pub pod_name: String,
+    pub namespace: Option<String>,
+    pub health_status: Option<String>,
+    /// The pod's log lines, or why they could not be fetched
+    pub entries: Result<Vec<LogEntry>, String>,
+}
+
+/// Log lines collapsed into clusters (`cluster` / `top_clusters`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogClusterSummary {
+    /// Distinct patterns among the lines
+    pub total_clusters: usize,
+    /// Lines in the clusters left out by `top_clusters`
+    pub omitted_lines: usize,
+    /// Most frequent first
+    pub clusters: Vec<LogCluster>,
+}
+
+/// Log lines that only differ in numbers, IDs, addresses or timestamps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogCluster {
+    /// The lines with their varying parts masked, e.g. `connection to <ip> failed after <num>ms`
+    pub pattern: String,
+    pub level: LogLevel,
+    pub count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+    /// The first line of the cluster as written
+    pub example: String,
+    /// Pods that wrote the lines, when there were several
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pods: Vec<String>,
+}
+
+/// Template of a log line with the parts that change between occurrences
+/// masked, so repeated lines can be grouped
+///
+/// Timestamps and times of day become `<ts>`, UUIDs `<uuid>`, IPv4
+/// addresses (with an optional port) `<ip>`, numbers `<num>` (a unit suffix
+/// such as `ms` is kept) and any other word containing a digit, such as a
+/// pod hash or `0x1f`, `<id>`.
+pub fn log_template(content: &str) -> String {
+    let chars: Vec<char> = content.chars().collect();
+    let mut template = String::with_capacity(content.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !is_word_char(chars[i]) {
+            template.push(chars[i]);
+            i += 1;
+            continue;
+        }
+
+        let rest = &chars[i..];
+        let masked = match_timestamp(rest)
+            .map(|len| (len, "<ts>"))
+            .or_else(|| match_uuid(rest).map(|len| (len, "<uuid>")))
+            .or_else(|| match_ipv4(rest).map(|len| (len, "<ip>")));
+        if let Some((len, mask)) = masked {
+            template.push_str(mask);
+            i += len;
+            continue;
+        }
+
+        let word_len = rest.iter().take_while(|c| is_word_char(**c)).count();
+        let word = &rest[..word_len];
+        let number_len = leading_digits(word);
+        if number_len == 0 && !word.iter().any(char::is_ascii_digit) {
+            template.extend(word);
+            i += word_len;
+        } else if number_len > 0 && word[number_len..].iter().all(char::is_ascii_alphabetic) {
+            // A number, possibly decimal or a dotted version, with an optional unit
+            let mut len = number_len;
+            while rest.get(len) == Some(&'.')
+                && rest.get(len + 1).is_some_and(char::is_ascii_digit)
+            {
+                len += 1 + leading_digits(&rest[len + 1..]);
+            }
+            let unit_len = rest[len..].iter().take_while(|c| c.is_ascii_alphabetic()).count();
+            template.push_str("<num>");
+            template.extend(&rest[len..len + unit_len]);
+            i += len + unit_len;
+        } else {
+            template.push_str("<id>");
+            i += word_len;
+        }
+    }
+    template
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn leading_digits(chars: &[char]) -> usize {
+    chars.iter().take_while(|c| c.is_ascii_digit()).count()
+}
+
+/// Groups of exactly `widths` digits joined by one of `separators`;
+/// returns the length matched
+fn digit_groups(chars: &[char], widths: &[usize], separators: &[char]) -> Option<usize> {
+    let mut i = 0;
+    for (n, width) in widths.iter().enumerate() {
+        if n > 0 {
+            if !separators.contains(chars.get(i)?) {
+                return None;
+            }
+            i += 1;
+        }
+        if leading_digits(&chars[i..]) != *width {
+            return None;
+        }
+        i += width;
+    }
+    Some(i)
+}
+
+/// `2026-10-17`, `2026/10/17`, `10:00:03.5` or both joined by `T` or a
+/// space, with an optional `Z` or numeric zone
+fn match_timestamp(chars: &[char]) -> Option<usize> {
+    let time = |chars: &[char]| {
+        let mut len = digit_groups(chars, &[2, 2, 2], &[':'])?;
+        if matches!(chars.get(len), Some('.' | ','))
+            && chars.get(len + 1).is_some_and(char::is_ascii_digit)
+        {
+            len += 1 + leading_digits(&chars[len + 1..]);
+        }
+        Some(len)
+    };
+
+    let mut len = match digit_groups(chars, &[4, 2, 2], &['-', '/']) {
+        Some(date) => match chars.get(date) {
+            Some('T' | ' ') => match time(&chars[date + 1..]) {
+                Some(time) => date + 1 + time,
+                None => return Some(date),
+            },
+            _ => return Some(date),
+        },
+        None => time(chars)?,
+    };
+    match chars.get(len) {
+        Some('Z') => len += 1,
+        Some('+' | '-') => {
+            if let Some(zone) = digit_groups(&chars[len + 1..], &[2, 2], &[':'])
+                .or_else(|| digit_groups(&chars[len + 1..], &[4], &[]))
+            {
+                len += 1 + zone;
+            }
+        }
+        _ => {}
+    }
+    Some(len)
+}
+
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` in hex digits, as a whole word
+fn match_uuid(chars: &[char]) -> Option<usize> {
+    let mut i = 0;
+    for (n, width) in [8, 4, 4, 4, 12].into_iter().enumerate() {
+        if n > 0 {
+            if chars.get(i) != Some(&'-') {
+                return None;
+            }
+            i += 1;
+        }
+        let group = chars.get(i..i + width)?;
+        if !group.iter().all(char::is_ascii_hexdigit) {
+            return None;
+        }
+        i += width;
+    }
+    (!chars.get(i).is_some_and(|c| is_word_char(*c))).then_some(i)
+}
+
+/// Dotted IPv4 address with an optional `:port`
+fn match_ipv4(chars: &[char]) -> Option<usize> {
+    let mut i = 0;
+    for n in 0..4 {
+        if n > 0 {
+            if chars.get(i) != Some(&'.') {
+                return None;
+            }
+            i += 1;
+        }
+        let digits = leading_digits(&chars[i..]);
+        if !(1..=3).contains(&digits) {
+            return None;
+        }
+        i += digits;
+    }
+    if chars.get(i) == Some(&':') && chars.get(i + 1).is_some_and(char::is_ascii_digit) {
+        i += 1 + leading_digits(&chars[i + 1..]);
+    }
+    (!chars.get(i).is_some_and(|c| is_word_char(*c))).then_some(i)
+}
+
+/// Pods whose logs were merged, and how each of them is doing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadLogsSummary {
+    pub kind: String,
+    pub name: String,
+    /// Pods found under the workload; only the first `replicas.len()` are fetched
+    pub pods_found: usize,
+    /// Lines received from all pods, before `errors_only` filtering
+    pub lines_received: usize,
+    /// Erroring replicas first
+    pub replicas: Vec<ReplicaLogSummary>,
+}
+
+impl WorkloadLogsSummary {
+    /// Number of replicas in the given state
+    pub fn count(&self, state: ReplicaLogState) -> usize {
+        self.replicas.iter().filter(|r| r.state == state).count()
+    }
+}
+
+/// What a replica's logs say about it, worst first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ReplicaLogState {
+    /// At least one ERROR or FATAL line
+    Erroring,
+    /// Warnings but no errors
+    Warning,
+    Healthy,
+    /// The logs could not be fetched
+    Unavailable,
+}
+
+/// Log counts of one pod of a workload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplicaLogSummary {
+    pub pod_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Pod health as reported by ArgoCD
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<String>,
+    pub state: ReplicaLogState,
+    pub total_lines: usize,
+    pub error_count: usize,
+    pub warning_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fetch_error: Option<String>,
+}
+
+/// Pod or resource whose logs are requested, and how much of them
+#[derive(Debug, Clone, Default)]
+pub struct PodLogsQuery {
+    pub application_name: String,
+    pub namespace: Option<String>,
+    pub pod_name: Option<String>,
+    pub container: Option<String>,
+    pub since_seconds: Option<i64>,
+    pub tail_lines: Option<i64>,
+    pub previous: Option<bool>,
+    /// Server-side text filter
+    pub filter: Option<String>,
+    pub kind: Option<String>,
+    pub group: Option<String>,
+    pub resource_name: Option<String>,
+    pub app_namespace: Option<String>,
+    pub project: Option<String>,
+}
+
+/// Bounds for following a log stream
+#[derive(Debug, Clone)]
+pub struct LogFollowOptions {
+    /// Stop after this long
+    pub max_duration: Duration,
+    /// Stop after this many lines
+    pub max_lines: usize,
+    /// Stop at the first line containing this text
+    pub stop_pattern: Option<String>,
+    /// Hand lines over once this many have been received...
+    pub batch_lines: usize,
+    /// ...or this long after the previous batch
+    pub batch_interval: Duration,
+}
+
+impl Default for LogFollowOptions {
+    fn default() -> Self {
+        Self {
+            max_duration: Duration::from_secs(30),
+            max_lines: 1000,
+            stop_pattern: None,
+            batch_lines: 50,
+            batch_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Why a followed log stream stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LogFollowStop {
+    /// A line contained the stop pattern
+    PatternMatched,
+    /// The line limit was reached
+    LineLimit,
+    /// The duration limit was reached
+    TimeLimit,
+    /// ArgoCD closed the stream, e.g. because the container exited
+    StreamEnded,
+}
+
+/// How a followed log stream went
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogFollowSummary {
+    pub stopped: LogFollowStop,
+    pub elapsed_seconds: u64,
+    /// Lines received, before `errors_only` filtering
+    pub lines_received: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub matched_line: Option<String>,
+}
+
+/// Lines received from a followed log stream since the previous batch
+#[derive(Debug, Clone)]
+pub struct LogBatch {
+    pub entries: Vec<AnalyzedLogEntry>,
+    /// Lines received so far, including this batch
+    pub lines_received: usize,
+    pub elapsed_seconds: u64,
+}
+
+impl LogBatch {
+    /// One-line description used as the progress message
+    pub fn message(&self) -> String {
+        let errors = self.entries.iter().filter(|e| e.is_error).count();
+        let mut message = format!(
+            "{} line(s) after {}s",
+            self.lines_received, self.elapsed_seconds
+        );
+        if errors > 0 {
+            message.push_str(&format!(", {} error(s) in this batch", errors));
+        }
+        if let Some(latest) = self.entries.last() {
+            let content: String = latest.content.chars().take(200).collect();
+            message.push_str(&format!("; latest: {}", content));
         }
+        message
     }
 }
 
-/// Manifest structures for GetManifests
+// Manifest structures for GetManifests
 
 /// ManifestResponse contains application manifests and metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -892,7 +2130,7 @@ impl From<ManifestResponse> for ManifestSummary {
     }
 }
 
-/// Kubernetes Event structures for ListResourceEvents
+// Kubernetes Event structures for ListResourceEvents
 
 /// EventList is a list of events
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1122,7 +2360,7 @@ impl From<Event> for EventSummary {
     }
 }
 
-/// Revision Metadata structures for RevisionMetadata endpoint
+// Revision Metadata structures for RevisionMetadata endpoint
 
 /// RevisionMetadata contains metadata about a specific revision
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1299,6 +2537,9 @@ pub struct ApplicationRollbackSummary {
     pub health_status: Option<String>,
     /// Target revision after rollback
     pub target_revision: Option<String>,
+    /// Every source of a multi-source application, with its current revision
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceSummary>,
     /// Whether prune was enabled
     pub prune_enabled: bool,
 }
@@ -1332,8 +2573,10 @@ impl ApplicationRollbackSummary {
         let target_revision = app
             .spec
             .as_ref()
-            .and_then(|s| s.source.as_ref())
-            .and_then(|src| src.target_revision.clone());
+            .and_then(|s| s.primary_source())
+            .and_then(|src| src.target_revision);
+
+        let sources = app.multi_source_summaries();
 
         ApplicationRollbackSummary {
             name,
@@ -1343,6 +2586,7 @@ impl ApplicationRollbackSummary {
             sync_revision,
             health_status,
             target_revision,
+            sources,
             prune_enabled: prune,
         }
     }
@@ -1434,7 +2678,7 @@ pub struct ApplicationSyncRequest {
 }
 
 /// RetryStrategy defines the retry strategy for sync operations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RetryStrategy {
     /// Maximum number of retry attempts
@@ -1446,7 +2690,7 @@ pub struct RetryStrategy {
 }
 
 /// Backoff defines the backoff strategy
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct Backoff {
     /// Duration of the backoff (e.g., "5s", "1m")
@@ -1475,6 +2719,9 @@ pub struct ApplicationSyncSummary {
     pub health_status: Option<String>,
     /// Target revision that was synced to
     pub target_revision: Option<String>,
+    /// Every source of a multi-source application, with its current revision
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceSummary>,
     /// Whether prune was enabled
     pub prune_enabled: bool,
     /// Whether force was enabled
@@ -1521,8 +2768,10 @@ impl ApplicationSyncSummary {
         let target_revision = app
             .spec
             .as_ref()
-            .and_then(|s| s.source.as_ref())
-            .and_then(|src| src.target_revision.clone());
+            .and_then(|s| s.primary_source())
+            .and_then(|src| src.target_revision);
+
+        let sources = app.multi_source_summaries();
 
         ApplicationSyncSummary {
             name,
@@ -1531,6 +2780,7 @@ impl ApplicationSyncSummary {
             sync_revision,
             health_status,
             target_revision,
+            sources,
             prune_enabled: prune,
             force_enabled: force,
             sync_options,
@@ -1777,6 +3027,9 @@ pub struct RevisionHistorySummary {
     pub initiated_by: Option<String>,
     /// Whether deployment was automated
     pub automated: bool,
+    /// Every source deployed by a multi-source entry, with its revision
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceSummary>,
 }
 
 impl RevisionHistorySummary {
@@ -1784,7 +3037,14 @@ impl RevisionHistorySummary {
     pub fn from_revision_history(
         history: RevisionHistory,
     ) -> Self {
-        let revision_full = history.revision.clone().unwrap_or_else(|| "unknown".to_string());
+        // Multi-source entries record one revision per source instead of `revision`
+        let revisions = history.revisions.clone().unwrap_or_default();
+        let revision_full = history
+            .revision
+            .clone()
+            .filter(|r| !r.is_empty())
+            .or_else(|| revisions.first().cloned())
+            .unwrap_or_else(|| "unknown".to_string());
         let revision = if revision_full.len() > 8 {
             revision_full[..8].to_string()
         } else {
@@ -1821,6 +3081,11 @@ impl RevisionHistorySummary {
             (None, None, None)
         };
 
+        let sources = match &history.sources {
+            Some(sources) if !sources.is_empty() => SourceSummary::list(sources, &revisions),
+            _ => Vec::new(),
+        };
+
         // Get initiator information
         let (initiated_by, automated) = if let Some(initiator) = &history.initiated_by {
             let username = if let Some(username) = &initiator.username {
@@ -1846,6 +3111,7 @@ impl RevisionHistorySummary {
             source_target_revision,
             initiated_by,
             automated,
+            sources,
         }
     }
 }
@@ -1880,6 +3146,9 @@ pub struct RefreshApplicationSummary {
     pub repo_url: Option<String>,
     /// Target revision (branch/tag)
     pub target_revision: Option<String>,
+    /// Every source of a multi-source application, with its revision after refresh
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<SourceSummary>,
 }
 
 /// ApplicationResourcePatchRequest contains parameters for patching a resource
@@ -1913,3 +3182,1946 @@ pub struct ApplicationResourcePatchRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
 }
+
+impl ApplicationSpec {
+    /// Whether manifests come from `spec.sources` rather than `spec.source`
+    pub fn has_multiple_sources(&self) -> bool {
+        self.sources.as_ref().is_some_and(|sources| !sources.is_empty())
+    }
+
+    /// Sources manifests are rendered from, in position order
+    ///
+    /// Mirrors ArgoCD: a non-empty `sources` wins over `source`, and a
+    /// hydrated application reports its dry source.
+    pub fn effective_sources(&self) -> Vec<ApplicationSource> {
+        if self.has_multiple_sources() {
+            return self.sources.clone().unwrap_or_default();
+        }
+        if let Some(source) = &self.source {
+            return vec![source.clone()];
+        }
+        self.source_hydrator
+            .as_ref()
+            .map(|hydrator| {
+                vec![ApplicationSource {
+                    repo_url: hydrator.dry_source.repo_url.clone(),
+                    path: Some(hydrator.dry_source.path.clone()),
+                    target_revision: Some(hydrator.dry_source.target_revision.clone()),
+                    ..Default::default()
+                }]
+            })
+            .unwrap_or_default()
+    }
+
+    /// First effective source, which single-value summary fields describe
+    pub fn primary_source(&self) -> Option<ApplicationSource> {
+        self.effective_sources().into_iter().next()
+    }
+
+    /// Problems ArgoCD would reject the spec for, checked locally so dry runs
+    /// can report them without creating anything
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        if self.project.as_deref().unwrap_or("").is_empty() {
+            problems.push("spec.project is required (use \"default\" if unsure)".to_string());
+        }
+
+        match &self.destination {
+            None => problems.push("spec.destination is required".to_string()),
+            Some(destination) => match (&destination.server, &destination.name) {
+                (Some(_), Some(_)) => problems.push(
+                    "spec.destination must set either server or name, not both".to_string(),
+                ),
+                (None, None) => {
+                    problems.push("spec.destination must set server or name".to_string())
+                }
+                _ => {}
+            },
+        }
+
+        let sources = self.source.iter().chain(self.sources.iter().flatten());
+        match (&self.source, &self.sources) {
+            (Some(_), Some(_)) => {
+                problems.push("set either spec.source or spec.sources, not both".to_string())
+            }
+            (None, None) if self.source_hydrator.is_none() => problems
+                .push("spec.source, spec.sources or spec.sourceHydrator is required".to_string()),
+            (None, Some(sources)) if sources.is_empty() => {
+                problems.push("spec.sources must not be empty".to_string())
+            }
+            _ => {}
+        }
+        for source in sources {
+            if source.repo_url.is_empty() {
+                problems.push("every source needs a repoURL".to_string());
+            }
+            if source.chart.is_some() && source.path.is_some() {
+                problems.push(format!(
+                    "source {} sets both chart and path",
+                    source.repo_url
+                ));
+            }
+        }
+
+        problems
+    }
+
+    /// Top-level spec fields (camelCase) whose values differ from `other`
+    pub fn changed_fields(&self, other: &ApplicationSpec) -> Vec<String> {
+        changed_fields(other, self)
+    }
+}
+
+/// Top-level fields (as serialized) that differ between two values
+pub fn changed_fields<T: Serialize>(before: &T, after: &T) -> Vec<String> {
+    let as_map = |value: &T| match serde_json::to_value(value) {
+        Ok(serde_json::Value::Object(map)) => map,
+        _ => serde_json::Map::new(),
+    };
+    let (before, after) = (as_map(before), as_map(after));
+
+    let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .cloned()
+        .collect()
+}
+
+/// Compact view of an application spec for create/update output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSpecSummary {
+    pub project: Option<String>,
+    /// Sources as "repoURL path|chart@revision"
+    pub sources: Vec<String>,
+    pub destination_server: Option<String>,
+    pub destination_name: Option<String>,
+    pub destination_namespace: Option<String>,
+    pub automated_sync: bool,
+    pub self_heal: bool,
+    pub auto_prune: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sync_options: Vec<String>,
+}
+
+impl ApplicationSpecSummary {
+    pub fn from_spec(spec: &ApplicationSpec) -> Self {
+        let sources = spec
+            .source
+            .iter()
+            .chain(spec.sources.iter().flatten())
+            .map(|source| {
+                let mut description = source.repo_url.clone();
+                if let Some(target) = source.chart.as_ref().or(source.path.as_ref()) {
+                    description.push(' ');
+                    description.push_str(target);
+                }
+                if let Some(revision) = &source.target_revision {
+                    description.push('@');
+                    description.push_str(revision);
+                }
+                description
+            })
+            .collect();
+
+        let destination = spec.destination.as_ref();
+        let sync_policy = spec.sync_policy.as_ref();
+        let automated = sync_policy.and_then(|p| p.automated.as_ref());
+
+        ApplicationSpecSummary {
+            project: spec.project.clone(),
+            sources,
+            destination_server: destination.and_then(|d| d.server.clone()),
+            destination_name: destination.and_then(|d| d.name.clone()),
+            destination_namespace: destination.and_then(|d| d.namespace.clone()),
+            automated_sync: automated.is_some(),
+            self_heal: automated.and_then(|a| a.self_heal).unwrap_or(false),
+            auto_prune: automated.and_then(|a| a.prune).unwrap_or(false),
+            sync_options: sync_policy
+                .and_then(|p| p.sync_options.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Result of create_application (or of its dry-run checks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationCreateSummary {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub dry_run: bool,
+    pub upsert: bool,
+    pub validate: bool,
+    /// Whether an application with this name already existed (dry run only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub already_exists: Option<bool>,
+    /// Problems found in the spec (dry run only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub problems: Vec<String>,
+    pub spec: ApplicationSpecSummary,
+}
+
+/// Result of update_application_spec (or of its dry-run checks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSpecUpdateSummary {
+    pub name: String,
+    pub dry_run: bool,
+    pub validate: bool,
+    /// Top-level spec fields that differ from the current spec
+    pub changed_fields: Vec<String>,
+    /// Problems found in the new spec (dry run only)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub problems: Vec<String>,
+    pub spec: ApplicationSpecSummary,
+}
+
+/// Result of delete_application (or of its dry-run checks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationDeleteSummary {
+    pub name: String,
+    pub dry_run: bool,
+    /// Whether the application's resources are deleted with it
+    pub cascade: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub propagation_policy: Option<String>,
+    /// Number of resources in the application's tree when it was checked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_count: Option<usize>,
+    /// Resources by kind, as "Kind: count"
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub resources_by_kind: Vec<String>,
+}
+
+impl ApplicationDeleteSummary {
+    pub fn new(
+        name: String,
+        dry_run: bool,
+        cascade: bool,
+        propagation_policy: Option<String>,
+        tree: Option<&ApplicationTree>,
+    ) -> Self {
+        let mut by_kind: std::collections::BTreeMap<String, usize> =
+            std::collections::BTreeMap::new();
+        for node in tree.map(|t| t.nodes.as_slice()).unwrap_or_default() {
+            *by_kind
+                .entry(node.kind.clone().unwrap_or_else(|| "Unknown".to_string()))
+                .or_default() += 1;
+        }
+
+        ApplicationDeleteSummary {
+            name,
+            dry_run,
+            cascade,
+            propagation_policy,
+            resource_count: tree.map(|t| t.nodes.len()),
+            resources_by_kind: by_kind
+                .into_iter()
+                .map(|(kind, count)| format!("{}: {}", kind, count))
+                .collect(),
+        }
+    }
+}
+
+/// Result of terminate_operation, with the operation phase before and after
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TerminateOperationSummary {
+    pub name: String,
+    /// Operation phase before terminating (None if the app never ran an operation)
+    pub phase_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
+    /// Whether a terminate request was sent (false when nothing was running)
+    pub terminated: bool,
+    /// Operation phase right after the request, usually "Terminating" or "Failed"
+    pub phase_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_after: Option<String>,
+}
+
+/// States wait_for_application waits for, like `argocd app wait`
+#[derive(Debug, Clone, Default)]
+pub struct WaitConditions {
+    /// Wait until the application is Synced
+    pub sync: bool,
+    /// Wait until the application is Healthy
+    pub health: bool,
+    /// Wait until no operation is pending or running
+    pub operation: bool,
+    /// A sync or rollback was just requested, so an application without an
+    /// operation state has not picked it up yet rather than having nothing to run
+    pub operation_requested: bool,
+    /// `startedAt` of the operation recorded before a sync or rollback was
+    /// requested. Until a newer operation shows up that one still counts as
+    /// pending, since ArgoCD only replaces it once the controller picks up the
+    /// request
+    pub previous_operation_started_at: Option<String>,
+}
+
+impl WaitConditions {
+    /// Wait for sync, health and operation when none was picked, as the CLI does
+    pub fn new(sync: bool, health: bool, operation: bool) -> Self {
+        let all = !(sync || health || operation);
+        WaitConditions {
+            sync: sync || all,
+            health: health || all,
+            operation: operation || all,
+            operation_requested: false,
+            previous_operation_started_at: None,
+        }
+    }
+
+    /// Names of the selected conditions
+    pub fn names(&self) -> Vec<String> {
+        [("sync", self.sync), ("health", self.health), ("operation", self.operation)]
+            .into_iter()
+            .filter(|(_, selected)| *selected)
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    /// Conditions the application does not meet yet, e.g. "sync is OutOfSync"
+    pub fn unmet(&self, app: &Application) -> Vec<String> {
+        let status = app.status.as_ref();
+        let mut unmet = Vec::new();
+
+        let sync = status.and_then(|s| s.sync.as_ref()).map(|s| s.status.as_str());
+        if self.sync && sync != Some("Synced") {
+            unmet.push(format!("sync is {}", sync.unwrap_or("Unknown")));
+        }
+        let health = status.and_then(|s| s.health.as_ref()).map(|h| h.status.as_str());
+        if self.health && health != Some("Healthy") {
+            unmet.push(format!("health is {}", health.unwrap_or("Unknown")));
+        }
+        if self.operation {
+            match self.current_operation(app) {
+                None => unmet.push("operation has not started".to_string()),
+                Some(op) if op.is_in_progress() => {
+                    unmet.push(format!("operation is {}", op.phase))
+                }
+                Some(_) => {}
+            }
+        }
+
+        unmet
+    }
+
+    /// Why the wait can stop early: the awaited operation finished unsuccessfully
+    pub fn failure(&self, app: &Application) -> Option<String> {
+        if !self.operation {
+            return None;
+        }
+        let op = self.current_operation(app)?;
+        match op.phase.as_str() {
+            "Failed" | "Error" => Some(format!(
+                "operation {}: {}",
+                op.phase,
+                op.message.as_deref().unwrap_or("no message")
+            )),
+            _ => None,
+        }
+    }
+
+    /// Operation state, ignoring the one recorded before the wait began
+    ///
+    /// Returns a placeholder "Succeeded" state when no operation was requested
+    /// and the application never ran one. After a requested operation a missing
+    /// state means it is still pending.
+    fn current_operation(&self, app: &Application) -> Option<OperationState> {
+        let state = app.status.as_ref().and_then(|s| s.operation_state.clone());
+        match (&state, &self.previous_operation_started_at) {
+            (Some(op), Some(previous)) if op.started_at.as_ref() == Some(previous) => None,
+            (None, None) if !self.operation_requested => Some(OperationState {
+                phase: "Succeeded".to_string(),
+                message: None,
+                started_at: None,
+                finished_at: None,
+                operation: None,
+                sync_result: None,
+                retry_count: None,
+            }),
+            (None, _) => None,
+            _ => state,
+        }
+    }
+}
+
+/// Snapshot reported after every poll of wait_for_application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitProgress {
+    pub polls: u32,
+    pub elapsed_seconds: u64,
+    pub timeout_seconds: u64,
+    /// Conditions still unmet, e.g. "health is Progressing"
+    pub unmet: Vec<String>,
+}
+
+impl WaitProgress {
+    /// One-line progress message
+    pub fn message(&self) -> String {
+        if self.unmet.is_empty() {
+            format!("done after {}s", self.elapsed_seconds)
+        } else {
+            format!(
+                "{}s/{}s: waiting until {}",
+                self.elapsed_seconds,
+                self.timeout_seconds,
+                self.unmet.join(", ")
+            )
+        }
+    }
+}
+
+/// How a wait ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaitOutcome {
+    /// Every condition was met
+    Succeeded,
+    /// The awaited operation failed or errored
+    OperationFailed,
+    /// The timeout elapsed first
+    TimedOut,
+}
+
+/// Final state of an application after waiting for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationWaitSummary {
+    pub name: String,
+    pub outcome: WaitOutcome,
+    /// Conditions that were waited for (sync, health, operation)
+    pub waited_for: Vec<String>,
+    /// Conditions still unmet when the wait ended
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unmet: Vec<String>,
+    pub elapsed_seconds: u64,
+    pub polls: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_revision: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_message: Option<String>,
+    /// Error conditions such as SyncError or ComparisonError
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApplicationCondition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<OperationSummary>,
+    /// Managed resources that are out of sync or not healthy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource_issues: Vec<ResourceStatus>,
+}
+
+impl ApplicationWaitSummary {
+    pub fn new(
+        app: Application,
+        conditions: &WaitConditions,
+        outcome: WaitOutcome,
+        progress: &WaitProgress,
+    ) -> Self {
+        let detail = ApplicationDetailOutput::from(app);
+        ApplicationWaitSummary {
+            name: detail.name,
+            outcome,
+            waited_for: conditions.names(),
+            unmet: progress.unmet.clone(),
+            elapsed_seconds: progress.elapsed_seconds,
+            polls: progress.polls,
+            sync_status: detail.sync_status,
+            sync_revision: detail.sync_revision,
+            health_status: detail.health_status,
+            health_message: detail.health_message,
+            errors: detail.conditions.into_iter().filter(|c| c.is_error()).collect(),
+            operation: detail.operation,
+            resource_issues: detail.resource_issues,
+        }
+    }
+}
+
+/// Response of `GET /api/v1/applications/{name}/resource/actions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceActionsListResponse {
+    #[serde(default)]
+    pub actions: Vec<ResourceAction>,
+}
+
+/// An action (built-in or custom Lua) that can be run on a resource
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceAction {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Vec<ResourceActionParam>>,
+    /// Set when the action does not apply to the resource's current state
+    #[serde(default)]
+    pub disabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_class: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceActionParam {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub param_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+/// Actions available on a resource (context-efficient)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceActionsSummary {
+    pub application_name: String,
+    pub kind: String,
+    pub resource_name: String,
+    pub namespace: Option<String>,
+    pub actions: Vec<ResourceActionSummary>,
+    pub available_count: usize,
+    pub disabled_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceActionSummary {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_name: Option<String>,
+    pub disabled: bool,
+    /// Parameter names the action accepts
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+}
+
+impl ResourceActionsSummary {
+    pub fn from_response(
+        response: ResourceActionsListResponse,
+        application_name: String,
+        kind: String,
+        resource_name: String,
+        namespace: Option<String>,
+    ) -> Self {
+        let actions: Vec<ResourceActionSummary> = response
+            .actions
+            .into_iter()
+            .map(|action| ResourceActionSummary {
+                name: action.name,
+                display_name: action.display_name,
+                disabled: action.disabled,
+                params: action
+                    .params
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|p| p.name)
+                    .collect(),
+            })
+            .collect();
+        let disabled_count = actions.iter().filter(|a| a.disabled).count();
+
+        ResourceActionsSummary {
+            application_name,
+            kind,
+            resource_name,
+            namespace,
+            available_count: actions.len() - disabled_count,
+            disabled_count,
+            actions,
+        }
+    }
+}
+
+/// Result of run_resource_action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceActionRunSummary {
+    pub application_name: String,
+    pub kind: String,
+    pub resource_name: String,
+    pub namespace: Option<String>,
+    pub action: String,
+    /// Parameters passed to the action, as "name=value"
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<String>,
+}
+
+/// Result of delete_resource, including the preview shown before deleting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceDeleteSummary {
+    /// The resource as it was before deletion
+    pub resource: ApplicationResourceSummary,
+    /// Resources owned by it according to the application's resource tree
+    pub children: Vec<ResourceNodeSummary>,
+    pub dry_run: bool,
+    pub force: bool,
+    /// Whether children are orphaned (left running) instead of deleted
+    pub orphan: bool,
+}
+
+/// AppProject (ArgoCD `v1alpha1.AppProject`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppProject {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<AppProjectSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<AppProjectStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppProjectList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMetadata>,
+    #[serde(default)]
+    pub items: Vec<AppProject>,
+}
+
+/// What applications in a project may deploy, and where
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AppProjectSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Repositories applications may use ("*" allows any)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_repos: Option<Vec<String>>,
+    /// Namespaces Application resources of this project may live in
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_namespaces: Option<Vec<String>>,
+    /// Clusters and namespaces applications may deploy to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destinations: Option<Vec<ApplicationDestination>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_resource_whitelist: Option<Vec<GroupKind>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_resource_blacklist: Option<Vec<GroupKind>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace_resource_whitelist: Option<Vec<GroupKind>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace_resource_blacklist: Option<Vec<GroupKind>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub roles: Option<Vec<ProjectRole>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_windows: Option<Vec<SyncWindow>>,
+    /// Warn about (or prune) resources in destination namespaces no application manages
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orphaned_resources: Option<serde_json::Value>,
+    /// GnuPG key IDs commits must be signed with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_keys: Option<Vec<SignatureKey>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permit_only_project_scoped_clusters: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_service_accounts: Option<Vec<serde_json::Value>>,
+}
+
+/// Kubernetes API group and kind ("*" matches any)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupKind {
+    /// API group ("" for core resources)
+    #[serde(default)]
+    pub group: String,
+    pub kind: String,
+}
+
+impl std::fmt::Display for GroupKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.group.is_empty() {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "{}/{}", self.group, self.kind)
+        }
+    }
+}
+
+/// Project role, granting its policies to SSO groups and JWT tokens
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRole {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Casbin policies, e.g. "p, proj:my-project:ci, applications, sync, my-project/*, allow"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
+    /// Tokens issued for the role (older ArgoCD versions keep them here)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_tokens: Option<Vec<JwtToken>>,
+    /// SSO groups bound to the role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+}
+
+/// A token issued for a project role (only its metadata; ArgoCD never returns the token itself again)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtToken {
+    /// Issued-at time (Unix seconds); identifies the token
+    pub iat: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+/// Sync window as defined on a project
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncWindow {
+    /// "allow" or "deny"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Cron schedule the window opens on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// How long the window stays open (e.g. "1h")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clusters: Option<Vec<String>>,
+    /// Whether manual syncs are allowed while the window applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manual_sync: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_zone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_and_operator: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SignatureKey {
+    #[serde(rename = "keyID")]
+    pub key_id: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppProjectStatus {
+    /// Tokens issued per role (ArgoCD v1.8+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jwt_tokens_by_role: Option<HashMap<String, JwtTokens>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JwtTokens {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<JwtToken>>,
+}
+
+impl AppProject {
+    pub fn name(&self) -> String {
+        self.metadata
+            .as_ref()
+            .map(|m| m.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Tokens of a role, from the role itself and from the project status
+    pub fn role_tokens(&self, role: &str) -> Vec<JwtToken> {
+        let from_spec = self
+            .spec
+            .as_ref()
+            .and_then(|s| s.roles.as_ref())
+            .and_then(|roles| roles.iter().find(|r| r.name == role))
+            .and_then(|r| r.jwt_tokens.clone())
+            .unwrap_or_default();
+        let from_status = self
+            .status
+            .as_ref()
+            .and_then(|s| s.jwt_tokens_by_role.as_ref())
+            .and_then(|by_role| by_role.get(role))
+            .and_then(|t| t.items.clone())
+            .unwrap_or_default();
+
+        let mut tokens: Vec<JwtToken> = Vec::new();
+        for token in from_spec.into_iter().chain(from_status) {
+            if !tokens.iter().any(|t| t.iat == token.iat && t.id == token.id) {
+                tokens.push(token);
+            }
+        }
+        tokens.sort_by_key(|t| t.iat);
+        tokens
+    }
+}
+
+/// Unix seconds as RFC 3339 (falls back to the number)
+fn unix_to_rfc3339(seconds: i64) -> String {
+    chrono::DateTime::from_timestamp(seconds, 0)
+        .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        .unwrap_or_else(|| seconds.to_string())
+}
+
+/// "server-or-name namespace" for a destination
+fn destination_label(destination: &ApplicationDestination) -> String {
+    format!(
+        "{} {}",
+        destination
+            .server
+            .as_deref()
+            .or(destination.name.as_deref())
+            .unwrap_or("*"),
+        destination.namespace.as_deref().unwrap_or("*")
+    )
+}
+
+/// Optimized summary for list_projects (context-efficient)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub source_repos_count: usize,
+    pub destinations_count: usize,
+    pub roles_count: usize,
+    pub sync_windows_count: usize,
+}
+
+impl From<&AppProject> for ProjectSummary {
+    fn from(project: &AppProject) -> Self {
+        let spec = project.spec.clone().unwrap_or_default();
+        ProjectSummary {
+            name: project.name(),
+            description: spec.description.filter(|d| !d.is_empty()),
+            source_repos_count: spec.source_repos.map_or(0, |v| v.len()),
+            destinations_count: spec.destinations.map_or(0, |v| v.len()),
+            roles_count: spec.roles.map_or(0, |v| v.len()),
+            sync_windows_count: spec.sync_windows.map_or(0, |v| v.len()),
+        }
+    }
+}
+
+/// Detailed project view for get_project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectDetail {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub source_repos: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub source_namespaces: Vec<String>,
+    /// Destinations as "server-or-name namespace"
+    pub destinations: Vec<String>,
+    /// Resource kinds as "group/kind"
+    pub cluster_resource_whitelist: Vec<String>,
+    pub cluster_resource_blacklist: Vec<String>,
+    pub namespace_resource_whitelist: Vec<String>,
+    pub namespace_resource_blacklist: Vec<String>,
+    pub roles: Vec<ProjectRoleSummary>,
+    pub sync_windows: Vec<SyncWindow>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRoleSummary {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub policies: Vec<String>,
+    pub groups: Vec<String>,
+    pub tokens: Vec<ProjectTokenSummary>,
+}
+
+/// Metadata of a role token; `token` is only set right after creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTokenSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub issued_at: i64,
+    pub issued_at_time: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl From<&JwtToken> for ProjectTokenSummary {
+    fn from(token: &JwtToken) -> Self {
+        ProjectTokenSummary {
+            id: token.id.clone().filter(|id| !id.is_empty()),
+            issued_at: token.iat,
+            issued_at_time: unix_to_rfc3339(token.iat),
+            expires_at_time: token.exp.filter(|exp| *exp > 0).map(unix_to_rfc3339),
+            token: None,
+        }
+    }
+}
+
+impl ProjectDetail {
+    pub fn from_project(project: &AppProject) -> Self {
+        let spec = project.spec.clone().unwrap_or_default();
+        let kinds = |list: Option<Vec<GroupKind>>| -> Vec<String> {
+            list.unwrap_or_default().iter().map(|gk| gk.to_string()).collect()
+        };
+
+        ProjectDetail {
+            name: project.name(),
+            namespace: project.metadata.as_ref().and_then(|m| m.namespace.clone()),
+            description: spec.description.filter(|d| !d.is_empty()),
+            source_repos: spec.source_repos.unwrap_or_default(),
+            source_namespaces: spec.source_namespaces.unwrap_or_default(),
+            destinations: spec
+                .destinations
+                .unwrap_or_default()
+                .iter()
+                .map(destination_label)
+                .collect(),
+            cluster_resource_whitelist: kinds(spec.cluster_resource_whitelist),
+            cluster_resource_blacklist: kinds(spec.cluster_resource_blacklist),
+            namespace_resource_whitelist: kinds(spec.namespace_resource_whitelist),
+            namespace_resource_blacklist: kinds(spec.namespace_resource_blacklist),
+            roles: spec
+                .roles
+                .unwrap_or_default()
+                .into_iter()
+                .map(|role| ProjectRoleSummary {
+                    tokens: project
+                        .role_tokens(&role.name)
+                        .iter()
+                        .map(ProjectTokenSummary::from)
+                        .collect(),
+                    name: role.name,
+                    description: role.description.filter(|d| !d.is_empty()),
+                    policies: role.policies.unwrap_or_default(),
+                    groups: role.groups.unwrap_or_default(),
+                })
+                .collect(),
+            sync_windows: spec.sync_windows.unwrap_or_default(),
+        }
+    }
+}
+
+/// Result of update_project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectUpdateSummary {
+    /// Top-level spec fields that changed
+    pub changed_fields: Vec<String>,
+    pub project: ProjectDetail,
+}
+
+/// Result of creating, updating or deleting a project role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectRoleChangeSummary {
+    pub project: String,
+    pub role: String,
+    /// "created", "updated" or "deleted"
+    pub action: String,
+    /// Roles of the project after the change
+    pub roles: Vec<ProjectRoleSummary>,
+}
+
+// ============================================================================
+// Cluster Models
+// ============================================================================
+
+/// Cluster registered in ArgoCD. Its `config` (bearer token, TLS keys, exec
+/// provider) is deliberately not modelled, so credentials are never read
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cluster {
+    /// API server URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Namespaces ArgoCD may manage; empty means all namespaces
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespaces: Option<Vec<String>>,
+    /// Whether cluster-scoped resources are managed when `namespaces` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_resources: Option<bool>,
+    /// Project the cluster is scoped to, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_requested_at: Option<String>,
+    /// Deprecated in favour of `info.connectionState`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_state: Option<ConnectionState>,
+    /// Deprecated in favour of `info.serverVersion`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub info: Option<ClusterInfo>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionState {
+    /// "Successful", "Failed" or "Unknown"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attempted_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_state: Option<ConnectionState>,
+    /// Kubernetes version, e.g. "1.29"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_info: Option<ClusterCacheInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_versions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterCacheInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apis_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_cache_sync_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMetadata>,
+    #[serde(default)]
+    pub items: Vec<Cluster>,
+}
+
+impl Cluster {
+    /// Whether an application destination points at this cluster, by server
+    /// URL or, for destinations without one, by cluster name
+    pub fn is_destination_of(&self, destination: &ApplicationDestination) -> bool {
+        match (&destination.server, &destination.name) {
+            (Some(server), _) if !server.is_empty() => {
+                self.server.as_deref().map(|s| s.trim_end_matches('/'))
+                    == Some(server.trim_end_matches('/'))
+            }
+            (_, Some(name)) if !name.is_empty() => self.name.as_deref() == Some(name.as_str()),
+            _ => false,
+        }
+    }
+
+    fn connection_state(&self) -> Option<&ConnectionState> {
+        self.info
+            .as_ref()
+            .and_then(|i| i.connection_state.as_ref())
+            .or(self.connection_state.as_ref())
+    }
+}
+
+/// Optimized summary for list_clusters (context-efficient)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterSummary {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kubernetes_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apis_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_cache_sync_time: Option<String>,
+    /// Namespaces ArgoCD may manage; empty means all namespaces
+    pub namespaces: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Applications whose destination is this cluster, as "name -> namespace"
+    pub applications: Vec<String>,
+}
+
+impl ClusterSummary {
+    /// Summarize a cluster, cross-referencing the applications deployed to it
+    pub fn new(cluster: &Cluster, applications: &[Application]) -> Self {
+        let connection = cluster.connection_state();
+        let info = cluster.info.as_ref();
+        let cache = info.and_then(|i| i.cache_info.as_ref());
+
+        let mut targeting: Vec<String> = applications
+            .iter()
+            .filter_map(|app| {
+                let destination = app.spec.as_ref()?.destination.as_ref()?;
+                if !cluster.is_destination_of(destination) {
+                    return None;
+                }
+                let name = app.metadata.as_ref().map_or("", |m| m.name.as_str());
+                Some(match &destination.namespace {
+                    Some(ns) if !ns.is_empty() => format!("{} -> {}", name, ns),
+                    _ => name.to_string(),
+                })
+            })
+            .collect();
+        targeting.sort();
+
+        ClusterSummary {
+            name: cluster.name.clone(),
+            server: cluster.server.clone(),
+            connection_status: connection.and_then(|c| c.status.clone()),
+            connection_message: connection
+                .and_then(|c| c.message.clone())
+                .filter(|m| !m.is_empty()),
+            kubernetes_version: info
+                .and_then(|i| i.server_version.clone())
+                .or(cluster.server_version.clone())
+                .filter(|v| !v.is_empty()),
+            resources_count: cache.and_then(|c| c.resources_count),
+            apis_count: cache.and_then(|c| c.apis_count),
+            last_cache_sync_time: cache.and_then(|c| c.last_cache_sync_time.clone()),
+            namespaces: cluster.namespaces.clone().unwrap_or_default(),
+            project: cluster.project.clone().filter(|p| !p.is_empty()),
+            applications: targeting,
+        }
+    }
+}
+
+/// Detailed cluster view for get_cluster
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterDetail {
+    #[serde(flatten)]
+    pub summary: ClusterSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_attempted_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_resources: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shard: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_requested_at: Option<String>,
+    pub api_versions_count: usize,
+}
+
+impl ClusterDetail {
+    pub fn new(cluster: &Cluster, applications: &[Application]) -> Self {
+        ClusterDetail {
+            summary: ClusterSummary::new(cluster, applications),
+            connection_attempted_at: cluster
+                .connection_state()
+                .and_then(|c| c.attempted_at.clone()),
+            cluster_resources: cluster.cluster_resources,
+            shard: cluster.shard,
+            labels: cluster.labels.clone().filter(|l| !l.is_empty()),
+            refresh_requested_at: cluster.refresh_requested_at.clone(),
+            api_versions_count: cluster
+                .info
+                .as_ref()
+                .and_then(|i| i.api_versions.as_ref())
+                .map_or(0, |v| v.len()),
+        }
+    }
+}
+
+/// Result of invalidate_cluster_cache and rotate_cluster_auth
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterActionSummary {
+    /// Cluster as given (server URL or name)
+    pub cluster: String,
+    /// "cache invalidated" or "auth rotated"
+    pub action: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_requested_at: Option<String>,
+}
+
+// ============================================================================
+// Repository Models
+// ============================================================================
+
+/// Repository registered in ArgoCD. Secret fields (password, SSH and TLS
+/// keys, GitHub App and GCP keys) are not modelled; the fields below that
+/// only tell which kind of credentials are configured are never serialized
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Repository {
+    /// Repository URL
+    pub repo: String,
+    /// "git" or "helm"
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Project the repository is scoped to, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_lfs: Option<bool>,
+    #[serde(rename = "enableOCI", skip_serializing_if = "Option::is_none")]
+    pub enable_oci: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_state: Option<ConnectionState>,
+    /// Credentials come from a matching credential template
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited_creds: Option<bool>,
+    #[serde(default, skip_serializing)]
+    pub username: Option<String>,
+    #[serde(default, rename = "githubAppID", skip_serializing)]
+    pub github_app_id: Option<serde_json::Value>,
+    #[serde(default, skip_serializing)]
+    pub tls_client_cert_data: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub gcp_service_account_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMetadata>,
+    #[serde(default)]
+    pub items: Vec<Repository>,
+}
+
+/// Credential template applied to repositories whose URL starts with `url`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoCreds {
+    pub url: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub username: Option<String>,
+    #[serde(default, rename = "githubAppID", skip_serializing)]
+    pub github_app_id: Option<serde_json::Value>,
+    #[serde(default, skip_serializing)]
+    pub tls_client_cert_data: Option<String>,
+    #[serde(default, skip_serializing)]
+    pub gcp_service_account_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoCredsList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMetadata>,
+    #[serde(default)]
+    pub items: Vec<RepoCreds>,
+}
+
+/// Kind of credentials configured, without revealing them
+fn auth_method(
+    url: &str,
+    username: &Option<String>,
+    github_app_id: &Option<serde_json::Value>,
+    tls_client_cert_data: &Option<String>,
+    gcp_service_account_key: &Option<String>,
+) -> &'static str {
+    let set = |value: &Option<String>| value.as_deref().is_some_and(|v| !v.is_empty());
+    let app_id_set = match github_app_id {
+        Some(serde_json::Value::Number(id)) => id.as_i64() != Some(0),
+        Some(serde_json::Value::String(id)) => !id.is_empty(),
+        _ => false,
+    };
+    if app_id_set {
+        "github-app"
+    } else if set(gcp_service_account_key) {
+        "google-cloud"
+    } else if set(tls_client_cert_data) {
+        "tls-client-cert"
+    } else if set(username) {
+        "https"
+    } else if url.starts_with("git@") || url.starts_with("ssh://") {
+        "ssh"
+    } else {
+        "none"
+    }
+}
+
+/// Debug value for an optional secret: shows whether it is set, never its content
+pub fn redact_secret(secret: &Option<String>) -> Option<&'static str> {
+    secret.as_ref().map(|_| "<redacted>")
+}
+
+/// Remove `user:password@` from URLs inside a message, e.g. a git error
+pub fn redact_url_credentials(text: &str) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(scheme_end) = rest.find("://") {
+        let authority_start = scheme_end + 3;
+        redacted.push_str(&rest[..authority_start]);
+        rest = &rest[authority_start..];
+        let authority_end = rest
+            .find(|c: char| c == '/' || c.is_whitespace() || c == '"' || c == '\'')
+            .unwrap_or(rest.len());
+        match rest[..authority_end].rfind('@') {
+            Some(at) => {
+                redacted.push_str("***");
+                rest = &rest[at..];
+            }
+            None => {
+                redacted.push_str(&rest[..authority_end]);
+                rest = &rest[authority_end..];
+            }
+        }
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+/// Repository details with credentials redacted, for list_repositories and get_repository
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositorySummary {
+    pub repo: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_attempted_at: Option<String>,
+    /// "none", "https", "ssh", "tls-client-cert", "github-app" or "google-cloud"
+    pub auth: String,
+    pub inherited_creds: bool,
+    pub insecure: bool,
+    pub enable_oci: bool,
+    pub enable_lfs: bool,
+}
+
+impl From<&Repository> for RepositorySummary {
+    fn from(repository: &Repository) -> Self {
+        let connection = repository.connection_state.as_ref();
+        RepositorySummary {
+            repo: redact_url_credentials(&repository.repo),
+            repo_type: repository.repo_type.clone(),
+            name: repository.name.clone().filter(|n| !n.is_empty()),
+            project: repository.project.clone().filter(|p| !p.is_empty()),
+            connection_status: connection.and_then(|c| c.status.clone()),
+            connection_message: connection
+                .and_then(|c| c.message.as_deref())
+                .filter(|m| !m.is_empty())
+                .map(redact_url_credentials),
+            connection_attempted_at: connection.and_then(|c| c.attempted_at.clone()),
+            auth: auth_method(
+                &repository.repo,
+                &repository.username,
+                &repository.github_app_id,
+                &repository.tls_client_cert_data,
+                &repository.gcp_service_account_key,
+            )
+            .to_string(),
+            inherited_creds: repository.inherited_creds.unwrap_or(false),
+            insecure: repository.insecure.unwrap_or(false),
+            enable_oci: repository.enable_oci.unwrap_or(false),
+            enable_lfs: repository.enable_lfs.unwrap_or(false),
+        }
+    }
+}
+
+/// Credential template with its secrets redacted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoCredsSummary {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    pub auth: String,
+}
+
+impl From<&RepoCreds> for RepoCredsSummary {
+    fn from(creds: &RepoCreds) -> Self {
+        RepoCredsSummary {
+            url: redact_url_credentials(&creds.url),
+            repo_type: creds.repo_type.clone(),
+            auth: auth_method(
+                &creds.url,
+                &creds.username,
+                &creds.github_app_id,
+                &creds.tls_client_cert_data,
+                &creds.gcp_service_account_key,
+            )
+            .to_string(),
+        }
+    }
+}
+
+/// Result of list_repositories
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryListSummary {
+    pub repositories: Vec<RepositorySummary>,
+    pub credential_templates: Vec<RepoCredsSummary>,
+}
+
+/// Result of validate_repository_access
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepositoryValidationSummary {
+    pub repo: String,
+    pub valid: bool,
+    /// Why access failed, with credentials redacted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Branches and tags of a Git repository
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoRefs {
+    #[serde(default)]
+    pub branches: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Application found in a repository by list_repo_apps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoAppInfo {
+    /// Directory of the application within the repository
+    pub path: String,
+    /// Detected tool: "Helm", "Kustomize", "Directory" or "Plugin"
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub app_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoAppsResponse {
+    #[serde(default)]
+    pub items: Vec<RepoAppInfo>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmChart {
+    pub name: String,
+    #[serde(default)]
+    pub versions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HelmChartsResponse {
+    #[serde(default)]
+    pub items: Vec<HelmChart>,
+}
+
+/// Credentials to test with validate_repository_access; sent to ArgoCD only
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepoAccessQuery {
+    pub repo: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssh_private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_oci: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+}
+
+impl std::fmt::Debug for RepoAccessQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RepoAccessQuery")
+            .field("repo", &redact_url_credentials(&self.repo))
+            .field("repo_type", &self.repo_type)
+            .field("username", &self.username)
+            .field("password", &redact_secret(&self.password))
+            .field("ssh_private_key", &redact_secret(&self.ssh_private_key))
+            .field("insecure", &self.insecure)
+            .field("enable_oci", &self.enable_oci)
+            .field("project", &self.project)
+            .finish()
+    }
+}
+
+/// Branches and tags of a repository, truncated for the context window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoRefsSummary {
+    pub repo: String,
+    pub total_branches: usize,
+    pub total_tags: usize,
+    pub branches: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+impl RepoRefsSummary {
+    pub fn new(repo: &str, refs: RepoRefs, limit: usize) -> Self {
+        RepoRefsSummary {
+            repo: redact_url_credentials(repo),
+            total_branches: refs.branches.len(),
+            total_tags: refs.tags.len(),
+            branches: refs.branches.into_iter().take(limit).collect(),
+            tags: refs.tags.into_iter().take(limit).collect(),
+        }
+    }
+}
+
+/// Helm chart with its most recent versions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HelmChartSummary {
+    pub name: String,
+    pub versions_count: usize,
+    /// Up to 10 versions, in repository index order (newest first)
+    pub versions: Vec<String>,
+}
+
+impl From<&HelmChart> for HelmChartSummary {
+    fn from(chart: &HelmChart) -> Self {
+        HelmChartSummary {
+            name: chart.name.clone(),
+            versions_count: chart.versions.len(),
+            versions: chart.versions.iter().take(10).cloned().collect(),
+        }
+    }
+}
+
+// ============================================================================
+// ApplicationSet Models
+// ============================================================================
+
+/// ApplicationSet: generators produce parameter sets that render `template`
+/// into Applications
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSet {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ObjectMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<ApplicationSetSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<ApplicationSetStatus>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetSpec {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub go_template: Option<bool>,
+    /// Generators are kept as JSON: there are many kinds and they nest
+    #[serde(default)]
+    pub generators: Vec<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<ApplicationSetTemplate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_policy: Option<ApplicationSetSyncPolicy>,
+    /// Progressive sync strategy, e.g. RollingSync
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetTemplate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ApplicationSetTemplateMeta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<ApplicationSpec>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetTemplateMeta {
+    /// Name pattern, e.g. "{{cluster}}-guestbook"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetSyncPolicy {
+    /// Keep the applications' resources when the ApplicationSet is deleted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preserve_resources_on_deletion: Option<bool>,
+    /// "create-only", "create-update", "create-delete" or "sync"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications_sync: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<Vec<ApplicationSetCondition>>,
+    /// Progressive sync status per application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_status: Option<Vec<ApplicationSetApplicationStatus>>,
+    /// Generated applications with their sync and health status
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resources: Option<Vec<ResourceStatus>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetCondition {
+    /// e.g. "ErrorOccurred", "ParametersGenerated", "ResourcesUpToDate", "RolloutProgressing"
+    #[serde(rename = "type")]
+    pub condition_type: String,
+    /// "True", "False" or "Unknown"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_transition_time: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicationSetApplicationStatus {
+    pub application: String,
+    /// "Waiting", "Pending", "Progressing" or "Healthy"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step: Option<String>,
+}
+
+/// Resource status as reported in ApplicationSet (and Application) status
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Sync status: "Synced", "OutOfSync" or "Unknown"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<HealthStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requires_pruning: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSetList {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ListMetadata>,
+    #[serde(default)]
+    pub items: Vec<ApplicationSet>,
+}
+
+/// Response of the ApplicationSet generate endpoint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApplicationSetGenerateResponse {
+    #[serde(default)]
+    pub applications: Vec<Application>,
+}
+
+/// One-line description of a generator, e.g. "git https://... directories: apps/*"
+pub fn describe_generator(generator: &serde_json::Value) -> String {
+    let Some((kind, config)) = generator.as_object().and_then(|o| o.iter().next()) else {
+        return "unknown".to_string();
+    };
+    let text = |key: &str| config.get(key).and_then(|v| v.as_str());
+    let paths = |key: &str| -> Vec<String> {
+        config
+            .get(key)
+            .and_then(|v| v.as_array())
+            .map(|items| {
+                items
+                    .iter()
+                    .filter_map(|i| i.get("path").and_then(|p| p.as_str()))
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    match kind.as_str() {
+        "list" => format!(
+            "list ({} elements)",
+            config
+                .get("elements")
+                .and_then(|e| e.as_array())
+                .map_or(0, |e| e.len())
+        ),
+        "clusters" => match config.pointer("/selector/matchLabels") {
+            Some(labels) => format!("clusters matching {}", labels),
+            None => "clusters (all)".to_string(),
+        },
+        "git" => {
+            let mut description = format!("git {}", text("repoURL").unwrap_or("?"));
+            if let Some(revision) = text("revision") {
+                description.push_str(&format!("@{}", revision));
+            }
+            for (key, label) in [("directories", "directories"), ("files", "files")] {
+                let found = paths(key);
+                if !found.is_empty() {
+                    description.push_str(&format!(" {}: {}", label, found.join(", ")));
+                }
+            }
+            description
+        }
+        "matrix" | "merge" => {
+            let nested: Vec<String> = config
+                .get("generators")
+                .and_then(|g| g.as_array())
+                .map(|g| g.iter().map(describe_generator).collect())
+                .unwrap_or_default();
+            format!("{} [{}]", kind, nested.join("; "))
+        }
+        "scmProvider" | "pullRequest" => {
+            // The provider is the key that is not a common option
+            let provider = config
+                .as_object()
+                .and_then(|o| {
+                    o.keys().find(|k| {
+                        !matches!(
+                            k.as_str(),
+                            "filters"
+                                | "requeueAfterSeconds"
+                                | "template"
+                                | "cloneProtocol"
+                                | "values"
+                        )
+                    })
+                })
+                .map_or("?", |k| k.as_str());
+            format!("{} ({})", kind, provider)
+        }
+        "plugin" => format!(
+            "plugin {}",
+            config
+                .pointer("/configMapRef/name")
+                .and_then(|n| n.as_str())
+                .unwrap_or("?")
+        ),
+        other => other.to_string(),
+    }
+}
+
+impl ApplicationSet {
+    pub fn name(&self) -> String {
+        self.metadata
+            .as_ref()
+            .map(|m| m.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Message of an ErrorOccurred condition that is currently true
+    fn error(&self) -> Option<String> {
+        self.status
+            .as_ref()?
+            .conditions
+            .as_ref()?
+            .iter()
+            .find(|c| c.condition_type == "ErrorOccurred" && c.status.as_deref() == Some("True"))
+            .map(|c| c.message.clone().unwrap_or_default())
+    }
+}
+
+/// Optimized summary for list_applicationsets (context-efficient)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSetSummary {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Generator kinds, e.g. ["git", "matrix"]
+    pub generators: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    pub applications_count: usize,
+    /// Message of the ErrorOccurred condition, when set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<&ApplicationSet> for ApplicationSetSummary {
+    fn from(appset: &ApplicationSet) -> Self {
+        let spec = appset.spec.as_ref();
+        ApplicationSetSummary {
+            name: appset.name(),
+            namespace: appset.metadata.as_ref().and_then(|m| m.namespace.clone()),
+            generators: spec
+                .map(|s| {
+                    s.generators
+                        .iter()
+                        .filter_map(|g| g.as_object()?.keys().next().cloned())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            project: spec
+                .and_then(|s| s.template.as_ref())
+                .and_then(|t| t.spec.as_ref())
+                .and_then(|s| s.project.clone()),
+            applications_count: appset
+                .status
+                .as_ref()
+                .and_then(|s| s.resources.as_ref())
+                .map_or(0, |r| r.len()),
+            error: appset.error(),
+        }
+    }
+}
+
+/// Status of one generated application
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedApplicationStatus {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health_message: Option<String>,
+    /// Progressive sync status and step, when a strategy is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollout_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollout_step: Option<String>,
+}
+
+/// Detailed ApplicationSet view for get_applicationset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSetDetail {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub go_template: bool,
+    /// One line per generator
+    pub generators: Vec<String>,
+    /// Name pattern of generated applications
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<ApplicationSpecSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub applications_sync: Option<String>,
+    pub preserve_resources_on_deletion: bool,
+    pub progressive_sync: bool,
+    pub conditions: Vec<ApplicationSetCondition>,
+    pub applications: Vec<GeneratedApplicationStatus>,
+}
+
+impl ApplicationSetDetail {
+    pub fn from_applicationset(appset: &ApplicationSet) -> Self {
+        let spec = appset.spec.clone().unwrap_or_default();
+        let status = appset.status.clone().unwrap_or_default();
+        let template = spec.template.unwrap_or_default();
+        let rollouts = status.application_status.unwrap_or_default();
+        let sync_policy = spec.sync_policy.unwrap_or_default();
+
+        ApplicationSetDetail {
+            name: appset.name(),
+            namespace: appset.metadata.as_ref().and_then(|m| m.namespace.clone()),
+            go_template: spec.go_template.unwrap_or(false),
+            generators: spec.generators.iter().map(describe_generator).collect(),
+            template_name: template.metadata.and_then(|m| m.name),
+            template: template.spec.as_ref().map(ApplicationSpecSummary::from_spec),
+            applications_sync: sync_policy.applications_sync,
+            preserve_resources_on_deletion: sync_policy
+                .preserve_resources_on_deletion
+                .unwrap_or(false),
+            progressive_sync: spec.strategy.is_some(),
+            conditions: status.conditions.unwrap_or_default(),
+            applications: status
+                .resources
+                .unwrap_or_default()
+                .into_iter()
+                .map(|resource| {
+                    let rollout = rollouts.iter().find(|r| r.application == resource.name);
+                    GeneratedApplicationStatus {
+                        sync_status: resource.status,
+                        health_status: resource.health.as_ref().map(|h| h.status.clone()),
+                        health_message: resource
+                            .health
+                            .and_then(|h| h.message)
+                            .filter(|m| !m.is_empty()),
+                        rollout_status: rollout.and_then(|r| r.status.clone()),
+                        rollout_step: rollout.and_then(|r| r.step.clone()),
+                        name: resource.name,
+                        namespace: resource.namespace,
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Application an ApplicationSet would generate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeneratedApplicationSummary {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<ApplicationSpecSummary>,
+}
+
+/// Result of generate_applicationset_preview
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSetPreviewSummary {
+    pub name: String,
+    pub applications: Vec<GeneratedApplicationSummary>,
+}
+
+impl ApplicationSetPreviewSummary {
+    pub fn new(name: String, response: &ApplicationSetGenerateResponse) -> Self {
+        ApplicationSetPreviewSummary {
+            name,
+            applications: response
+                .applications
+                .iter()
+                .map(|app| GeneratedApplicationSummary {
+                    name: app.metadata.as_ref().map(|m| m.name.clone()).unwrap_or_default(),
+                    spec: app.spec.as_ref().map(ApplicationSpecSummary::from_spec),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Result of delete_applicationset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplicationSetDeleteSummary {
+    pub name: String,
+    pub dry_run: bool,
+    /// Generated applications deleted along with the ApplicationSet
+    pub applications: Vec<String>,
+    /// Whether the applications' Kubernetes resources are left running
+    pub preserve_resources_on_deletion: bool,
+}
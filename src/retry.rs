@@ -0,0 +1,264 @@
+use reqwest::StatusCode;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// Default number of retries after the first attempt
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+/// Default delay before the first retry
+pub const DEFAULT_INITIAL_BACKOFF_MS: u64 = 200;
+/// Default upper bound for the exponential backoff
+pub const DEFAULT_MAX_BACKOFF_MS: u64 = 5_000;
+/// Longest `Retry-After` the client is willing to wait
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(30);
+
+/// When a failed request may be sent again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetrySafety {
+    /// Reads: any transient failure is retried
+    Idempotent,
+    /// Mutations that may be retried when `RetryPolicy::retry_mutations` is
+    /// set, and only if the server cannot have acted on the first attempt
+    OptIn,
+    /// Never retried
+    Never,
+}
+
+/// How an attempt failed, as far as retrying is concerned
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptFailure {
+    /// The connection could not be established, so nothing reached the server
+    NotSent,
+    /// The request timed out; the server may or may not have processed it
+    Timeout,
+    /// The connection broke after the request was sent
+    Interrupted,
+    /// The server answered with a non-success status
+    Status(StatusCode),
+}
+
+/// Retry settings for an ArgoCD connection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt (0 disables retries)
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each following retry
+    pub initial_backoff: Duration,
+    /// Upper bound for the backoff delay
+    pub max_backoff: Duration,
+    /// Allow retrying sync and rollback requests
+    pub retry_mutations: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: Duration::from_millis(DEFAULT_INITIAL_BACKOFF_MS),
+            max_backoff: Duration::from_millis(DEFAULT_MAX_BACKOFF_MS),
+            retry_mutations: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    #[allow(dead_code)]
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Delay before the next attempt, or `None` if the failure must be reported
+    ///
+    /// `retries` is the number of retries already made.
+    pub fn next_delay(
+        &self,
+        safety: RetrySafety,
+        failure: AttemptFailure,
+        retries: u32,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if retries >= self.max_retries || !self.is_retryable(safety, failure) {
+            return None;
+        }
+        Some(match retry_after {
+            Some(delay) => delay.min(MAX_RETRY_AFTER),
+            None => self.backoff(retries),
+        })
+    }
+
+    fn is_retryable(&self, safety: RetrySafety, failure: AttemptFailure) -> bool {
+        match safety {
+            RetrySafety::Idempotent => match failure {
+                AttemptFailure::NotSent | AttemptFailure::Timeout | AttemptFailure::Interrupted => {
+                    true
+                }
+                AttemptFailure::Status(status) => matches!(
+                    status,
+                    StatusCode::TOO_MANY_REQUESTS
+                        | StatusCode::BAD_GATEWAY
+                        | StatusCode::SERVICE_UNAVAILABLE
+                        | StatusCode::GATEWAY_TIMEOUT
+                ),
+            },
+            // Only failures where the request cannot have been acted on: a
+            // timeout or 502/504 could hide a sync that already started
+            RetrySafety::OptIn => {
+                self.retry_mutations
+                    && matches!(
+                        failure,
+                        AttemptFailure::NotSent
+                            | AttemptFailure::Status(StatusCode::TOO_MANY_REQUESTS)
+                            | AttemptFailure::Status(StatusCode::SERVICE_UNAVAILABLE)
+                    )
+            }
+            RetrySafety::Never => false,
+        }
+    }
+
+    /// Exponential backoff with jitter: a random delay between half and all
+    /// of `initial_backoff * 2^retries`, capped at `max_backoff`
+    pub fn backoff(&self, retries: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retries.min(16));
+        let ceiling = self
+            .initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff);
+        let half = ceiling / 2;
+        let jitter_range = (ceiling - half).as_millis() as u64;
+        if jitter_range == 0 {
+            return ceiling;
+        }
+        half + Duration::from_millis(random_u64() % (jitter_range + 1))
+    }
+}
+
+fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|d| d.as_nanos())
+            .unwrap_or_default(),
+    );
+    hasher.finish()
+}
+
+/// Parse a `Retry-After` header (delay in seconds or an HTTP date)
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = httpdate::parse_http_date(value).ok()?;
+    Some(
+        date.duration_since(SystemTime::now())
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// A retry made while serving a tool call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryRecord {
+    pub operation: &'static str,
+    /// Retry number for this request (1 for the first retry)
+    pub attempt: u32,
+    pub reason: String,
+    pub delay: Duration,
+}
+
+tokio::task_local! {
+    static RETRY_LOG: Arc<Mutex<Vec<RetryRecord>>>;
+}
+
+/// Run `future` and collect every retry the ArgoCD client makes while it runs
+pub async fn track_retries<F: Future>(future: F) -> (F::Output, Vec<RetryRecord>) {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let output = RETRY_LOG.scope(log.clone(), future).await;
+    let records = std::mem::take(&mut *log.lock().unwrap_or_else(|e| e.into_inner()));
+    (output, records)
+}
+
+/// Record a retry for the surrounding `track_retries` scope, if any
+pub(crate) fn record_retry(record: RetryRecord) {
+    let _ = RETRY_LOG.try_with(|log| {
+        log.lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(record);
+    });
+}
+
+/// One-line summary of the retries made for a tool call
+pub fn summarize_retries(records: &[RetryRecord]) -> Option<String> {
+    if records.is_empty() {
+        return None;
+    }
+    let reasons: Vec<String> = records
+        .iter()
+        .map(|r| format!("{} #{} after {}", r.operation, r.attempt, r.reason))
+        .collect();
+    Some(format!(
+        "🔁 ArgoCD API retries: {} ({})",
+        records.len(),
+        reasons.join("; ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(1_000),
+            ..RetryPolicy::default()
+        };
+        for _ in 0..20 {
+            let first = policy.backoff(0);
+            assert!(first >= Duration::from_millis(50) && first <= Duration::from_millis(100));
+            let capped = policy.backoff(10);
+            assert!(capped >= Duration::from_millis(500) && capped <= Duration::from_millis(1_000));
+        }
+    }
+
+    #[test]
+    fn test_mutations_only_retried_when_not_processed() {
+        let mut policy = RetryPolicy::default();
+        let unavailable = AttemptFailure::Status(StatusCode::SERVICE_UNAVAILABLE);
+
+        assert!(policy
+            .next_delay(RetrySafety::OptIn, AttemptFailure::NotSent, 0, None)
+            .is_none());
+
+        policy.retry_mutations = true;
+        assert!(policy
+            .next_delay(RetrySafety::OptIn, unavailable, 0, None)
+            .is_some());
+        assert!(policy
+            .next_delay(RetrySafety::OptIn, AttemptFailure::Timeout, 0, None)
+            .is_none());
+        assert!(policy
+            .next_delay(RetrySafety::Never, AttemptFailure::NotSent, 0, None)
+            .is_none());
+        assert!(policy
+            .next_delay(RetrySafety::Idempotent, unavailable, 3, None)
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("7"), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Thu, 01 Jan 1970 00:00:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
+    }
+}
@@ -1,12 +1,30 @@
 use rmcp::{
-    handler::server::{tool::ToolRouter, wrapper::Parameters},
+    handler::server::{
+        tool::{ToolCallContext, ToolRouter},
+        wrapper::Parameters,
+    },
     model::*,
-    schemars, tool, tool_handler, tool_router, ErrorData as McpError, ServerHandler,
+    schemars, tool, tool_router, ErrorData as McpError, Peer, RoleServer, ServerHandler,
 };
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::argocd_client::ArgocdClient;
+use crate::config::{Config, OutputLimits};
+use crate::context::{ContextLookup, ContextRegistry, ContextsConfig, DEFAULT_CONTEXT_NAME};
+use crate::error::{suggest_names, ArgocdError};
+use crate::models::{
+    redact_secret, redact_url_credentials, AnalyzedLogEntry, AppProjectSpec, Application,
+    ApplicationSetDetail, ApplicationSpec, ApplicationSpecSummary, ApplicationWaitSummary,
+    ClusterSummary, ContextSummary, EventListSummary, LogClusterSummary, LogFollowOptions,
+    LogFollowStop, LogFollowSummary, LogLevel, ObjectMeta, OperationSummary, PodLogsQuery,
+    PodLogsSummary, ProjectDetail, ProjectRole, ProjectRoleSummary, ReplicaLogState,
+    RepoAccessQuery, RepositorySummary, ResourceStatus, SourceSummary, WaitConditions, WaitOutcome,
+    WorkloadLogsSummary,
+};
+use crate::retry::{summarize_retries, track_retries};
+use crate::watch::{next_subscriber_id, WatchHub, WatchKey, WatchTarget};
 
 /// Arguments for listing ArgoCD applications
 #[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
@@ -26,6 +44,9 @@ pub struct ListApplicationsArgs {
     /// Filter by application namespace
     #[serde(skip_serializing_if = "Option::is_none")]
     pub app_namespace: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for listing only application names
@@ -43,6 +64,9 @@ pub struct ListApplicationNamesArgs {
     /// Filter by application namespace
     #[serde(skip_serializing_if = "Option::is_none")]
     pub app_namespace: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for server-side diff calculation
@@ -59,6 +83,9 @@ pub struct ServerSideDiffArgs {
     /// Target manifests for comparison (array of YAML/JSON strings)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_manifests: Option<Vec<String>>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for resource tree query
@@ -87,6 +114,9 @@ pub struct ResourceTreeArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for get application
@@ -106,6 +136,9 @@ pub struct GetApplicationArgs {
     /// Resource version for optimistic concurrency
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_version: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for listing resource events
@@ -128,6 +161,9 @@ pub struct ListResourceEventsArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for getting pod logs
@@ -174,6 +210,74 @@ pub struct PodLogsArgs {
     /// Filter to show only errors and potential issues (client-side filtering, recommended for LLM context)
     #[serde(default)]
     pub errors_only: bool,
+    /// Stream new lines as log notifications until follow_seconds, follow_lines or until_pattern stops the stream, then analyze them as usual
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow: Option<bool>,
+    /// With follow: seconds to follow the stream (default: 30, max: 600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow_seconds: Option<u64>,
+    /// With follow: stop after this many lines (default and max: the configured tail_lines limit)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow_lines: Option<usize>,
+    /// With follow: stop at the first line containing this text, e.g. "Started server"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until_pattern: Option<String>,
+    /// Collapse repeated lines into patterns with counts, first/last seen times and one example (numbers, UUIDs, IPs and timestamps are masked)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<bool>,
+    /// Return only the N most frequent patterns (implies cluster)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_clusters: Option<usize>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for getting the logs of every pod of a workload
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct WorkloadLogsArgs {
+    /// Application name (required)
+    pub application_name: String,
+    /// Workload kind, e.g. "Deployment", "StatefulSet", "DaemonSet" or "Rollout" (required)
+    pub kind: String,
+    /// Workload name (required)
+    pub resource_name: String,
+    /// Workload namespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    /// Container name (defaults to each pod's first container)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub container: Option<String>,
+    /// Show logs since N seconds ago
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since_seconds: Option<i64>,
+    /// Number of lines from the end of each pod's logs (default: 100 for context efficiency)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tail_lines: Option<i64>,
+    /// Show previous container logs (if containers restarted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous: Option<bool>,
+    /// Filter logs by text (server-side filtering)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+    /// Application namespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_namespace: Option<String>,
+    /// Project identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// Filter the merged lines to errors and potential issues; per-pod counts still cover every line
+    #[serde(default)]
+    pub errors_only: bool,
+    /// Collapse repeated lines into patterns with counts, first/last seen times and one example (numbers, UUIDs, IPs and timestamps are masked)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster: Option<bool>,
+    /// Return only the N most frequent patterns (implies cluster)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_clusters: Option<usize>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for getting application manifests
@@ -190,12 +294,15 @@ pub struct GetManifestsArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
-    /// Source positions (for multi-source applications)
+    /// 1-based source positions to render (for multi-source applications), paired with `revisions`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_positions: Option<Vec<i64>>,
-    /// Revisions for multi-source applications
+    /// Revisions for multi-source applications, one per entry in `source_positions`
     #[serde(skip_serializing_if = "Option::is_none")]
     pub revisions: Option<Vec<String>>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for getting revision metadata
@@ -211,12 +318,15 @@ pub struct RevisionMetadataArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
-    /// Source index (for multi-source applications)
+    /// 0-based index into `spec.sources` the revision belongs to (for multi-source applications)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_index: Option<i32>,
     /// Version ID from historical data (for multi-source applications)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub version_id: Option<i32>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for getting application sync windows
@@ -230,6 +340,9 @@ pub struct GetApplicationSyncWindowsArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for rolling back an application
@@ -238,6 +351,7 @@ pub struct RollbackApplicationArgs {
     /// Application name (required)
     pub application_name: String,
     /// History ID to rollback to (required). If not specified or set to 0, will rollback to the previous version.
+    /// Multi-source entries pin every source, so they all roll back together.
     pub id: i64,
     /// Dry run mode - if true, will preview the rollback without actually performing it
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -251,6 +365,15 @@ pub struct RollbackApplicationArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// Wait until the application is synced, healthy and the operation has finished
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait: Option<bool>,
+    /// Seconds to wait when `wait` is true (default 300, max 3600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timeout_seconds: Option<u64>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Sync resource specification for partial sync
@@ -308,6 +431,40 @@ pub struct GetResourceArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for running a resource action
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct RunResourceActionArgs {
+    /// Resource to run the action on (same fields as get_resource)
+    #[serde(flatten)]
+    pub resource: GetResourceArgs,
+    /// Action name as listed by list_resource_actions (e.g. "restart", "resume", "promote-full")
+    pub action: String,
+    /// Parameters for parameterized actions, e.g. {"replicas": "3"} (optional, ArgoCD v2.14+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_parameters: Option<HashMap<String, String>>,
+}
+
+/// Arguments for deleting a resource
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct DeleteResourceArgs {
+    /// Resource to delete (same fields as get_resource)
+    #[serde(flatten)]
+    pub resource: GetResourceArgs,
+    /// Force deletion, skipping graceful termination (optional, defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+    /// Leave the resource's children (e.g. Pods of a Job) running (optional, defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orphan: Option<bool>,
+    /// Dry run mode, on by default: only preview the resource and its children.
+    /// Set to false, after reviewing the preview, to delete
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
 }
 
 /// Arguments for patching a resource
@@ -338,6 +495,9 @@ pub struct PatchResourceArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for getting application deployment history
@@ -351,6 +511,9 @@ pub struct GetApplicationHistoryArgs {
     /// Project identifier (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for refreshing an application
@@ -369,6 +532,15 @@ pub struct RefreshApplicationArgs {
     /// Project identifier (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// Wait until the application is synced, healthy and the operation has finished
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait: Option<bool>,
+    /// Seconds to wait when `wait` is true (default 300, max 3600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timeout_seconds: Option<u64>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
 /// Arguments for syncing an application
@@ -403,394 +575,3956 @@ pub struct SyncApplicationArgs {
     /// Project identifier
     #[serde(skip_serializing_if = "Option::is_none")]
     pub project: Option<String>,
+    /// Wait until the application is synced, healthy and the operation has finished
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait: Option<bool>,
+    /// Seconds to wait when `wait` is true (default 300, max 3600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_timeout_seconds: Option<u64>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
-/// MCP Server handler for ArgoCD operations
-#[derive(Clone)]
-pub struct ArgocdMcpHandler {
-    client: Arc<RwLock<Option<ArgocdClient>>>,
-    tool_router: ToolRouter<Self>,
-    read_only: bool,
+/// Arguments for terminating an application's running operation
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct TerminateOperationArgs {
+    /// Application name (required)
+    pub application_name: String,
+    /// Application namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_namespace: Option<String>,
+    /// Project identifier (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
 }
 
-#[tool_router]
-impl ArgocdMcpHandler {
-    /// Create a new handler with optional read-only mode
-    pub fn new() -> Self {
-        Self::with_read_only(false)
-    }
+/// Arguments for creating an application
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct CreateApplicationArgs {
+    /// Application name (required)
+    pub application_name: String,
+    /// Namespace the Application resource is created in (optional, defaults to ArgoCD's namespace)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_namespace: Option<String>,
+    /// Application spec: source(s), destination, project and sync policy (required)
+    pub spec: ApplicationSpec,
+    /// Labels for the Application resource (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    /// Annotations for the Application resource (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub annotations: Option<HashMap<String, String>>,
+    /// Replace the spec if the application already exists (optional, defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upsert: Option<bool>,
+    /// Have ArgoCD check that the repository and destination are reachable (optional, defaults to true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+    /// Dry run mode - check the spec and whether the application exists without creating it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-    /// Create a new handler with explicit read-only mode
-    pub fn with_read_only(read_only: bool) -> Self {
-        Self {
-            client: Arc::new(RwLock::new(None)),
-            tool_router: Self::tool_router(),
-            read_only,
-        }
-    }
+/// Arguments for replacing an application's spec
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct UpdateApplicationSpecArgs {
+    /// Application name (required)
+    pub application_name: String,
+    /// Complete new spec; it replaces the current one, so include unchanged fields (required)
+    pub spec: ApplicationSpec,
+    /// Have ArgoCD check that the repository and destination are reachable (optional, defaults to true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validate: Option<bool>,
+    /// Dry run mode - show which fields would change without updating
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// Application namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_namespace: Option<String>,
+    /// Project identifier (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-    /// Create a new handler from environment variables
-    /// Reads ARGOCD_READ_ONLY environment variable (true/false, default: false)
-    pub fn from_env() -> Self {
-        let read_only = std::env::var("ARGOCD_READ_ONLY")
-            .ok()
-            .and_then(|v| v.parse::<bool>().ok())
-            .unwrap_or(false);
-        Self::with_read_only(read_only)
-    }
+/// Arguments for deleting an application
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct DeleteApplicationArgs {
+    /// Application name (required)
+    pub application_name: String,
+    /// Delete the application's Kubernetes resources too (optional, defaults to true).
+    /// With false the resources are left running, unmanaged
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cascade: Option<bool>,
+    /// How cascading deletes remove resources: "foreground" or "background" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub propagation_policy: Option<String>,
+    /// Dry run mode - list the resources a cascading delete would remove without deleting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// Application namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_namespace: Option<String>,
+    /// Project identifier (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-    /// Initialize the client with credentials
-    pub async fn initialize(&self, base_url: String, access_token: String) -> anyhow::Result<()> {
-        let client = ArgocdClient::new(base_url, access_token)?;
-        let mut guard = self.client.write().await;
-        *guard = Some(client);
-        Ok(())
-    }
+/// Arguments for listing projects
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListProjectsArgs {
+    /// Only return the project with this name (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-    /// Check if the handler is in read-only mode
-    #[allow(dead_code)]
-    pub fn is_read_only(&self) -> bool {
-        self.read_only
-    }
+/// Arguments for tools that take a single project
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GetProjectArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-    /// List ArgoCD applications with optional filters
-    #[tool(
-        description = "List ArgoCD applications. Returns optimized summaries including name, project, sync status, health status, repository information, and destination. Use filters to narrow down results by name, projects, labels, repository, or namespace."
-    )]
-    async fn list_applications(
-        &self,
-        Parameters(args): Parameters<ListApplicationsArgs>,
-    ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+/// Arguments for creating a project
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct CreateProjectArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// Project spec: source repos, destinations, resource allow/deny lists, roles and sync windows (required)
+    pub spec: AppProjectSpec,
+    /// Replace the project if it already exists (optional, defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upsert: Option<bool>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-        // Call ArgoCD API
-        let summaries = client
-            .list_applications(
-                args.name,
-                args.projects,
-                args.selector,
-                args.repo,
-                args.app_namespace,
-            )
-            .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to list applications: {}", e), None)
-            })?;
+/// Arguments for replacing a project's spec
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct UpdateProjectArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// Complete new spec; it replaces the current one, so include unchanged fields (required)
+    pub spec: AppProjectSpec,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-        if summaries.is_empty() {
-            Ok(CallToolResult::success(vec![Content::text(
-                "No applications found matching the criteria",
-            )]))
-        } else {
-            // Format as readable text
-            let mut output = format!("Found {} application(s):\n\n", summaries.len());
+/// Arguments for creating or updating a project role
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct UpsertProjectRoleArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// Role name (required)
+    pub role_name: String,
+    /// Role description (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Casbin policies, e.g. "p, proj:my-project:ci, applications, sync, my-project/*, allow" (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub policies: Option<Vec<String>>,
+    /// SSO groups bound to the role (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<Vec<String>>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-            for (idx, app) in summaries.iter().enumerate() {
-                output.push_str(&format!("{}. {}\n", idx + 1, app.name));
-                if let Some(project) = &app.project {
-                    output.push_str(&format!("   Project: {}\n", project));
-                }
-                if let Some(repo) = &app.repo_url {
-                    output.push_str(&format!("   Repository: {}\n", repo));
-                }
-                if let Some(revision) = &app.target_revision {
-                    output.push_str(&format!("   Target Revision: {}\n", revision));
-                }
-                if let Some(dest_server) = &app.destination_server {
-                    output.push_str(&format!("   Destination Server: {}\n", dest_server));
-                }
-                if let Some(dest_ns) = &app.destination_namespace {
-                    output.push_str(&format!("   Destination Namespace: {}\n", dest_ns));
-                }
-                if let Some(sync) = &app.sync_status {
-                    output.push_str(&format!("   Sync Status: {}\n", sync));
-                }
-                if let Some(health) = &app.health_status {
-                    output.push_str(&format!("   Health Status: {}\n", health));
-                }
-                if let Some(true) = app.auto_sync {
-                    output.push_str("   Auto Sync: Enabled\n");
-                }
-                output.push('\n');
-            }
+/// Arguments for deleting a project role
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct DeleteProjectRoleArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// Role name (required)
+    pub role_name: String,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-            // Also include JSON for structured consumption
-            let json_data = serde_json::to_string_pretty(&summaries).map_err(|e| {
-                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-            })?;
+/// Arguments for issuing a project role token
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct CreateProjectTokenArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// Role the token is issued for (required)
+    pub role_name: String,
+    /// Token description (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Lifetime in seconds (optional, the token never expires when omitted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_in_seconds: Option<i64>,
+    /// Token ID, used to revoke it later (optional, generated by ArgoCD when omitted)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
 
-            Ok(CallToolResult::success(vec![
-                Content::text(output),
+/// Arguments for revoking a project role token
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct DeleteProjectTokenArgs {
+    /// Project name (required)
+    pub project_name: String,
+    /// Role the token was issued for (required)
+    pub role_name: String,
+    /// Token issued-at time in Unix seconds, as listed by get_project (required)
+    pub issued_at: i64,
+    /// Token ID, as listed by get_project (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for listing clusters
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListClustersArgs {
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for tools that take a single cluster
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GetClusterArgs {
+    /// Cluster API server URL (e.g. "https://kubernetes.default.svc") or cluster name (e.g. "in-cluster") (required)
+    pub cluster: String,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for listing repositories
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListRepositoriesArgs {
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for getting a repository
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GetRepositoryArgs {
+    /// Repository URL as registered in ArgoCD (required)
+    pub repo: String,
+    /// Test the connection again instead of returning the cached state (optional, defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh: Option<bool>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for validating access to a repository
+#[derive(serde::Deserialize, schemars::JsonSchema)]
+pub struct ValidateRepositoryAccessArgs {
+    /// Repository URL (required)
+    pub repo: String,
+    /// Repository type: "git" or "helm" (optional, defaults to git)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo_type: Option<String>,
+    /// Username for HTTPS access (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// Password or access token for HTTPS access (optional, never echoed back)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// PEM private key for SSH access (optional, never echoed back)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssh_private_key: Option<String>,
+    /// Skip TLS and SSH host key verification (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure: Option<bool>,
+    /// Treat a Helm repository as an OCI registry (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_oci: Option<bool>,
+    /// Project whose scoped credentials may be used (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+impl std::fmt::Debug for ValidateRepositoryAccessArgs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ValidateRepositoryAccessArgs")
+            .field("repo", &redact_url_credentials(&self.repo))
+            .field("repo_type", &self.repo_type)
+            .field("username", &self.username)
+            .field("password", &redact_secret(&self.password))
+            .field("ssh_private_key", &redact_secret(&self.ssh_private_key))
+            .field("insecure", &self.insecure)
+            .field("enable_oci", &self.enable_oci)
+            .field("project", &self.project)
+            .field("context", &self.context)
+            .finish()
+    }
+}
+
+/// Arguments for listing the branches and tags of a repository
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListRepoRefsArgs {
+    /// Repository URL as registered in ArgoCD (required)
+    pub repo: String,
+    /// Maximum number of branches and of tags to return (optional, defaults to 100)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for finding applications in a repository
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListRepoAppsArgs {
+    /// Git repository URL as registered in ArgoCD (required)
+    pub repo: String,
+    /// Branch, tag or commit to inspect (optional, defaults to HEAD)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// Application whose settings (e.g. plugins) apply (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_name: Option<String>,
+    /// Project whose scoped credentials may be used (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for listing the charts of a Helm repository
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GetHelmChartsArgs {
+    /// Helm repository URL as registered in ArgoCD (required)
+    pub repo: String,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for listing ApplicationSets
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct ListApplicationSetsArgs {
+    /// Filter by project names
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub projects: Option<Vec<String>>,
+    /// Label selector to filter ApplicationSets (e.g., 'team=payments')
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selector: Option<String>,
+    /// Filter by ApplicationSet namespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appset_namespace: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for getting an ApplicationSet
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GetApplicationSetArgs {
+    /// ApplicationSet name (required)
+    pub applicationset_name: String,
+    /// ApplicationSet namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appset_namespace: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for previewing the applications an ApplicationSet generates
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct GenerateApplicationSetPreviewArgs {
+    /// ApplicationSet name (required)
+    pub applicationset_name: String,
+    /// ApplicationSet spec (generators, template, goTemplate, ...) with camelCase fields, as in an ApplicationSet manifest (required)
+    pub spec: serde_json::Value,
+    /// ApplicationSet namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appset_namespace: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for creating an ApplicationSet
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct CreateApplicationSetArgs {
+    /// ApplicationSet name (required)
+    pub applicationset_name: String,
+    /// ApplicationSet spec (generators, template, goTemplate, syncPolicy, strategy) with camelCase fields, as in an ApplicationSet manifest (required)
+    pub spec: serde_json::Value,
+    /// ApplicationSet namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appset_namespace: Option<String>,
+    /// Replace the ApplicationSet if it already exists (optional, defaults to false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upsert: Option<bool>,
+    /// Dry run mode - have ArgoCD validate the spec without saving it (ArgoCD v2.11+)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for deleting an ApplicationSet
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct DeleteApplicationSetArgs {
+    /// ApplicationSet name (required)
+    pub applicationset_name: String,
+    /// ApplicationSet namespace (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub appset_namespace: Option<String>,
+    /// Dry run mode - list the generated applications that would be deleted without deleting
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dry_run: Option<bool>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Arguments for waiting until an application reaches a target state
+#[derive(Debug, serde::Deserialize, schemars::JsonSchema)]
+pub struct WaitForApplicationArgs {
+    /// Application name (required)
+    pub application_name: String,
+    /// Wait until the application is Synced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync: Option<bool>,
+    /// Wait until the application is Healthy
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub health: Option<bool>,
+    /// Wait until the current operation has finished. Stops early when it fails
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<bool>,
+    /// Maximum seconds to wait (default 300, max 3600)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<u64>,
+    /// Seconds between checks (default 5, 1 to 60)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_interval_seconds: Option<u64>,
+    /// Application namespace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_namespace: Option<String>,
+    /// Project identifier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<String>,
+    /// ArgoCD context to run against (see list_contexts). Uses the default context when omitted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+}
+
+/// Converts ArgoCD client failures into MCP errors with typed details
+trait ApiResultExt<T> {
+    /// `action` prefixes the message (e.g. "Failed to get application"). When
+    /// `application` is given and ArgoCD reports NotFound, similarly named
+    /// applications are suggested.
+    async fn or_api_error(
+        self,
+        client: &ArgocdClient,
+        action: &str,
+        application: Option<&str>,
+    ) -> Result<T, McpError>;
+}
+
+impl<T> ApiResultExt<T> for anyhow::Result<T> {
+    async fn or_api_error(
+        self,
+        client: &ArgocdClient,
+        action: &str,
+        application: Option<&str>,
+    ) -> Result<T, McpError> {
+        let error = match self {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        let Some(argocd_error) = ArgocdError::from_anyhow(&error) else {
+            return Err(McpError::internal_error(
+                format!("{}: {}", action, error),
+                None,
+            ));
+        };
+
+        let mut details = argocd_error.details();
+        if let (ArgocdError::NotFound(_), Some(name)) = (argocd_error, application) {
+            // Best effort: a failed lookup just leaves the generic hint in place
+            if let Ok(names) = client.list_application_names(None, None, None, None).await {
+                if names.iter().any(|n| n == name) {
+                    details.hint = Some(format!(
+                        "Application '{}' exists; the requested resource, revision or sub-object was not found",
+                        name
+                    ));
+                } else {
+                    details.suggestions = suggest_names(name, &names, 3);
+                    if !details.suggestions.is_empty() {
+                        let quoted: Vec<String> = details
+                            .suggestions
+                            .iter()
+                            .map(|s| format!("'{}'", s))
+                            .collect();
+                        details.hint = Some(format!(
+                            "Application '{}' was not found. Did you mean {}?",
+                            name,
+                            quoted.join(" or ")
+                        ));
+                    }
+                }
+            }
+        }
+
+        let mut message = format!("{}: {}", action, argocd_error);
+        if let Some(hint) = &details.hint {
+            message.push_str(&format!("\nHint: {}", hint));
+        }
+
+        Err(McpError::new(
+            argocd_error.mcp_code(),
+            message,
+            serde_json::to_value(&details).ok(),
+        ))
+    }
+}
+
+/// Append event counts by type and reason, then up to `event_limit` events
+fn push_events(output: &mut String, summary: &EventListSummary, event_limit: usize) {
+    // Event counts by type
+    if !summary.events_by_type.is_empty() {
+        output.push_str("Events by Type:\n");
+        let mut types: Vec<_> = summary.events_by_type.iter().collect();
+        types.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+        for (event_type, count) in types {
+            output.push_str(&format!("  {}: {}\n", event_type, count));
+        }
+        output.push('\n');
+    }
+
+    // Event counts by reason
+    if !summary.events_by_reason.is_empty() {
+        output.push_str("Events by Reason:\n");
+        let mut reasons: Vec<_> = summary.events_by_reason.iter().collect();
+        reasons.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+        for (reason, count) in reasons.iter().take(10) {
+            output.push_str(&format!("  {}: {}\n", reason, count));
+        }
+        if reasons.len() > 10 {
+            output.push_str(&format!("  ... and {} more\n", reasons.len() - 10));
+        }
+        output.push('\n');
+    }
+
+    // List individual events (limited to prevent context overflow)
+    output.push_str(&format!("Recent Events (showing up to {}):\n", event_limit));
+    for (idx, event) in summary.events.iter().take(event_limit).enumerate() {
+        output.push_str(&format!("\n{}. ", idx + 1));
+
+        if let Some(event_type) = &event.event_type {
+            output.push_str(&format!("[{}] ", event_type));
+        }
+
+        if let Some(reason) = &event.reason {
+            output.push_str(reason);
+        }
+
+        if let Some(involved_kind) = &event.involved_object_kind {
+            output.push_str(&format!(" - {}", involved_kind));
+            if let Some(involved_name) = &event.involved_object_name {
+                output.push_str(&format!("/{}", involved_name));
+            }
+        }
+
+        output.push('\n');
+
+        if let Some(message) = &event.message {
+            output.push_str(&format!("   Message: {}\n", message));
+        }
+
+        if let Some(count) = event.count {
+            if count > 1 {
+                output.push_str(&format!("   Count: {}\n", count));
+            }
+        }
+
+        if let Some(first_ts) = &event.first_timestamp {
+            output.push_str(&format!("   First: {}", first_ts));
+            if let Some(last_ts) = &event.last_timestamp {
+                if first_ts != last_ts {
+                    output.push_str(&format!(" | Last: {}", last_ts));
+                }
+            }
+            output.push('\n');
+        }
+
+        if let Some(source) = &event.source_component {
+            output.push_str(&format!("   Source: {}\n", source));
+        }
+    }
+
+    if summary.total_events > event_limit {
+        output.push_str(&format!(
+            "\n... and {} more events (total: {})\n",
+            summary.total_events - event_limit,
+            summary.total_events
+        ));
+    }
+}
+
+/// Append a project's permissions, roles and sync windows
+fn push_project_detail(output: &mut String, project: &ProjectDetail) {
+    if let Some(description) = &project.description {
+        output.push_str(&format!("Description: {}\n", description));
+    }
+
+    let mut push_list = |title: &str, items: &[String]| {
+        if items.is_empty() {
+            return;
+        }
+        output.push_str(&format!("\n{} ({}):\n", title, items.len()));
+        for item in items {
+            output.push_str(&format!("  - {}\n", item));
+        }
+    };
+    push_list("Source Repositories", &project.source_repos);
+    push_list("Source Namespaces", &project.source_namespaces);
+    push_list("Destinations", &project.destinations);
+    push_list("Cluster Resources Allowed", &project.cluster_resource_whitelist);
+    push_list("Cluster Resources Denied", &project.cluster_resource_blacklist);
+    push_list("Namespace Resources Allowed", &project.namespace_resource_whitelist);
+    push_list("Namespace Resources Denied", &project.namespace_resource_blacklist);
+
+    push_project_roles(output, &project.roles);
+
+    if !project.sync_windows.is_empty() {
+        output.push_str(&format!("\nSync Windows ({}):\n", project.sync_windows.len()));
+        for window in &project.sync_windows {
+            output.push_str(&format!(
+                "  - {} {} for {}",
+                window.kind.as_deref().unwrap_or("?"),
+                window.schedule.as_deref().unwrap_or("?"),
+                window.duration.as_deref().unwrap_or("?")
+            ));
+            if window.manual_sync == Some(true) {
+                output.push_str(" (manual sync allowed)");
+            }
+            output.push('\n');
+        }
+    }
+}
+
+/// Append project roles with their policies, groups and token metadata
+fn push_project_roles(output: &mut String, roles: &[ProjectRoleSummary]) {
+    if roles.is_empty() {
+        output.push_str("\nRoles: none\n");
+        return;
+    }
+    output.push_str(&format!("\nRoles ({}):\n", roles.len()));
+    for role in roles {
+        output.push_str(&format!("  • {}", role.name));
+        if let Some(description) = &role.description {
+            output.push_str(&format!(" - {}", description));
+        }
+        output.push('\n');
+        for policy in &role.policies {
+            output.push_str(&format!("    Policy: {}\n", policy));
+        }
+        if !role.groups.is_empty() {
+            output.push_str(&format!("    Groups: {}\n", role.groups.join(", ")));
+        }
+        for token in &role.tokens {
+            output.push_str(&format!(
+                "    Token: issued {} (iat {})",
+                token.issued_at_time, token.issued_at
+            ));
+            if let Some(id) = &token.id {
+                output.push_str(&format!(", id {}", id));
+            }
+            match &token.expires_at_time {
+                Some(exp) => output.push_str(&format!(", expires {}\n", exp)),
+                None => output.push_str(", never expires\n"),
+            }
+        }
+    }
+}
+
+/// Append a cluster's connection state, cache info, namespace scope and the
+/// applications deployed to it
+fn push_cluster_summary(output: &mut String, cluster: &ClusterSummary) {
+    let status = cluster.connection_status.as_deref().unwrap_or("Unknown");
+    let icon = match status {
+        "Successful" => "✅",
+        "Failed" => "❌",
+        _ => "❔",
+    };
+    output.push_str(&format!("Connection: {} {}\n", icon, status));
+    if let Some(message) = &cluster.connection_message {
+        output.push_str(&format!("  Message: {}\n", message));
+    }
+    if let Some(version) = &cluster.kubernetes_version {
+        output.push_str(&format!("Kubernetes Version: {}\n", version));
+    }
+    if cluster.resources_count.is_some() || cluster.apis_count.is_some() {
+        output.push_str(&format!(
+            "Cache: {} resources, {} APIs",
+            cluster.resources_count.unwrap_or(0),
+            cluster.apis_count.unwrap_or(0)
+        ));
+        if let Some(synced) = &cluster.last_cache_sync_time {
+            output.push_str(&format!(", last sync {}", synced));
+        }
+        output.push('\n');
+    }
+    if cluster.namespaces.is_empty() {
+        output.push_str("Namespaces: all\n");
+    } else {
+        output.push_str(&format!("Namespaces: {}\n", cluster.namespaces.join(", ")));
+    }
+    if let Some(project) = &cluster.project {
+        output.push_str(&format!("Project: {}\n", project));
+    }
+    if cluster.applications.is_empty() {
+        output.push_str("Applications: none\n");
+    } else {
+        output.push_str(&format!("Applications ({}):\n", cluster.applications.len()));
+        for app in &cluster.applications {
+            output.push_str(&format!("  - {}\n", app));
+        }
+    }
+}
+
+/// Append a repository's type, scope, connection state and kind of credentials
+fn push_repository_summary(output: &mut String, repository: &RepositorySummary) {
+    let status = repository.connection_status.as_deref().unwrap_or("Unknown");
+    let icon = match status {
+        "Successful" => "✅",
+        "Failed" => "❌",
+        _ => "❔",
+    };
+    output.push_str(&format!(
+        "  Type: {} | Connection: {} {}\n",
+        repository.repo_type.as_deref().unwrap_or("git"),
+        icon,
+        status
+    ));
+    if let Some(message) = &repository.connection_message {
+        output.push_str(&format!("  Message: {}\n", message));
+    }
+    output.push_str(&format!("  Credentials: {}", repository.auth));
+    if repository.inherited_creds {
+        output.push_str(" (from credential template)");
+    }
+    output.push('\n');
+    if let Some(project) = &repository.project {
+        output.push_str(&format!("  Project: {}\n", project));
+    }
+    let flags: Vec<&str> = [
+        (repository.insecure, "insecure"),
+        (repository.enable_oci, "OCI"),
+        (repository.enable_lfs, "LFS"),
+    ]
+    .iter()
+    .filter(|(set, _)| *set)
+    .map(|(_, name)| *name)
+    .collect();
+    if !flags.is_empty() {
+        output.push_str(&format!("  Options: {}\n", flags.join(", ")));
+    }
+}
+
+/// Append an ApplicationSet's generators, template, conditions and the
+/// status of each generated application
+fn push_applicationset_detail(output: &mut String, appset: &ApplicationSetDetail) {
+    output.push_str(&format!("\nGenerators ({}):\n", appset.generators.len()));
+    for generator in &appset.generators {
+        output.push_str(&format!("  - {}\n", generator));
+    }
+
+    output.push_str("\nTemplate:\n");
+    if let Some(name) = &appset.template_name {
+        output.push_str(&format!("Name: {}", name));
+        if appset.go_template {
+            output.push_str(" (Go template)");
+        }
+        output.push('\n');
+    }
+    if let Some(spec) = &appset.template {
+        push_spec_summary(output, spec);
+    }
+    if let Some(policy) = &appset.applications_sync {
+        output.push_str(&format!("Applications Sync: {}\n", policy));
+    }
+    if appset.preserve_resources_on_deletion {
+        output.push_str("Preserve Resources On Deletion: true\n");
+    }
+    if appset.progressive_sync {
+        output.push_str("Progressive Sync: enabled\n");
+    }
+
+    if !appset.conditions.is_empty() {
+        output.push_str("\nConditions:\n");
+        for condition in &appset.conditions {
+            let icon = match (condition.condition_type.as_str(), condition.status.as_deref()) {
+                ("ErrorOccurred", Some("True")) => "❌",
+                (_, Some("True")) => "✅",
+                _ => "•",
+            };
+            output.push_str(&format!(
+                "  {} {}={}",
+                icon,
+                condition.condition_type,
+                condition.status.as_deref().unwrap_or("Unknown")
+            ));
+            if let Some(message) = &condition.message {
+                output.push_str(&format!(": {}", message));
+            }
+            output.push('\n');
+        }
+    }
+
+    output.push_str(&format!(
+        "\nGenerated Applications ({}):\n",
+        appset.applications.len()
+    ));
+    for app in &appset.applications {
+        output.push_str(&format!(
+            "  - {}: {} / {}",
+            app.name,
+            app.sync_status.as_deref().unwrap_or("Unknown"),
+            app.health_status.as_deref().unwrap_or("Unknown")
+        ));
+        if let Some(rollout) = &app.rollout_status {
+            output.push_str(&format!(" (rollout: {}", rollout));
+            if let Some(step) = &app.rollout_step {
+                output.push_str(&format!(", step {}", step));
+            }
+            output.push(')');
+        }
+        output.push('\n');
+        if let Some(message) = &app.health_message {
+            output.push_str(&format!("      {}\n", message));
+        }
+    }
+}
+
+/// Wait for an application, sending a progress notification after every
+/// check when the caller passed a progress token
+#[allow(clippy::too_many_arguments)]
+async fn wait_with_progress(
+    client: &ArgocdClient,
+    name: &str,
+    app_namespace: Option<String>,
+    project: Option<String>,
+    conditions: &WaitConditions,
+    timeout_seconds: Option<u64>,
+    poll_interval_seconds: Option<u64>,
+    peer: &Peer<RoleServer>,
+    meta: &Meta,
+) -> Result<ApplicationWaitSummary, McpError> {
+    let timeout = timeout_seconds.unwrap_or(300).clamp(1, 3600);
+    let poll_interval = poll_interval_seconds.unwrap_or(5).clamp(1, 60);
+    let progress_token = meta.get_progress_token();
+
+    client
+        .wait_for_application(
+            name.to_string(),
+            app_namespace,
+            project,
+            conditions,
+            std::time::Duration::from_secs(timeout),
+            std::time::Duration::from_secs(poll_interval),
+            |progress| {
+                let peer = peer.clone();
+                let progress_token = progress_token.clone();
+                async move {
+                    let Some(progress_token) = progress_token else {
+                        return;
+                    };
+                    // Progress is advisory, so a failed notification must not end the wait
+                    let _ = peer
+                        .notify_progress(ProgressNotificationParam {
+                            progress_token,
+                            progress: progress.elapsed_seconds as f64,
+                            total: Some(progress.timeout_seconds as f64),
+                            message: Some(progress.message()),
+                        })
+                        .await;
+                }
+            },
+        )
+        .await
+        .or_api_error(client, "Failed to wait for application", Some(name))
+        .await
+}
+
+/// Serialize a tool summary, adding the wait result under `wait` when there is one
+fn json_with_wait<T: serde::Serialize>(
+    summary: &T,
+    wait: Option<&ApplicationWaitSummary>,
+) -> Result<String, McpError> {
+    let serialize_error = |e: serde_json::Error| {
+        McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+    };
+    let mut value = serde_json::to_value(summary).map_err(serialize_error)?;
+    if let (Some(wait), serde_json::Value::Object(map)) = (wait, &mut value) {
+        map.insert("wait".to_string(), serde_json::to_value(wait).map_err(serialize_error)?);
+    }
+    serde_json::to_string_pretty(&value).map_err(serialize_error)
+}
+
+/// Operation start time recorded before a sync or rollback is requested, so
+/// waiting afterwards does not mistake the previous operation for the new one
+async fn operation_started_at(
+    client: &ArgocdClient,
+    name: &str,
+    app_namespace: Option<String>,
+    project: Option<String>,
+) -> Result<Option<String>, McpError> {
+    let app = client
+        .get_application_full(name.to_string(), app_namespace, project, None, None)
+        .await
+        .or_api_error(client, "Failed to get application", Some(name))
+        .await?;
+    Ok(app
+        .status
+        .and_then(|s| s.operation_state)
+        .and_then(|op| op.started_at))
+}
+
+/// Append the outcome and final state of a wait
+fn push_wait_summary(output: &mut String, summary: &ApplicationWaitSummary) {
+    let (icon, outcome) = match summary.outcome {
+        WaitOutcome::Succeeded => ("✅", "conditions met"),
+        WaitOutcome::OperationFailed => ("❌", "operation failed"),
+        WaitOutcome::TimedOut => ("⏱️", "timed out"),
+    };
+    output.push_str(&format!(
+        "{} Wait for {}: {} after {}s ({} checks)\n",
+        icon,
+        summary.waited_for.join(", "),
+        outcome,
+        summary.elapsed_seconds,
+        summary.polls
+    ));
+    if !summary.unmet.is_empty() {
+        output.push_str(&format!("  Still waiting until: {}\n", summary.unmet.join(", ")));
+    }
+    output.push_str(&format!(
+        "  Sync: {}{}\n",
+        summary.sync_status.as_deref().unwrap_or("Unknown"),
+        summary
+            .sync_revision
+            .as_deref()
+            .map(|r| format!(" ({})", r))
+            .unwrap_or_default()
+    ));
+    output.push_str(&format!(
+        "  Health: {}{}\n",
+        summary.health_status.as_deref().unwrap_or("Unknown"),
+        summary
+            .health_message
+            .as_deref()
+            .map(|m| format!(" - {}", m))
+            .unwrap_or_default()
+    ));
+    for condition in &summary.errors {
+        output.push_str(&format!(
+            "  ❌ {}: {}\n",
+            condition.condition_type, condition.message
+        ));
+    }
+    if let Some(operation) = &summary.operation {
+        push_operation_summary(output, operation);
+    }
+    push_resource_issues(output, &summary.resource_issues, None);
+}
+
+/// Append resources that are out of sync or unhealthy, out of `total` when known
+fn push_resource_issues(output: &mut String, issues: &[ResourceStatus], total: Option<usize>) {
+    if issues.is_empty() {
+        return;
+    }
+    match total {
+        Some(total) => output.push_str(&format!(
+            "\nResources With Issues ({} of {}):\n",
+            issues.len(),
+            total
+        )),
+        None => output.push_str(&format!("\nResources With Issues ({}):\n", issues.len())),
+    }
+    for resource in issues {
+        let health = resource.health.as_ref();
+        output.push_str(&format!(
+            "  {} {}{}: {} / {}{}\n",
+            resource.kind.as_deref().unwrap_or("Unknown"),
+            resource
+                .namespace
+                .as_deref()
+                .map(|ns| format!("{}/", ns))
+                .unwrap_or_default(),
+            resource.name,
+            resource.status.as_deref().unwrap_or("Unknown"),
+            health.map_or("-", |h| h.status.as_str()),
+            health
+                .and_then(|h| h.message.as_deref())
+                .map(|m| format!(" ({})", m))
+                .unwrap_or_default()
+        ));
+    }
+}
+
+/// Append the phase, timing and failed or hook results of an operation
+fn push_operation_summary(output: &mut String, operation: &OperationSummary) {
+    let (title, icon) = match operation.phase.as_str() {
+        "Running" | "Terminating" => ("Current Operation", "🔄"),
+        "Succeeded" => ("Last Operation", "✅"),
+        _ => ("Last Operation", "❌"),
+    };
+    output.push_str(&format!("\n{}: {} {}\n", title, icon, operation.phase));
+    if let Some(message) = &operation.message {
+        output.push_str(&format!("  Message: {}\n", message));
+    }
+    if let Some(started) = &operation.started_at {
+        output.push_str(&format!("  Started: {}\n", started));
+    }
+    if let Some(finished) = &operation.finished_at {
+        output.push_str(&format!("  Finished: {}\n", finished));
+    }
+    if let Some(by) = &operation.initiated_by {
+        output.push_str(&format!("  Initiated By: {}\n", by));
+    }
+    if let Some(revision) = &operation.revision {
+        output.push_str(&format!("  Revision: {}\n", revision));
+    }
+    if !operation.revisions.is_empty() {
+        output.push_str(&format!("  Revisions: {}\n", operation.revisions.join(", ")));
+    }
+    if let Some(retries) = operation.retry_count.filter(|count| *count > 0) {
+        output.push_str(&format!("  Retries: {}\n", retries));
+    }
+    if !operation.result_counts.is_empty() {
+        let counts: Vec<String> = operation
+            .result_counts
+            .iter()
+            .map(|(status, count)| format!("{}: {}", status, count))
+            .collect();
+        output.push_str(&format!("  Results: {}\n", counts.join(", ")));
+    }
+    for result in &operation.resource_results {
+        let icon = if result.is_failure() { "❌" } else { "🪝" };
+        let target = if result.namespace.is_empty() {
+            format!("{} {}", result.kind, result.name)
+        } else {
+            format!("{} {}/{}", result.kind, result.namespace, result.name)
+        };
+        let mut line = format!("  {} {}", icon, target);
+        if let Some(hook_type) = &result.hook_type {
+            line.push_str(&format!(
+                " [{} hook: {}]",
+                hook_type,
+                result.hook_phase.as_deref().unwrap_or("Unknown")
+            ));
+        } else if let Some(status) = &result.status {
+            line.push_str(&format!(": {}", status));
+        }
+        if let Some(message) = result.message.as_deref().filter(|m| !m.is_empty()) {
+            line.push_str(&format!(" - {}", message));
+        }
+        output.push_str(&line);
+        output.push('\n');
+    }
+}
+
+/// Append each source of a multi-source application with its resolved revision
+fn push_sources(output: &mut String, indent: &str, sources: &[SourceSummary]) {
+    for source in sources {
+        output.push_str(&format!("{}{}. {}\n", indent, source.position, source.describe()));
+        if let Some(revision) = &source.revision {
+            output.push_str(&format!("{}   Revision: {}\n", indent, revision));
+        }
+    }
+}
+
+/// MCP logging level for a log line
+fn logging_level(level: &LogLevel) -> LoggingLevel {
+    match level {
+        LogLevel::Fatal => LoggingLevel::Critical,
+        LogLevel::Error => LoggingLevel::Error,
+        LogLevel::Warning => LoggingLevel::Warning,
+        LogLevel::Debug => LoggingLevel::Debug,
+        LogLevel::Info | LogLevel::Unknown => LoggingLevel::Info,
+    }
+}
+
+/// Follow pod logs, sending each batch of lines at or above `min_level` as a
+/// log notification, and a progress notification per batch when the caller
+/// passed a progress token
+async fn follow_with_notifications(
+    client: &ArgocdClient,
+    query: PodLogsQuery,
+    errors_only: bool,
+    options: &LogFollowOptions,
+    min_level: LoggingLevel,
+    peer: &Peer<RoleServer>,
+    meta: &Meta,
+) -> Result<PodLogsSummary, McpError> {
+    let application = query.application_name.clone();
+    let progress_token = meta.get_progress_token();
+    let max_lines = options.max_lines;
+
+    client
+        .pod_logs_follow(query, errors_only, options, |batch| {
+            let peer = peer.clone();
+            let progress_token = progress_token.clone();
+            let application = application.clone();
+            async move {
+                // Notifications are advisory, so a failed one must not end the stream
+                let lines: Vec<(LoggingLevel, &AnalyzedLogEntry)> = batch
+                    .entries
+                    .iter()
+                    .map(|entry| (logging_level(&entry.level), entry))
+                    .filter(|(level, _)| *level as u8 >= min_level as u8)
+                    .collect();
+                let highest = lines.iter().map(|(level, _)| *level).max_by_key(|l| *l as u8);
+                if let Some(level) = highest {
+                    let lines: Vec<serde_json::Value> = lines
+                        .iter()
+                        .map(|(_, entry)| {
+                            serde_json::json!({
+                                "pod": entry.pod_name,
+                                "timestamp": entry.timestamp,
+                                "level": entry.level.as_str(),
+                                "content": entry.content,
+                            })
+                        })
+                        .collect();
+                    let _ = peer
+                        .notify_logging_message(LoggingMessageNotificationParam {
+                            level,
+                            logger: Some("pod_logs".to_string()),
+                            data: serde_json::json!({ "application": application, "lines": lines }),
+                        })
+                        .await;
+                }
+
+                if let Some(progress_token) = progress_token {
+                    let _ = peer
+                        .notify_progress(ProgressNotificationParam {
+                            progress_token,
+                            progress: batch.lines_received as f64,
+                            total: Some(max_lines as f64),
+                            message: Some(batch.message()),
+                        })
+                        .await;
+                }
+            }
+        })
+        .await
+        .or_api_error(client, "Failed to follow pod logs", Some(&application))
+        .await
+}
+
+/// Append how a followed log stream ended
+fn push_follow_summary(output: &mut String, follow: &LogFollowSummary) {
+    let reason = match follow.stopped {
+        LogFollowStop::PatternMatched => format!(
+            "matched '{}'",
+            follow.stop_pattern.as_deref().unwrap_or_default()
+        ),
+        LogFollowStop::LineLimit => "line limit reached".to_string(),
+        LogFollowStop::TimeLimit => "time limit reached".to_string(),
+        LogFollowStop::StreamEnded => "stream ended".to_string(),
+    };
+    output.push_str(&format!(
+        "Followed for {}s: {} line(s) received, stopped: {}\n",
+        follow.elapsed_seconds, follow.lines_received, reason
+    ));
+    if let Some(line) = &follow.matched_line {
+        output.push_str(&format!("Matched line: {}\n", line));
+    }
+}
+
+/// Parse an application resource URI (see `WatchKey::parse`)
+fn parse_resource_uri(uri: &str) -> Result<WatchKey, McpError> {
+    WatchKey::parse(uri).ok_or_else(|| {
+        McpError::resource_not_found(
+            format!(
+                "Unknown resource URI '{}'. Expected argocd://{{context}}/applications/{{name}} or argocd://{{context}}/applications/{{name}}/resource-tree, optionally followed by ?appNamespace={{namespace}}",
+                uri
+            ),
+            None,
+        )
+    })
+}
+
+/// Append the counts, level breakdown and entries of a pod logs summary
+fn push_log_analysis(output: &mut String, summary: &PodLogsSummary) {
+    output.push_str(&format!("\nTotal lines: {}\n", summary.total_lines));
+
+    if summary.filtered {
+        output.push_str("🔍 Filtered to show errors and potential issues only\n");
+    }
+
+    // Log statistics
+    if summary.error_count > 0
+        || summary.warning_count > 0
+        || summary.potential_issue_count > 0
+    {
+        output.push_str("\n📊 Log Analysis:\n");
+        if summary.error_count > 0 {
+            output.push_str(&format!("  ❌ Errors: {}\n", summary.error_count));
+        }
+        if summary.warning_count > 0 {
+            output.push_str(&format!("  ⚠️  Warnings: {}\n", summary.warning_count));
+        }
+        if summary.potential_issue_count > 0 {
+            output.push_str(&format!(
+                "  🔍 Potential Issues: {}\n",
+                summary.potential_issue_count
+            ));
+        }
+    }
+
+    // Log levels breakdown
+    if !summary.logs_by_level.is_empty() {
+        output.push_str("\nLogs by Level:\n");
+        let mut levels: Vec<_> = summary.logs_by_level.iter().collect();
+        levels.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+        for (level, count) in levels {
+            output.push_str(&format!("  {}: {}\n", level, count));
+        }
+    }
+
+    if let Some(clusters) = &summary.clusters {
+        push_log_clusters(output, clusters);
+        return;
+    }
+
+    // Show log entries
+    output.push_str(&format!(
+        "\n📝 Log Entries (showing {}):\n",
+        summary.total_lines
+    ));
+    output.push_str(&"─".repeat(80));
+    output.push('\n');
+
+    for (idx, entry) in summary.log_entries.iter().enumerate() {
+        // Add visual indicators for issues
+        let indicator = level_indicator(&entry.level);
+
+        // Format timestamp if available
+        let timestamp_str = if let Some(ts) = &entry.timestamp {
+            format!("[{}] ", ts)
+        } else {
+            String::new()
+        };
+
+        // Label merged lines with the pod (and container) they came from
+        let source = match (&summary.workload, &entry.pod_name, &entry.container) {
+            (Some(_), Some(pod), Some(container)) => format!("{}/{} ", pod, container),
+            (Some(_), Some(pod), None) => format!("{} ", pod),
+            _ => String::new(),
+        };
+
+        output.push_str(&format!(
+            "{}{} {}{}{}\n",
+            indicator,
+            timestamp_str,
+            source,
+            entry.level.as_str(),
+            if entry.level.as_str() != "UNKNOWN" {
+                ": "
+            } else {
+                ""
+            }
+        ));
+        output.push_str(&format!("   {}\n", entry.content));
+
+        // Add separator between entries for readability
+        if idx < summary.total_lines - 1 {
+            output.push('\n');
+        }
+    }
+
+    output.push_str(&"─".repeat(80));
+    output.push('\n');
+}
+
+/// Visual indicator for a log level
+fn level_indicator(level: &LogLevel) -> &'static str {
+    match level {
+        LogLevel::Fatal => "💀",
+        LogLevel::Error => "❌",
+        LogLevel::Warning => "⚠️ ",
+        LogLevel::Info => "ℹ️ ",
+        LogLevel::Debug => "🐛",
+        LogLevel::Unknown => "  ",
+    }
+}
+
+/// Append log clusters, most frequent first
+fn push_log_clusters(output: &mut String, clusters: &LogClusterSummary) {
+    output.push_str(&format!(
+        "\n🧩 Log Patterns (showing {} of {}):\n",
+        clusters.clusters.len(),
+        clusters.total_clusters
+    ));
+    output.push_str(&"─".repeat(80));
+    output.push('\n');
+
+    for (idx, cluster) in clusters.clusters.iter().enumerate() {
+        output.push_str(&format!(
+            "{} {}× {}",
+            level_indicator(&cluster.level),
+            cluster.count,
+            cluster.level.as_str()
+        ));
+        match (&cluster.first_seen, &cluster.last_seen) {
+            (Some(first), Some(last)) if cluster.count > 1 && first != last => {
+                output.push_str(&format!(" [{} … {}]", first, last));
+            }
+            (Some(first), _) => output.push_str(&format!(" [{}]", first)),
+            _ => {}
+        }
+        output.push('\n');
+        output.push_str(&format!("   pattern: {}\n", cluster.pattern));
+        if cluster.count > 1 || cluster.example != cluster.pattern {
+            output.push_str(&format!("   example: {}\n", cluster.example));
+        }
+        if !cluster.pods.is_empty() {
+            output.push_str(&format!("   pods: {}\n", cluster.pods.join(", ")));
+        }
+
+        if idx < clusters.clusters.len() - 1 {
+            output.push('\n');
+        }
+    }
+
+    output.push_str(&"─".repeat(80));
+    output.push('\n');
+    if clusters.omitted_lines > 0 {
+        output.push_str(&format!(
+            "{} more pattern(s) with {} line(s) omitted (top_clusters)\n",
+            clusters.total_clusters - clusters.clusters.len(),
+            clusters.omitted_lines
+        ));
+    }
+}
+
+/// Append the per-replica breakdown of a merged workload log
+fn push_replica_summary(output: &mut String, workload: &WorkloadLogsSummary) {
+    output.push_str(&format!(
+        "Replicas: {} erroring, {} with warnings, {} healthy",
+        workload.count(ReplicaLogState::Erroring),
+        workload.count(ReplicaLogState::Warning),
+        workload.count(ReplicaLogState::Healthy)
+    ));
+    let unavailable = workload.count(ReplicaLogState::Unavailable);
+    if unavailable > 0 {
+        output.push_str(&format!(", {} unavailable", unavailable));
+    }
+    output.push('\n');
+    if workload.pods_found > workload.replicas.len() {
+        output.push_str(&format!(
+            "⚠️  Showing {} of {} pods (unhealthy pods first)\n",
+            workload.replicas.len(),
+            workload.pods_found
+        ));
+    }
+
+    for replica in &workload.replicas {
+        let indicator = match replica.state {
+            ReplicaLogState::Erroring => "❌",
+            ReplicaLogState::Warning => "⚠️ ",
+            ReplicaLogState::Healthy => "✅",
+            ReplicaLogState::Unavailable => "❔",
+        };
+        output.push_str(&format!("  {} {}", indicator, replica.pod_name));
+        if let Some(health) = &replica.health_status {
+            output.push_str(&format!(" ({})", health));
+        }
+        if let Some(error) = &replica.fetch_error {
+            output.push_str(&format!(": logs unavailable: {}\n", error));
+            continue;
+        }
+        output.push_str(&format!(
+            ": {} line(s), {} error(s), {} warning(s)\n",
+            replica.total_lines, replica.error_count, replica.warning_count
+        ));
+        if let Some(line) = &replica.last_error {
+            let line: String = line.chars().take(200).collect();
+            output.push_str(&format!("     last error: {}\n", line));
+        }
+    }
+}
+
+/// Append the project, sources, destination and sync policy of a spec
+fn push_spec_summary(output: &mut String, spec: &ApplicationSpecSummary) {
+    if let Some(project) = &spec.project {
+        output.push_str(&format!("Project: {}\n", project));
+    }
+    for source in &spec.sources {
+        output.push_str(&format!("Source: {}\n", source));
+    }
+    let cluster = spec
+        .destination_server
+        .as_deref()
+        .or(spec.destination_name.as_deref())
+        .unwrap_or("(none)");
+    output.push_str(&format!(
+        "Destination: {} / {}\n",
+        cluster,
+        spec.destination_namespace.as_deref().unwrap_or("(none)")
+    ));
+    if spec.automated_sync {
+        output.push_str(&format!(
+            "Automated Sync: enabled (prune: {}, self-heal: {})\n",
+            spec.auto_prune, spec.self_heal
+        ));
+    } else {
+        output.push_str("Automated Sync: disabled\n");
+    }
+    if !spec.sync_options.is_empty() {
+        output.push_str(&format!("Sync Options: {}\n", spec.sync_options.join(", ")));
+    }
+}
+
+/// Append the outcome of a dry run's local checks
+fn push_problems(output: &mut String, problems: &[String], tool: &str) {
+    if problems.is_empty() {
+        output.push_str("\n✅ Dry run passed. No actual changes were made.\n");
+        output.push_str(&format!(
+            "    Run {} without dry_run=true to apply it.\n",
+            tool
+        ));
+    } else {
+        output.push_str("\n❌ Dry run found problems. No actual changes were made.\n");
+        for problem in problems {
+            output.push_str(&format!("  - {}\n", problem));
+        }
+    }
+}
+
+/// MCP Server handler for ArgoCD operations
+#[derive(Clone)]
+pub struct ArgocdMcpHandler {
+    contexts: Arc<RwLock<ContextRegistry>>,
+    tool_router: ToolRouter<Self>,
+    read_only: bool,
+    limits: OutputLimits,
+    /// Watch streams behind resource subscriptions, shared by all sessions
+    watches: WatchHub,
+    /// Identifies this session's resource subscriptions
+    subscriber: u64,
+    /// Lowest level of log notifications this session wants (logging/setLevel)
+    log_level: Arc<std::sync::Mutex<LoggingLevel>>,
+}
+
+#[tool_router]
+impl ArgocdMcpHandler {
+    /// Create a new handler with optional read-only mode
+    pub fn new() -> Self {
+        Self::with_read_only(false)
+    }
+
+    /// Create a new handler with explicit read-only mode
+    pub fn with_read_only(read_only: bool) -> Self {
+        Self {
+            contexts: Arc::new(RwLock::new(ContextRegistry::default())),
+            tool_router: Self::tool_router(),
+            read_only,
+            limits: OutputLimits::default(),
+            watches: WatchHub::default(),
+            subscriber: next_subscriber_id(),
+            log_level: Arc::new(std::sync::Mutex::new(LoggingLevel::Info)),
+        }
+    }
+
+    /// Handler for a new MCP session
+    ///
+    /// Contexts and watch streams are shared; resource subscriptions and
+    /// the log notification level are tracked per session.
+    pub fn new_session(&self) -> Self {
+        Self {
+            subscriber: next_subscriber_id(),
+            log_level: Arc::new(std::sync::Mutex::new(LoggingLevel::Info)),
+            ..self.clone()
+        }
+    }
+
+    /// Create a new handler from a loaded configuration
+    /// Contexts are registered separately with `initialize_contexts`
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            limits: config.output.clone(),
+            ..Self::with_read_only(config.read_only)
+        }
+    }
+
+    /// Create a new handler from environment variables
+    /// Reads ARGOCD_READ_ONLY environment variable (true/false, default: false)
+    #[allow(dead_code)]
+    pub fn from_env() -> Self {
+        let read_only = std::env::var("ARGOCD_READ_ONLY")
+            .ok()
+            .and_then(|v| v.parse::<bool>().ok())
+            .unwrap_or(false);
+        Self::with_read_only(read_only)
+    }
+
+    /// Initialize the client with credentials
+    /// Registers the credentials as the "default" context
+    #[allow(dead_code)]
+    pub async fn initialize(&self, base_url: String, access_token: String) -> anyhow::Result<()> {
+        self.add_context(DEFAULT_CONTEXT_NAME.to_string(), base_url, access_token)
+            .await
+    }
+
+    /// Register (or replace) a named ArgoCD context
+    pub async fn add_context(
+        &self,
+        name: String,
+        base_url: String,
+        access_token: String,
+    ) -> anyhow::Result<()> {
+        let client = ArgocdClient::new(base_url, access_token)?;
+        let mut guard = self.contexts.write().await;
+        guard.insert(name, client);
+        Ok(())
+    }
+
+    /// Set the context used by tool calls that do not pass `context`
+    pub async fn set_default_context(&self, name: &str) -> anyhow::Result<()> {
+        let mut guard = self.contexts.write().await;
+        guard.set_default(name)
+    }
+
+    /// Register all configured contexts and select the default context
+    pub async fn initialize_contexts(&self, config: ContextsConfig) -> anyhow::Result<()> {
+        for settings in config.contexts {
+            let client = ArgocdClient::with_credentials(
+                settings.base_url,
+                settings.credentials,
+                settings.options,
+            )
+            .map_err(|e| {
+                e.context(format!(
+                    "Failed to initialize ArgoCD context '{}'",
+                    settings.name
+                ))
+            })?;
+            self.contexts.write().await.insert(settings.name, client);
+        }
+        if let Some(default) = config.default_context {
+            self.set_default_context(&default).await?;
+        }
+        Ok(())
+    }
+
+    /// Probe every registered context with `/api/version`
+    ///
+    /// A wrong base URL, root path or credential then stops startup
+    /// instead of failing the first tool call.
+    pub async fn probe_contexts(&self) -> anyhow::Result<()> {
+        let clients: Vec<(String, ArgocdClient)> = self
+            .contexts
+            .read()
+            .await
+            .iter()
+            .map(|(name, client)| (name.to_string(), client.clone()))
+            .collect();
+
+        for (name, client) in clients {
+            match client.get_version().await {
+                Ok(version) => tracing::info!(
+                    "Context '{}': ArgoCD {} at {}",
+                    name,
+                    version.version,
+                    client.base_url()
+                ),
+                Err(e) => anyhow::bail!(
+                    "Context '{}' failed the startup probe ({}/api/version): {}\nCheck base_url, root_path and credentials, or disable the probe with --startup-probe=false",
+                    name,
+                    client.base_url(),
+                    e
+                ),
+            }
+        }
+        Ok(())
+    }
+
+    /// Names of the registered contexts
+    #[allow(dead_code)]
+    pub async fn context_names(&self) -> Vec<String> {
+        let guard = self.contexts.read().await;
+        guard.names().into_iter().map(String::from).collect()
+    }
+
+    /// Resolve the client for a tool call's `context` argument
+    async fn client_for(&self, context: Option<&str>) -> Result<ArgocdClient, McpError> {
+        let guard = self.contexts.read().await;
+        match guard.resolve(context) {
+            ContextLookup::Found(client) => Ok(client.clone()),
+            ContextLookup::Empty => Err(McpError::internal_error(
+                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
+                None,
+            )),
+            ContextLookup::NoDefault => Err(McpError::invalid_params(
+                format!(
+                    "Multiple ArgoCD contexts are configured and no default is set. Pass 'context' with one of: {}",
+                    guard.names().join(", ")
+                ),
+                None,
+            )),
+            ContextLookup::Unknown(name) => Err(McpError::invalid_params(
+                format!(
+                    "Unknown ArgoCD context '{}'. Available contexts: {}",
+                    name,
+                    guard.names().join(", ")
+                ),
+                None,
+            )),
+        }
+    }
+
+    /// Contents of an application resource as pretty JSON: the watch cache
+    /// when the resource is watched, otherwise fetched from ArgoCD
+    async fn read_application_resource(&self, key: &WatchKey) -> Result<String, McpError> {
+        let value = match self.watches.snapshot(key) {
+            Some(snapshot) => snapshot.render(),
+            None => {
+                // Resolve the client for the resource's context
+                let client = self.client_for(Some(&key.context)).await?;
+
+                // Call ArgoCD API
+                let rendered = match key.target {
+                    WatchTarget::Application => client
+                        .get_application(
+                            key.name.clone(),
+                            key.app_namespace.clone(),
+                            None,
+                            None,
+                            None,
+                        )
+                        .await
+                        .or_api_error(&client, "Failed to get application", Some(&key.name))
+                        .await
+                        .map(|detail| serde_json::to_value(&detail))?,
+                    WatchTarget::ResourceTree => client
+                        .resource_tree(
+                            key.name.clone(),
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            key.app_namespace.clone(),
+                            None,
+                        )
+                        .await
+                        .or_api_error(&client, "Failed to get resource tree", Some(&key.name))
+                        .await
+                        .map(|tree| serde_json::to_value(&tree))?,
+                };
+                rendered.map_err(|e| {
+                    McpError::internal_error(format!("Failed to serialize resource: {}", e), None)
+                })?
+            }
+        };
+
+        serde_json::to_string_pretty(&value).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize resource: {}", e), None)
+        })
+    }
+
+    /// Check if the handler is in read-only mode
+    #[allow(dead_code)]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// List the configured ArgoCD contexts
+    #[tool(
+        description = "List the configured ArgoCD contexts (named ArgoCD instances). Shows each context name, its server URL, and which one is the default. Pass a context name as the 'context' argument of any other tool to run it against that instance; tools use the default context when 'context' is omitted."
+    )]
+    async fn list_contexts(&self) -> Result<CallToolResult, McpError> {
+        let guard = self.contexts.read().await;
+        let default = guard.default_name();
+        let summaries: Vec<ContextSummary> = guard
+            .iter()
+            .map(|(name, client)| ContextSummary {
+                name: name.to_string(),
+                server: client.base_url().to_string(),
+                is_default: Some(name) == default,
+            })
+            .collect();
+        let has_default = default.is_some();
+        drop(guard);
+
+        if summaries.is_empty() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                "No ArgoCD contexts configured. Set ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN, or ARGOCD_CONTEXTS",
+            )]));
+        }
+
+        let mut output = format!("Found {} ArgoCD context(s):\n\n", summaries.len());
+        for (idx, context) in summaries.iter().enumerate() {
+            output.push_str(&format!("{}. {}", idx + 1, context.name));
+            if context.is_default {
+                output.push_str(" (default)");
+            }
+            output.push('\n');
+            output.push_str(&format!("   Server: {}\n\n", context.server));
+        }
+        if !has_default {
+            output.push_str("⚠️  No default context set - pass 'context' on every tool call\n");
+        }
+
+        let json_data = serde_json::to_string_pretty(&summaries).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// List ArgoCD applications with optional filters
+    #[tool(
+        description = "List ArgoCD applications. Returns optimized summaries including name, project, sync status, health status, repository information, and destination. Use filters to narrow down results by name, projects, labels, repository, or namespace."
+    )]
+    async fn list_applications(
+        &self,
+        Parameters(args): Parameters<ListApplicationsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let mut summaries = client
+            .list_applications(
+                args.name,
+                args.projects,
+                args.selector,
+                args.repo,
+                args.app_namespace,
+            )
+            .await
+            .or_api_error(&client, "Failed to list applications", None)
+            .await?;
+
+        if summaries.is_empty() {
+            Ok(CallToolResult::success(vec![Content::text(
+                "No applications found matching the criteria",
+            )]))
+        } else {
+            // Format as readable text
+            let total = summaries.len();
+            let mut output = format!("Found {} application(s):\n\n", total);
+            if let Some(max) = self.limits.max_applications.filter(|max| total > *max) {
+                summaries.truncate(max);
+                output.push_str(&format!(
+                    "Showing the first {} - narrow the filters or use list_application_names for the full list\n\n",
+                    max
+                ));
+            }
+
+            for (idx, app) in summaries.iter().enumerate() {
+                output.push_str(&format!("{}. {}\n", idx + 1, app.name));
+                if let Some(project) = &app.project {
+                    output.push_str(&format!("   Project: {}\n", project));
+                }
+                if let Some(repo) = &app.repo_url {
+                    output.push_str(&format!("   Repository: {}\n", repo));
+                }
+                if let Some(revision) = &app.target_revision {
+                    output.push_str(&format!("   Target Revision: {}\n", revision));
+                }
+                if !app.sources.is_empty() {
+                    output.push_str(&format!("   Sources ({}):\n", app.sources.len()));
+                    push_sources(&mut output, "     ", &app.sources);
+                }
+                if let Some(dest_server) = &app.destination_server {
+                    output.push_str(&format!("   Destination Server: {}\n", dest_server));
+                }
+                if let Some(dest_ns) = &app.destination_namespace {
+                    output.push_str(&format!("   Destination Namespace: {}\n", dest_ns));
+                }
+                if let Some(sync) = &app.sync_status {
+                    output.push_str(&format!("   Sync Status: {}\n", sync));
+                }
+                if let Some(health) = &app.health_status {
+                    output.push_str(&format!("   Health Status: {}\n", health));
+                }
+                if let Some(true) = app.auto_sync {
+                    output.push_str("   Auto Sync: Enabled\n");
+                }
+                output.push('\n');
+            }
+
+            // Also include JSON for structured consumption
+            let json_data = serde_json::to_string_pretty(&summaries).map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+            })?;
+
+            Ok(CallToolResult::success(vec![
+                Content::text(output),
+                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+            ]))
+        }
+    }
+
+    /// List only application names for efficient name lookup and typo correction
+    #[tool(
+        description = "List only ArgoCD application names. Returns a simple list of all application names, which is extremely efficient for name lookups and auto-correcting typos in application names. Use this when you need to find exact application names or verify if an application exists."
+    )]
+    async fn list_application_names(
+        &self,
+        Parameters(args): Parameters<ListApplicationNamesArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let names = client
+            .list_application_names(args.projects, args.selector, args.repo, args.app_namespace)
+            .await
+            .or_api_error(&client, "Failed to list application names", None)
+            .await?;
+
+        if names.is_empty() {
+            Ok(CallToolResult::success(vec![Content::text(
+                "No applications found matching the criteria",
+            )]))
+        } else {
+            // Format as a simple, readable list
+            let mut output = format!("Found {} application(s):\n\n", names.len());
+            for (idx, name) in names.iter().enumerate() {
+                output.push_str(&format!("{}. {}\n", idx + 1, name));
+            }
+
+            // Also include as a simple array for easier parsing
+            output.push_str("\n--- Application Names Array ---\n");
+            output.push_str(&format!("{:?}", names));
+
+            Ok(CallToolResult::success(vec![Content::text(output)]))
+        }
+    }
+
+    /// Perform server-side diff calculation using dry-run apply
+    #[tool(
+        description = "Perform server-side diff calculation for an ArgoCD application using dry-run apply. This executes a Server-Side Apply operation in dryrun mode and compares the predicted state with the live state. Returns a list of resources with their diff status, showing which resources have differences between the live and target state. NOTE: This feature requires ArgoCD v2.5+ with Server-Side Apply support. If unavailable, a 404 error will be returned."
+    )]
+    async fn server_side_diff(
+        &self,
+        Parameters(args): Parameters<ServerSideDiffArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summaries = client
+            .server_side_diff(
+                args.app_name.clone(),
+                args.app_namespace,
+                args.project,
+                args.target_manifests,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to perform server-side diff",
+                Some(args.app_name.as_str()),
+            )
+            .await?;
+
+        if summaries.is_empty() {
+            Ok(CallToolResult::success(vec![Content::text(format!(
+                "No resources found for application '{}'",
+                args.app_name
+            ))]))
+        } else {
+            // Count modified resources
+            let modified_count = summaries.iter().filter(|s| s.modified).count();
+            let total_count = summaries.len();
+
+            // Format as readable text
+            let mut output = format!("Server-Side Diff for application '{}'\n", args.app_name);
+            output.push_str(&format!(
+                "Total resources: {}, Modified: {}, In sync: {}\n\n",
+                total_count,
+                modified_count,
+                total_count - modified_count
+            ));
+
+            // Group by modified status
+            output.push_str("Modified Resources:\n");
+            let modified: Vec<_> = summaries.iter().filter(|s| s.modified).collect();
+            if modified.is_empty() {
+                output.push_str("  (none)\n\n");
+            } else {
+                for (idx, res) in modified.iter().enumerate() {
+                    output.push_str(&format!(
+                        "{}. {} ({})",
+                        idx + 1,
+                        res.resource_name,
+                        res.kind
+                    ));
+                    if let Some(ns) = &res.namespace {
+                        output.push_str(&format!(" in namespace '{}'", ns));
+                    }
+                    output.push('\n');
+                    if let Some(summary) = &res.diff_summary {
+                        output.push_str(&format!("   Status: {}\n", summary));
+                    }
+                }
+                output.push('\n');
+            }
+
+            output.push_str("In Sync Resources:\n");
+            let in_sync: Vec<_> = summaries.iter().filter(|s| !s.modified).collect();
+            if in_sync.is_empty() {
+                output.push_str("  (none)\n");
+            } else {
+                for (idx, res) in in_sync.iter().enumerate() {
+                    output.push_str(&format!(
+                        "{}. {} ({})",
+                        idx + 1,
+                        res.resource_name,
+                        res.kind
+                    ));
+                    if let Some(ns) = &res.namespace {
+                        output.push_str(&format!(" in namespace '{}'", ns));
+                    }
+                    output.push('\n');
+                }
+            }
+
+            // Also include JSON for structured consumption
+            let json_data = serde_json::to_string_pretty(&summaries).map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+            })?;
+
+            Ok(CallToolResult::success(vec![
+                Content::text(output),
+                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+            ]))
+        }
+    }
+
+    /// Get a single application by name
+    #[tool(
+        description = "Get detailed information about a specific ArgoCD application by name. Returns comprehensive application details including source repository, destination cluster, sync status, health status, sync policy configuration, error and warning conditions, the current or last operation with failed resources and hook phases, and resources that are out of sync or unhealthy. Use this when you need detailed information about a specific application."
+    )]
+    async fn get_application(
+        &self,
+        Parameters(args): Parameters<GetApplicationArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let detail = client
+            .get_application(
+                args.name.clone(),
+                args.app_namespace,
+                args.project,
+                args.refresh,
+                args.resource_version,
+            )
+            .await
+            .or_api_error(&client, "Failed to get application", Some(args.name.as_str()))
+            .await?;
+
+        // Format as readable text
+        let mut output = format!("Application: {}\n\n", detail.name);
+
+        // Errors explain most failed syncs, so show them before anything else
+        for condition in detail.conditions.iter().filter(|c| c.is_error()) {
+            output.push_str(&format!(
+                "❌ {}: {}\n",
+                condition.condition_type, condition.message
+            ));
+        }
+        if detail.conditions.iter().any(|c| c.is_error()) {
+            output.push('\n');
+        }
+
+        if let Some(ns) = &detail.namespace {
+            output.push_str(&format!("Namespace: {}\n", ns));
+        }
+        if let Some(project) = &detail.project {
+            output.push_str(&format!("Project: {}\n", project));
+        }
+        if let Some(created) = &detail.creation_timestamp {
+            output.push_str(&format!("Created: {}\n", created));
+        }
+
+        if !detail.sources.is_empty() {
+            output.push_str(&format!("\nSources ({}):\n", detail.sources.len()));
+            push_sources(&mut output, "  ", &detail.sources);
+        } else {
+            output.push_str("\nSource:\n");
+            if let Some(repo) = &detail.repo_url {
+                output.push_str(&format!("  Repository: {}\n", repo));
+            }
+            if let Some(path) = &detail.path {
+                output.push_str(&format!("  Path: {}\n", path));
+            }
+            if let Some(chart) = &detail.chart {
+                output.push_str(&format!("  Chart: {}\n", chart));
+            }
+            if let Some(revision) = &detail.target_revision {
+                output.push_str(&format!("  Target Revision: {}\n", revision));
+            }
+        }
+        if let Some(hydrator) = &detail.source_hydrator {
+            output.push_str("\nSource Hydrator:\n");
+            output.push_str(&format!(
+                "  Dry Source: {} {}@{}\n",
+                hydrator.dry_source.repo_url,
+                hydrator.dry_source.path,
+                hydrator.dry_source.target_revision
+            ));
+            output.push_str(&format!(
+                "  Sync Source: {} ({})\n",
+                hydrator.sync_source.target_branch, hydrator.sync_source.path
+            ));
+            if let Some(hydrate_to) = &hydrator.hydrate_to {
+                output.push_str(&format!("  Hydrate To: {}\n", hydrate_to.target_branch));
+            }
+        }
+
+        output.push_str("\nDestination:\n");
+        if let Some(server) = &detail.destination_server {
+            output.push_str(&format!("  Server: {}\n", server));
+        }
+        if let Some(ns) = &detail.destination_namespace {
+            output.push_str(&format!("  Namespace: {}\n", ns));
+        }
+        if let Some(name) = &detail.destination_name {
+            output.push_str(&format!("  Name: {}\n", name));
+        }
+
+        output.push_str("\nStatus:\n");
+        if let Some(sync_status) = &detail.sync_status {
+            output.push_str(&format!("  Sync Status: {}\n", sync_status));
+        }
+        if let Some(revision) = &detail.sync_revision {
+            output.push_str(&format!("  Sync Revision: {}\n", revision));
+        }
+        if let Some(health) = &detail.health_status {
+            output.push_str(&format!("  Health Status: {}\n", health));
+        }
+        if let Some(msg) = &detail.health_message {
+            output.push_str(&format!("  Health Message: {}\n", msg));
+        }
+        if let Some(source_type) = &detail.source_type {
+            output.push_str(&format!("  Source Type: {}\n", source_type));
+        }
+        if let Some(reconciled_at) = &detail.reconciled_at {
+            output.push_str(&format!("  Reconciled At: {}\n", reconciled_at));
+        }
+
+        let warnings: Vec<_> = detail.conditions.iter().filter(|c| !c.is_error()).collect();
+        if !warnings.is_empty() {
+            output.push_str("\nWarnings:\n");
+            for condition in warnings {
+                output.push_str(&format!(
+                    "  ⚠️ {}: {}\n",
+                    condition.condition_type, condition.message
+                ));
+            }
+        }
+
+        if let Some(operation) = &detail.operation {
+            push_operation_summary(&mut output, operation);
+        }
+
+        push_resource_issues(&mut output, &detail.resource_issues, Some(detail.resources_total));
+
+        if detail.auto_sync_enabled.is_some()
+            || detail.auto_sync_prune.is_some()
+            || detail.auto_sync_self_heal.is_some()
+        {
+            output.push_str("\nSync Policy:\n");
+            if let Some(true) = detail.auto_sync_enabled {
+                output.push_str("  Auto Sync: Enabled\n");
+            }
+            if let Some(prune) = detail.auto_sync_prune {
+                output.push_str(&format!("  Auto Prune: {}\n", prune));
+            }
+            if let Some(self_heal) = detail.auto_sync_self_heal {
+                output.push_str(&format!("  Self Heal: {}\n", self_heal));
+            }
+        }
+
+        if let Some(labels) = &detail.labels {
+            if !labels.is_empty() {
+                output.push_str("\nLabels:\n");
+                for (key, value) in labels {
+                    output.push_str(&format!("  {}: {}\n", key, value));
+                }
+            }
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&detail).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Get resource tree for an ArgoCD application
+    #[tool(
+        description = "Get the resource tree for an ArgoCD application. Returns a hierarchical view of all resources managed by the application, including Deployments, Services, Pods, ConfigMaps, and more. Provides resource counts by kind, health status summary, and sample resources. Use filters to narrow results by resource type, namespace, or other attributes."
+    )]
+    async fn resource_tree(
+        &self,
+        Parameters(args): Parameters<ResourceTreeArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .resource_tree(
+                args.application_name.clone(),
+                args.namespace,
+                args.name,
+                args.version,
+                args.group,
+                args.kind,
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to get resource tree",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Resource Tree for application '{}'\n",
+            args.application_name
+        );
+        output.push_str(&format!("Total resources: {}\n", summary.total_nodes));
+        output.push_str(&format!(
+            "Orphaned resources: {}\n\n",
+            summary.orphaned_nodes_count
+        ));
+
+        // Resources by kind
+        output.push_str("Resources by Kind:\n");
+        let mut kinds: Vec<_> = summary.nodes_by_kind.iter().collect();
+        kinds.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+        for (kind, count) in kinds {
+            output.push_str(&format!("  {}: {}\n", kind, count));
+        }
+        output.push('\n');
+
+        // Health summary
+        output.push_str("Health Summary:\n");
+        let mut health: Vec<_> = summary.health_summary.iter().collect();
+        health.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+        for (status, count) in health {
+            output.push_str(&format!("  {}: {}\n", status, count));
+        }
+        output.push('\n');
+
+        // Sample nodes
+        if !summary.sample_nodes.is_empty() {
+            output.push_str("Sample Resources (showing up to 10):\n");
+            for (idx, node) in summary.sample_nodes.iter().enumerate() {
+                output.push_str(&format!("{}. {} ({})", idx + 1, node.name, node.kind));
+                if let Some(ns) = &node.namespace {
+                    output.push_str(&format!(" in namespace '{}'", ns));
+                }
+                if let Some(health) = &node.health_status {
+                    output.push_str(&format!(" - Health: {}", health));
+                }
+                if let Some(parent_count) = node.parent_count {
+                    if parent_count > 0 {
+                        output.push_str(&format!(" - {} parent(s)", parent_count));
+                    }
+                }
+                if let Some(images) = &node.images {
+                    if !images.is_empty() {
+                        output.push_str(&format!("\n   Images: {}", images.join(", ")));
+                    }
+                }
+                output.push('\n');
+            }
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// List resource events for an ArgoCD application
+    #[tool(
+        description = "List Kubernetes events for an ArgoCD application or specific resource within an application. Returns event details including type (Normal/Warning), reason, message, timestamps, and involved objects. Use filters to narrow results by resource name, namespace, or UID. Events provide insights into application lifecycle, deployments, and issues. NOTE: If no events are found or the response format is unexpected, an empty list will be returned with appropriate logging."
+    )]
+    async fn list_resource_events(
+        &self,
+        Parameters(args): Parameters<ListResourceEventsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .list_resource_events(
+                args.application_name.clone(),
+                args.resource_namespace,
+                args.resource_name,
+                args.resource_uid,
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to list resource events",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        if summary.total_events == 0 {
+            Ok(CallToolResult::success(vec![Content::text(format!(
+                "No events found for application '{}'",
+                args.application_name
+            ))]))
+        } else {
+            // Format as readable text
+            let mut output = format!("Events for application '{}'\n", args.application_name);
+            output.push_str(&format!("Total events: {}\n\n", summary.total_events));
+
+            push_events(&mut output, &summary, self.limits.max_events);
+
+            // Also include JSON for structured consumption
+            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+            })?;
+
+            Ok(CallToolResult::success(vec![
+                Content::text(output),
+                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+            ]))
+        }
+    }
+
+    /// Get pod logs for an ArgoCD application resource
+    #[tool(
+        description = "Get container logs from pods in an ArgoCD application. Supports filtering for errors/warnings, tailing logs, and analyzing log levels. Essential for troubleshooting deployments, investigating crashes, and monitoring application behavior. Use 'errors_only' parameter to filter for issues automatically, and 'cluster' (or 'top_clusters' for the N most frequent) to collapse repeated lines such as a crash loop into patterns with counts. Set follow=true to stream new lines for a bounded time (follow_seconds) or line count (follow_lines), optionally until a line contains until_pattern (e.g. \"Started server\"); lines are sent as log and progress notifications while streaming and analyzed when the stream stops."
+    )]
+    async fn pod_logs(
+        &self,
+        Parameters(args): Parameters<PodLogsArgs>,
+        peer: Peer<RoleServer>,
+        meta: Meta,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Apply the configured default (context-efficient) and cap tail_lines
+        let tail_lines = Some(
+            args.tail_lines
+                .unwrap_or(self.limits.default_tail_lines)
+                .min(self.limits.max_tail_lines),
+        );
+
+        // Call ArgoCD API
+        let mut summary = if args.follow.unwrap_or(false) {
+            let max_lines = self.limits.max_tail_lines.max(1) as usize;
+            let options = LogFollowOptions {
+                max_duration: std::time::Duration::from_secs(
+                    args.follow_seconds.unwrap_or(30).clamp(1, 600),
+                ),
+                max_lines: args.follow_lines.unwrap_or(max_lines).clamp(1, max_lines),
+                stop_pattern: args.until_pattern.filter(|p| !p.is_empty()),
+                ..LogFollowOptions::default()
+            };
+            let query = PodLogsQuery {
+                application_name: args.application_name.clone(),
+                namespace: args.namespace,
+                pod_name: args.pod_name.clone(),
+                container: args.container.clone(),
+                since_seconds: args.since_seconds,
+                tail_lines,
+                previous: args.previous,
+                filter: args.filter,
+                kind: args.kind,
+                group: args.group,
+                resource_name: args.resource_name,
+                app_namespace: args.app_namespace,
+                project: args.project,
+            };
+            let min_level = *self.log_level.lock().unwrap();
+            follow_with_notifications(
+                &client,
+                query,
+                args.errors_only,
+                &options,
+                min_level,
+                &peer,
+                &meta,
+            )
+            .await?
+        } else {
+            client
+                .pod_logs(
+                    args.application_name.clone(),
+                    args.namespace,
+                    args.pod_name.clone(),
+                    args.container.clone(),
+                    args.since_seconds,
+                    tail_lines,
+                    args.previous,
+                    args.filter,
+                    args.kind,
+                    args.group,
+                    args.resource_name,
+                    args.app_namespace,
+                    args.project,
+                    args.errors_only,
+                )
+                .await
+                .or_api_error(
+                    &client,
+                    "Failed to get pod logs",
+                    Some(args.application_name.as_str()),
+                )
+                .await?
+        };
+        if args.cluster.unwrap_or(false) || args.top_clusters.is_some() {
+            summary.cluster(args.top_clusters);
+        }
+
+        if summary.total_lines == 0 {
+            let mut no_logs_msg =
+                format!("No logs found for application '{}'", args.application_name);
+            if let Some(pod) = &args.pod_name {
+                no_logs_msg.push_str(&format!(" pod '{}'", pod));
+            }
+            if let Some(container) = &args.container {
+                no_logs_msg.push_str(&format!(" container '{}'", container));
+            }
+            if args.errors_only {
+                no_logs_msg.push_str(" (no errors or warnings detected)");
+            }
+            if let Some(follow) = &summary.follow {
+                no_logs_msg.push('\n');
+                push_follow_summary(&mut no_logs_msg, follow);
+            }
+
+            Ok(CallToolResult::success(vec![Content::text(no_logs_msg)]))
+        } else {
+            // Format as readable text
+            let mut output = format!("Pod Logs for application '{}'\n", args.application_name);
+
+            if let Some(pod) = &summary.pod_name {
+                output.push_str(&format!("Pod: {}\n", pod));
+            }
+            if let Some(container) = &summary.container {
+                output.push_str(&format!("Container: {}\n", container));
+            }
+            if let Some(tail) = summary.tail_lines {
+                output.push_str(&format!("Tail Lines: {}\n", tail));
+            }
+            if let Some(follow) = &summary.follow {
+                push_follow_summary(&mut output, follow);
+            }
+
+            push_log_analysis(&mut output, &summary);
+
+            // Add helpful notes
+            if !summary.filtered && (summary.error_count > 0 || summary.potential_issue_count > 0) {
+                output.push_str(&format!(
+                    "\n💡 Tip: Use 'errors_only: true' to filter {} errors and {} potential issues\n",
+                    summary.error_count, summary.potential_issue_count
+                ));
+            }
+
+            if summary.follow.is_none()
+                && summary.total_lines as i64 >= self.limits.default_tail_lines
+                && tail_lines == Some(self.limits.default_tail_lines)
+            {
+                output.push_str("\n💡 Tip: Increase 'tail_lines' to see more logs or use 'since_seconds' for time-based filtering\n");
+            }
+
+            // Also include JSON for structured consumption
+            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+            })?;
+
+            Ok(CallToolResult::success(vec![
+                Content::text(output),
+                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+            ]))
+        }
+    }
+
+    /// Get the merged logs of every pod of a workload
+    #[tool(
+        description = "Get the logs of every pod of a Deployment, StatefulSet, DaemonSet, Rollout or other workload in an ArgoCD application. Pods are found through the application's resource tree and fetched concurrently (up to 20, unhealthy pods first); lines are interleaved by timestamp and labelled with their pod. Reports which replicas are erroring, warning or healthy, so a single bad replica stands out. Use 'errors_only' to keep only the problem lines; the per-replica counts still cover every line. Use 'cluster' or 'top_clusters' to collapse repeated lines into patterns."
+    )]
+    async fn workload_logs(
+        &self,
+        Parameters(args): Parameters<WorkloadLogsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Apply the configured default (context-efficient) and cap tail_lines per pod
+        let tail_lines = Some(
+            args.tail_lines
+                .unwrap_or(self.limits.default_tail_lines)
+                .min(self.limits.max_tail_lines),
+        );
+
+        // Call ArgoCD API
+        let query = PodLogsQuery {
+            application_name: args.application_name.clone(),
+            namespace: args.namespace,
+            container: args.container,
+            since_seconds: args.since_seconds,
+            tail_lines,
+            previous: args.previous,
+            filter: args.filter,
+            kind: Some(args.kind.clone()),
+            resource_name: Some(args.resource_name.clone()),
+            app_namespace: args.app_namespace,
+            project: args.project,
+            ..PodLogsQuery::default()
+        };
+        let mut summary = client
+            .workload_logs(query, args.errors_only)
+            .await
+            .or_api_error(
+                &client,
+                "Failed to get workload logs",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+        if args.cluster.unwrap_or(false) || args.top_clusters.is_some() {
+            summary.cluster(args.top_clusters);
+        }
+
+        // Format as readable text
+        let mut output = format!(
+            "Logs of {} '{}' in application '{}'\n",
+            args.kind, args.resource_name, args.application_name
+        );
+        if let Some(container) = &summary.container {
+            output.push_str(&format!("Container: {}\n", container));
+        }
+        if let Some(tail) = summary.tail_lines {
+            output.push_str(&format!("Tail Lines: {} per pod\n", tail));
+        }
+        if let Some(workload) = &summary.workload {
+            push_replica_summary(&mut output, workload);
+        }
+
+        if summary.total_lines == 0 {
+            output.push_str("\nNo log lines");
+            if args.errors_only {
+                output.push_str(" (no errors or warnings detected)");
+            }
+            output.push('\n');
+        } else {
+            push_log_analysis(&mut output, &summary);
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Get application manifests
+    #[tool(
+        description = "Get Kubernetes manifests for an ArgoCD application. Returns parsed YAML/JSON manifests with metadata including kind, API version, name, and namespace. Useful for reviewing what will be deployed, validating configurations, and understanding application structure."
+    )]
+    async fn get_manifests(
+        &self,
+        Parameters(args): Parameters<GetManifestsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // ArgoCD pairs each source position with the revision at the same index
+        let positions = args.source_positions.as_ref().map_or(0, Vec::len);
+        let revisions = args.revisions.as_ref().map_or(0, Vec::len);
+        if positions != revisions {
+            return Err(McpError::invalid_params(
+                format!(
+                    "source_positions ({}) and revisions ({}) must have the same length",
+                    positions, revisions
+                ),
+                None,
+            ));
+        }
+
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .get_manifests(
+                args.application_name.clone(),
+                args.revision,
+                args.app_namespace,
+                args.project,
+                args.source_positions,
+                args.revisions,
+            )
+            .await
+            .or_api_error(&client, "Failed to get manifests", Some(args.application_name.as_str()))
+            .await?;
+
+        if summary.total_manifests == 0 {
+            Ok(CallToolResult::success(vec![Content::text(format!(
+                "No manifests found for application '{}'",
+                args.application_name
+            ))]))
+        } else {
+            // Format as readable text
+            let mut output = format!("Manifests for application '{}'\n", args.application_name);
+
+            if let Some(rev) = &summary.revision {
+                output.push_str(&format!("Revision: {}\n", rev));
+            }
+            if let Some(ns) = &summary.namespace {
+                output.push_str(&format!("Namespace: {}\n", ns));
+            }
+            if let Some(server) = &summary.server {
+                output.push_str(&format!("Server: {}\n", server));
+            }
+            if let Some(source_type) = &summary.source_type {
+                output.push_str(&format!("Source Type: {}\n", source_type));
+            }
+
+            output.push_str(&format!("\nTotal manifests: {}\n", summary.total_manifests));
+
+            // Manifests by kind
+            if !summary.manifests_by_kind.is_empty() {
+                output.push_str("\nManifests by Kind:\n");
+                let mut kinds: Vec<_> = summary.manifests_by_kind.iter().collect();
+                kinds.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
+                for (kind, count) in kinds {
+                    output.push_str(&format!("  {}: {}\n", kind, count));
+                }
+            }
+
+            // Commands used
+            if let Some(commands) = &summary.commands {
+                if !commands.is_empty() {
+                    output.push_str(&format!(
+                        "\nCommands used to generate manifests ({}):\n",
+                        commands.len()
+                    ));
+                    for (idx, cmd) in commands.iter().enumerate() {
+                        output.push_str(&format!("  {}. {}\n", idx + 1, cmd));
+                    }
+                }
+            }
+
+            // List manifests
+            output.push_str(&format!(
+                "\n📄 Manifest Summaries ({})\n",
+                summary.total_manifests
+            ));
+            output.push_str(&"─".repeat(80));
+            output.push('\n');
+
+            for (idx, manifest) in summary.manifests.iter().enumerate() {
+                output.push_str(&format!(
+                    "\n{}. {} - {}/{}\n",
+                    idx + 1,
+                    manifest.kind,
+                    manifest.api_version,
+                    manifest.name
+                ));
+
+                if let Some(ns) = &manifest.namespace {
+                    output.push_str(&format!("   Namespace: {}\n", ns));
+                }
+
+                // Show first few lines of YAML for context (limited to prevent overflow)
+                let lines: Vec<&str> = manifest.raw_yaml.lines().collect();
+                let preview_lines = std::cmp::min(5, lines.len());
+                output.push_str("   Preview:\n");
+                for line in lines.iter().take(preview_lines) {
+                    output.push_str(&format!("   {}\n", line));
+                }
+                if lines.len() > preview_lines {
+                    output.push_str(&format!(
+                        "   ... ({} more lines)\n",
+                        lines.len() - preview_lines
+                    ));
+                }
+            }
+
+            output.push('\n');
+            output.push_str(&"─".repeat(80));
+            output.push('\n');
+
+            // Add helpful note
+            output.push_str(
+                "\n💡 Tip: Use this to review what will be deployed and validate configurations\n",
+            );
+
+            // Also include JSON for structured consumption
+            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+            })?;
+
+            Ok(CallToolResult::success(vec![
+                Content::text(output),
+                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+            ]))
+        }
+    }
+
+    /// Get revision metadata
+    #[tool(
+        description = "Get metadata (author, date, message, tags) for a specific revision of an ArgoCD application. Returns commit information including author, timestamp, commit message, associated Git tags, and signature verification status. Useful for tracking changes, auditing deployments, and understanding revision history."
+    )]
+    async fn revision_metadata(
+        &self,
+        Parameters(args): Parameters<RevisionMetadataArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .revision_metadata(
+                args.application_name.clone(),
+                args.revision.clone(),
+                args.app_namespace,
+                args.project,
+                args.source_index,
+                args.version_id,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to get revision metadata",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Revision Metadata for application '{}' at revision '{}'\n\n",
+            args.application_name, args.revision
+        );
+
+        if let Some(author) = &summary.author {
+            output.push_str(&format!("Author: {}\n", author));
+        }
+        if let Some(date) = &summary.date {
+            output.push_str(&format!("Date: {}\n", date));
+        }
+
+        // Show short message first
+        if let Some(msg_short) = &summary.message_short {
+            output.push_str(&format!("\nCommit Message:\n  {}\n", msg_short));
+        }
+
+        // Show full message if it's multi-line
+        if let Some(msg_full) = &summary.message_full {
+            let lines: Vec<&str> = msg_full.lines().collect();
+            if lines.len() > 1 {
+                output.push_str("\nFull Message:\n");
+                for line in lines {
+                    output.push_str(&format!("  {}\n", line));
+                }
+            }
+        }
+
+        // Tags information
+        if summary.tag_count > 0 {
+            output.push_str(&format!("\nTags ({}):\n", summary.tag_count));
+            if let Some(tags) = &summary.tags {
+                for tag in tags {
+                    output.push_str(&format!("  - {}\n", tag));
+                }
+            }
+        } else {
+            output.push_str("\nTags: None\n");
+        }
+
+        // Signature information
+        output.push_str("\nSignature Status: ");
+        if summary.is_signed {
+            if let Some(sig_summary) = &summary.signature_summary {
+                output.push_str(&format!("{}\n", sig_summary));
+            } else {
+                output.push_str("Signed\n");
+            }
+        } else {
+            output.push_str("Not signed\n");
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Get application sync windows
+    #[tool(
+        description = "Get synchronization windows for an ArgoCD application. Returns a list of configured sync windows, including their schedule, duration, and affected applications/namespaces/clusters. Useful for understanding when an application can be synced or is blocked from syncing. NOTE: This feature requires ArgoCD v2.6+. If unavailable, a 404 error will be returned."
+    )]
+    async fn get_application_sync_windows(
+        &self,
+        Parameters(args): Parameters<GetApplicationSyncWindowsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .get_application_sync_windows(
+                args.application_name.clone(),
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to get application sync windows",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        if summary.total_windows == 0 {
+            Ok(CallToolResult::success(vec![Content::text(format!(
+                "No sync windows found for application '{}'",
+                args.application_name
+            ))]))
+        } else {
+            // Format as readable text
+            let mut output = format!(
+                "Sync Windows for application '{}' ({} total):\n\n",
+                args.application_name, summary.total_windows
+            );
+
+            for (idx, window) in summary.windows.iter().enumerate() {
+                output.push_str(&format!(
+                    "{}. Kind: {}\n",
+                    idx + 1,
+                    window.kind.as_deref().unwrap_or("Unknown")
+                ));
+                if let Some(schedule) = &window.schedule {
+                    output.push_str(&format!("   Schedule: {}\n", schedule));
+                }
+                if let Some(duration) = &window.duration {
+                    output.push_str(&format!("   Duration: {}\n", duration));
+                }
+                if let Some(start_time) = &window.start_time {
+                    output.push_str(&format!("   Start Time: {}\n", start_time));
+                }
+                if let Some(end_time) = &window.end_time {
+                    output.push_str(&format!("   End Time: {}\n", end_time));
+                }
+                if let Some(manual_sync_enabled) = window.manual_sync_enabled {
+                    output.push_str(&format!(
+                        "   Manual Sync Enabled: {}\n",
+                        manual_sync_enabled
+                    ));
+                }
+                if let Some(apps) = &window.applications {
+                    if !apps.is_empty() {
+                        output.push_str(&format!("   Applications: {}\n", apps.join(", ")));
+                    }
+                }
+                if let Some(namespaces) = &window.namespaces {
+                    if !namespaces.is_empty() {
+                        output.push_str(&format!("   Namespaces: {}\n", namespaces.join(", ")));
+                    }
+                }
+                if let Some(clusters) = &window.clusters {
+                    if !clusters.is_empty() {
+                        output.push_str(&format!("   Clusters: {}\n", clusters.join(", ")));
+                    }
+                }
+                output.push('\n');
+            }
+
+            // Also include JSON for structured consumption
+            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+            })?;
+
+            Ok(CallToolResult::success(vec![
+                Content::text(output),
                 Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
             ]))
         }
     }
 
-    /// List only application names for efficient name lookup and typo correction
+    /// Rollback an application to a previous deployed version by History ID
+    #[tool(
+        description = "Rollback an ArgoCD application to a previous deployed version by History ID. This operation reverts the application to a specific point in its deployment history. Use dry_run mode to preview changes before applying. Use prune to remove resources that were removed in the target revision. Returns the application state after rollback including sync status, health status, and the revision that was rolled back to. Set wait=true to block until the rollback has finished and the application is healthy (see wait_for_application)."
+    )]
+    async fn rollback_application(
+        &self,
+        Parameters(args): Parameters<RollbackApplicationArgs>,
+        peer: Peer<RoleServer>,
+        meta: Meta,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot rollback application in read-only mode. This operation modifies application state.",
+                None,
+            ));
+        }
+
+        // Remember the last operation so waiting skips it. A dry run starts
+        // no operation, so there is nothing to wait for
+        let wait = args.wait.unwrap_or(false) && !args.dry_run.unwrap_or(false);
+        let previous_operation = if wait {
+            operation_started_at(
+                &client,
+                &args.application_name,
+                args.app_namespace.clone(),
+                args.project.clone(),
+            )
+            .await?
+        } else {
+            None
+        };
+
+        // Call ArgoCD API
+        let summary = client
+            .rollback_application(
+                args.application_name.clone(),
+                args.id,
+                args.dry_run,
+                args.prune,
+                args.app_namespace.clone(),
+                args.project.clone(),
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to rollback application",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Rollback {} for application '{}'\n\n",
+            if summary.dry_run {
+                "(Dry Run)"
+            } else {
+                "Completed"
+            },
+            summary.name
+        );
+
+        output.push_str(&format!(
+            "Rolled back to History ID: {}\n",
+            summary.rolled_back_to_id
+        ));
+
+        if let Some(target_rev) = &summary.target_revision {
+            output.push_str(&format!("Target Revision: {}\n", target_rev));
+        }
+
+        if let Some(sync_rev) = &summary.sync_revision {
+            output.push_str(&format!("Current Sync Revision: {}\n", sync_rev));
+        }
+        if !summary.sources.is_empty() {
+            output.push_str(&format!("Sources ({}):\n", summary.sources.len()));
+            push_sources(&mut output, "  ", &summary.sources);
+        }
+
+        output.push_str("\nStatus:\n");
+        if let Some(sync_status) = &summary.sync_status {
+            output.push_str(&format!("  Sync Status: {}\n", sync_status));
+        }
+        if let Some(health_status) = &summary.health_status {
+            output.push_str(&format!("  Health Status: {}\n", health_status));
+        }
+
+        output.push_str("\nOptions:\n");
+        output.push_str(&format!("  Dry Run: {}\n", summary.dry_run));
+        output.push_str(&format!("  Prune Enabled: {}\n", summary.prune_enabled));
+
+        if summary.dry_run {
+            output.push_str("\n⚠️  Note: This was a dry run. No actual changes were made.\n");
+            output.push_str("    Run without dry_run=true to perform the actual rollback.\n");
+            if args.wait.unwrap_or(false) {
+                output.push_str("    wait=true was ignored: a dry run starts no operation.\n");
+            }
+        } else {
+            output.push_str("\n✅ Rollback completed successfully.\n");
+            if !wait {
+                output.push_str(
+                    "    Monitor the application to ensure it reaches the desired state.\n",
+                );
+            }
+        }
+
+        // Wait for the rollback operation to finish when asked to
+        let wait_summary = if wait {
+            let conditions = WaitConditions {
+                operation_requested: true,
+                previous_operation_started_at: previous_operation,
+                ..WaitConditions::new(false, false, false)
+            };
+            let wait_summary = wait_with_progress(
+                &client,
+                &args.application_name,
+                args.app_namespace,
+                args.project,
+                &conditions,
+                args.wait_timeout_seconds,
+                None,
+                &peer,
+                &meta,
+            )
+            .await?;
+            output.push('\n');
+            push_wait_summary(&mut output, &wait_summary);
+            Some(wait_summary)
+        } else {
+            None
+        };
+
+        // Also include JSON for structured consumption
+        let json_data = json_with_wait(&summary, wait_summary.as_ref())?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Delete a resource managed by an application
+    #[tool(
+        description = "Delete a Kubernetes resource from an ArgoCD application, e.g. a stuck Job or an orphaned Pod. Takes the same resource fields as get_resource. Runs as a dry run unless dry_run=false is passed: the preview shows the resource's manifest summary and the child resources (from the resource tree) that would be deleted with it, or left running with orphan=true. force skips graceful termination. Note that ArgoCD recreates resources still defined in Git on the next sync. Blocked in read-only mode."
+    )]
+    async fn delete_resource(
+        &self,
+        Parameters(args): Parameters<DeleteResourceArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        let resource = args.resource;
+
+        // Resolve the client for the requested context
+        let client = self.client_for(resource.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot delete resource in read-only mode. This operation modifies resource state.",
+                None,
+            ));
+        }
+
+        // Call ArgoCD API
+        let summary = client
+            .delete_resource(
+                resource.application_name.clone(),
+                resource.namespace,
+                resource.resource_name,
+                resource.version,
+                resource.group,
+                resource.kind,
+                args.force,
+                args.orphan,
+                args.dry_run.unwrap_or(true),
+                resource.app_namespace,
+                resource.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to delete resource",
+                Some(resource.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let target = &summary.resource;
+        let mut output = format!(
+            "Delete {} for {} '{}' (application '{}')\n\n",
+            if summary.dry_run {
+                "(Dry Run)"
+            } else {
+                "Completed"
+            },
+            target.kind,
+            target.resource_name,
+            target.app_name
+        );
+        if let Some(ns) = &target.namespace {
+            output.push_str(&format!("Namespace: {}\n", ns));
+        }
+        if let Some(api_version) = &target.manifest_summary.api_version {
+            output.push_str(&format!("API Version: {}\n", api_version));
+        }
+        if let Some(created) = &target.manifest_summary.creation_timestamp {
+            output.push_str(&format!("Created: {}\n", created));
+        }
+        if let Some(status) = &target.manifest_summary.status_summary {
+            output.push_str(&format!("Status: {}\n", status));
+        }
+
+        if summary.children.is_empty() {
+            output.push_str("\nChild Resources: none\n");
+        } else {
+            output.push_str(&format!(
+                "\nChild Resources ({}) {}:\n",
+                summary.children.len(),
+                match (summary.orphan, summary.dry_run) {
+                    (false, true) => "that would be deleted",
+                    (false, false) => "deleted",
+                    (true, true) => "that would be left running",
+                    (true, false) => "left running",
+                }
+            ));
+            for child in &summary.children {
+                output.push_str(&format!("  - {}/{}", child.kind, child.name));
+                if let Some(health) = &child.health_status {
+                    output.push_str(&format!(" ({})", health));
+                }
+                output.push('\n');
+            }
+        }
+
+        output.push_str("\nOptions:\n");
+        output.push_str(&format!("  Force: {}\n", summary.force));
+        output.push_str(&format!("  Orphan: {}\n", summary.orphan));
+
+        if summary.dry_run {
+            output.push_str("\n⚠️  Note: This was a dry run. No actual changes were made.\n");
+            output.push_str("    Run with dry_run=false to delete the resource.\n");
+        } else {
+            output.push_str("\n✅ Resource deletion requested.\n");
+            output.push_str(
+                "    Resources still defined in Git are recreated on the next sync.\n",
+            );
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// List the actions available on a resource
+    #[tool(
+        description = "List the resource actions ArgoCD offers for a Kubernetes resource in an application, such as Deployment restart, Rollout promote/abort/retry, CronJob create-job, or custom Lua actions. Takes the same resource fields as get_resource. Actions that do not apply to the resource's current state are marked disabled. Use run_resource_action to run one."
+    )]
+    async fn list_resource_actions(
+        &self,
+        Parameters(args): Parameters<GetResourceArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .list_resource_actions(
+                args.application_name.clone(),
+                args.namespace,
+                args.resource_name,
+                args.version,
+                args.group,
+                args.kind,
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to list resource actions",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Resource Actions for {} '{}' (application '{}')\n\n",
+            summary.kind, summary.resource_name, summary.application_name
+        );
+        if summary.actions.is_empty() {
+            output.push_str("No actions are defined for this resource kind.\n");
+        } else {
+            output.push_str(&format!(
+                "Actions: {} available, {} disabled\n\n",
+                summary.available_count, summary.disabled_count
+            ));
+            for action in &summary.actions {
+                output.push_str(&format!(
+                    "  {} {}",
+                    if action.disabled { "⛔" } else { "✅" },
+                    action.name
+                ));
+                if action.disabled {
+                    output.push_str(" (disabled)");
+                }
+                if !action.params.is_empty() {
+                    output.push_str(&format!(" [params: {}]", action.params.join(", ")));
+                }
+                output.push('\n');
+            }
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Run an action on a resource
+    #[tool(
+        description = "Run a resource action on a Kubernetes resource in an application, e.g. 'restart' on a Deployment, 'resume' or 'promote-full' on an Argo Rollout, 'create-job' on a CronJob, or a custom Lua action. Takes the same resource fields as get_resource plus the action name; parameterized actions take action_parameters (ArgoCD v2.14+). The action is checked against list_resource_actions first and refused if unknown or disabled. Blocked in read-only mode."
+    )]
+    async fn run_resource_action(
+        &self,
+        Parameters(args): Parameters<RunResourceActionArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        let resource = args.resource;
+
+        // Resolve the client for the requested context
+        let client = self.client_for(resource.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot run resource action in read-only mode. This operation modifies resource state.",
+                None,
+            ));
+        }
+
+        // Refuse unknown or disabled actions before running anything
+        let available = client
+            .list_resource_actions(
+                resource.application_name.clone(),
+                resource.namespace.clone(),
+                resource.resource_name.clone(),
+                resource.version.clone(),
+                resource.group.clone(),
+                resource.kind.clone(),
+                resource.app_namespace.clone(),
+                resource.project.clone(),
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to list resource actions",
+                Some(resource.application_name.as_str()),
+            )
+            .await?;
+        match available.actions.iter().find(|a| a.name == args.action) {
+            None => {
+                let names: Vec<&str> = available.actions.iter().map(|a| a.name.as_str()).collect();
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Action '{}' is not available on {} '{}'. Available actions: {}",
+                        args.action,
+                        resource.kind,
+                        resource.resource_name,
+                        if names.is_empty() {
+                            "none".to_string()
+                        } else {
+                            names.join(", ")
+                        }
+                    ),
+                    None,
+                ));
+            }
+            Some(action) if action.disabled => {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Action '{}' is disabled for {} '{}' in its current state",
+                        args.action, resource.kind, resource.resource_name
+                    ),
+                    None,
+                ));
+            }
+            Some(_) => {}
+        }
+
+        let mut parameters: Vec<(String, String)> =
+            args.action_parameters.unwrap_or_default().into_iter().collect();
+        parameters.sort();
+
+        // Call ArgoCD API
+        let summary = client
+            .run_resource_action(
+                resource.application_name.clone(),
+                resource.namespace,
+                resource.resource_name,
+                resource.version,
+                resource.group,
+                resource.kind,
+                args.action,
+                parameters,
+                resource.app_namespace,
+                resource.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to run resource action",
+                Some(resource.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Action '{}' run on {} '{}' (application '{}')\n",
+            summary.action, summary.kind, summary.resource_name, summary.application_name
+        );
+        if let Some(ns) = &summary.namespace {
+            output.push_str(&format!("Namespace: {}\n", ns));
+        }
+        if !summary.parameters.is_empty() {
+            output.push_str(&format!("Parameters: {}\n", summary.parameters.join(", ")));
+        }
+        output.push_str("\n✅ Action completed.\n");
+        output.push_str("    Use get_resource or resource_tree to follow its effect.\n");
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Terminate an application's running operation
+    #[tool(
+        description = "Terminate the running operation (sync or rollback) of an ArgoCD application, e.g. a sync stuck waiting on a hook. Reports the operation phase before and right after the request; nothing is sent when no operation is in progress. The application stays OutOfSync until it is synced again. Blocked in read-only mode."
+    )]
+    async fn terminate_operation(
+        &self,
+        Parameters(args): Parameters<TerminateOperationArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot terminate operation in read-only mode. This operation modifies application state.",
+                None,
+            ));
+        }
+
+        // Call ArgoCD API
+        let summary = client
+            .terminate_operation(
+                args.application_name.clone(),
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to terminate operation",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!("Terminate Operation for application '{}'\n\n", summary.name);
+        output.push_str(&format!(
+            "Phase Before: {}\n",
+            summary.phase_before.as_deref().unwrap_or("(no operation)")
+        ));
+        if let Some(message) = &summary.message_before {
+            output.push_str(&format!("  Message: {}\n", message));
+        }
+        if let Some(started_at) = &summary.started_at {
+            output.push_str(&format!("  Started At: {}\n", started_at));
+        }
+        output.push_str(&format!(
+            "Phase After: {}\n",
+            summary.phase_after.as_deref().unwrap_or("(no operation)")
+        ));
+        if let Some(message) = &summary.message_after {
+            output.push_str(&format!("  Message: {}\n", message));
+        }
+
+        if summary.terminated {
+            output.push_str("\n✅ Termination requested.\n");
+            output.push_str(
+                "    Check get_application until the phase leaves Terminating, then sync again if needed.\n",
+            );
+        } else {
+            output.push_str("\nℹ️  No operation is in progress; nothing was terminated.\n");
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Create an application from a full spec
+    #[tool(
+        description = "Create an ArgoCD application from a full spec (source or sources, destination, project, sync policy, ignoreDifferences). Set upsert to replace the spec of an existing application with the same name, and validate=false to skip ArgoCD's repository and destination checks. Use dry_run to check the spec and whether the application already exists without creating anything. Blocked in read-only mode."
+    )]
+    async fn create_application(
+        &self,
+        Parameters(args): Parameters<CreateApplicationArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot create application in read-only mode. This operation modifies application state.",
+                None,
+            ));
+        }
+
+        let application = Application {
+            metadata: Some(ObjectMeta {
+                name: args.application_name.clone(),
+                namespace: args.app_namespace,
+                labels: args.labels,
+                annotations: args.annotations,
+                ..ObjectMeta::default()
+            }),
+            spec: Some(args.spec),
+            status: None,
+        };
+
+        // Call ArgoCD API
+        let summary = client
+            .create_application(application, args.upsert, args.validate, args.dry_run)
+            .await
+            .or_api_error(&client, "Failed to create application", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Create {} for application '{}'\n\n",
+            if summary.dry_run {
+                "(Dry Run)"
+            } else {
+                "Completed"
+            },
+            summary.name
+        );
+        if let Some(namespace) = &summary.namespace {
+            output.push_str(&format!("Namespace: {}\n", namespace));
+        }
+        push_spec_summary(&mut output, &summary.spec);
+
+        output.push_str("\nOptions:\n");
+        output.push_str(&format!("  Upsert: {}\n", summary.upsert));
+        output.push_str(&format!("  Validate: {}\n", summary.validate));
+
+        if summary.dry_run {
+            if let Some(exists) = summary.already_exists {
+                output.push_str(&format!("\nAlready Exists: {}\n", exists));
+            }
+            push_problems(&mut output, &summary.problems, "create_application");
+        } else {
+            output.push_str("\n✅ Application created.\n");
+            output.push_str(
+                "    Use sync_application to deploy it unless automated sync is enabled.\n",
+            );
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Replace an application's spec
+    #[tool(
+        description = "Replace the spec of an existing ArgoCD application. The given spec replaces the current one entirely, so include unchanged fields (get_application shows the current state). Use dry_run to see which top-level fields would change and whether the new spec is complete. Set validate=false to skip ArgoCD's repository and destination checks. Blocked in read-only mode."
+    )]
+    async fn update_application_spec(
+        &self,
+        Parameters(args): Parameters<UpdateApplicationSpecArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot update application spec in read-only mode. This operation modifies application state.",
+                None,
+            ));
+        }
+
+        // Call ArgoCD API
+        let summary = client
+            .update_application_spec(
+                args.application_name.clone(),
+                args.spec,
+                args.validate,
+                args.dry_run,
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to update application spec",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Spec Update {} for application '{}'\n\n",
+            if summary.dry_run {
+                "(Dry Run)"
+            } else {
+                "Completed"
+            },
+            summary.name
+        );
+        if summary.changed_fields.is_empty() {
+            output.push_str("Changed Fields: none\n");
+        } else {
+            output.push_str(&format!(
+                "Changed Fields: {}\n",
+                summary.changed_fields.join(", ")
+            ));
+        }
+        push_spec_summary(&mut output, &summary.spec);
+        output.push_str(&format!("\nValidate: {}\n", summary.validate));
+
+        if summary.dry_run {
+            push_problems(&mut output, &summary.problems, "update_application_spec");
+        } else {
+            output.push_str("\n✅ Application spec updated.\n");
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Delete an application
+    #[tool(
+        description = "Delete an ArgoCD application. By default (cascade=true) its Kubernetes resources are deleted too; cascade=false leaves them running, unmanaged. propagation_policy ('foreground' or 'background') controls how cascading deletes remove resources. Use dry_run first to see how many resources, by kind, a cascading delete would remove. Blocked in read-only mode."
+    )]
+    async fn delete_application(
+        &self,
+        Parameters(args): Parameters<DeleteApplicationArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot delete application in read-only mode. This operation modifies application state.",
+                None,
+            ));
+        }
+
+        if let Some(policy) = &args.propagation_policy {
+            if policy != "foreground" && policy != "background" {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "Invalid propagation_policy '{}'. Use 'foreground' or 'background'",
+                        policy
+                    ),
+                    None,
+                ));
+            }
+        }
+
+        // Call ArgoCD API
+        let summary = client
+            .delete_application(
+                args.application_name.clone(),
+                args.cascade,
+                args.propagation_policy,
+                args.dry_run,
+                args.app_namespace,
+                args.project,
+            )
+            .await
+            .or_api_error(
+                &client,
+                "Failed to delete application",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Delete {} for application '{}'\n\n",
+            if summary.dry_run {
+                "(Dry Run)"
+            } else {
+                "Completed"
+            },
+            summary.name
+        );
+        output.push_str(&format!("Cascade: {}\n", summary.cascade));
+        if let Some(policy) = &summary.propagation_policy {
+            output.push_str(&format!("Propagation Policy: {}\n", policy));
+        }
+        if let Some(count) = summary.resource_count {
+            output.push_str(&format!(
+                "\nResources ({}) {}:\n",
+                count,
+                match (summary.cascade, summary.dry_run) {
+                    (true, true) => "that would be deleted",
+                    (true, false) => "deleted",
+                    (false, true) => "that would be left running",
+                    (false, false) => "left running",
+                }
+            ));
+            for kind in &summary.resources_by_kind {
+                output.push_str(&format!("  {}\n", kind));
+            }
+        }
+
+        if summary.dry_run {
+            output.push_str("\n⚠️  Note: This was a dry run. No actual changes were made.\n");
+            output.push_str("    Run without dry_run=true to delete the application.\n");
+        } else {
+            output.push_str("\n✅ Application deletion requested.\n");
+            output.push_str("    ArgoCD removes the application once its resources are gone.\n");
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// List projects
     #[tool(
-        description = "List only ArgoCD application names. Returns a simple list of all application names, which is extremely efficient for name lookups and auto-correcting typos in application names. Use this when you need to find exact application names or verify if an application exists."
+        description = "List ArgoCD projects (AppProjects) with their description and the number of source repositories, destinations, roles and sync windows each defines. Use get_project for the full permissions of one project."
     )]
-    async fn list_application_names(
+    async fn list_projects(
         &self,
-        Parameters(args): Parameters<ListApplicationNamesArgs>,
+        Parameters(args): Parameters<ListProjectsArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
-        let names = client
-            .list_application_names(args.projects, args.selector, args.repo, args.app_namespace)
+        let projects = client
+            .list_projects(args.name)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to list application names: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to list projects", None)
+            .await?;
 
-        if names.is_empty() {
-            Ok(CallToolResult::success(vec![Content::text(
-                "No applications found matching the criteria",
-            )]))
-        } else {
-            // Format as a simple, readable list
-            let mut output = format!("Found {} application(s):\n\n", names.len());
-            for (idx, name) in names.iter().enumerate() {
-                output.push_str(&format!("{}. {}\n", idx + 1, name));
+        // Format as readable text
+        let mut output = format!("Found {} project(s)\n\n", projects.len());
+        for project in &projects {
+            output.push_str(&format!("• {}", project.name));
+            if let Some(description) = &project.description {
+                output.push_str(&format!(" - {}", description));
             }
+            output.push_str(&format!(
+                "\n  Repos: {} | Destinations: {} | Roles: {} | Sync Windows: {}\n",
+                project.source_repos_count,
+                project.destinations_count,
+                project.roles_count,
+                project.sync_windows_count
+            ));
+        }
 
-            // Also include as a simple array for easier parsing
-            output.push_str("\n--- Application Names Array ---\n");
-            output.push_str(&format!("{:?}", names));
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&projects).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            Ok(CallToolResult::success(vec![Content::text(output)]))
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Get a project's permissions, roles and sync windows
+    #[tool(
+        description = "Get an ArgoCD project (AppProject): allowed source repositories and namespaces, destinations, cluster- and namespace-scoped resource allow/deny lists, roles with their policies, groups and token metadata (issued/expiry times and IDs, never the tokens themselves), and sync windows. Useful for explaining why an application is not permitted to deploy somewhere."
+    )]
+    async fn get_project(
+        &self,
+        Parameters(args): Parameters<GetProjectArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let project = client
+            .get_project(args.project_name)
+            .await
+            .or_api_error(&client, "Failed to get project", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!("Project '{}'\n", project.name);
+        if let Some(ns) = &project.namespace {
+            output.push_str(&format!("Namespace: {}\n", ns));
         }
+        push_project_detail(&mut output, &project);
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&project).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
     }
 
-    /// Perform server-side diff calculation using dry-run apply
+    /// List events of a project
     #[tool(
-        description = "Perform server-side diff calculation for an ArgoCD application using dry-run apply. This executes a Server-Side Apply operation in dryrun mode and compares the predicted state with the live state. Returns a list of resources with their diff status, showing which resources have differences between the live and target state. NOTE: This feature requires ArgoCD v2.5+ with Server-Side Apply support. If unavailable, a 404 error will be returned."
+        description = "List Kubernetes events recorded for an ArgoCD project (AppProject), such as spec updates and role token changes. Returns event counts by type and reason and the most recent events."
     )]
-    async fn server_side_diff(
+    async fn get_project_events(
         &self,
-        Parameters(args): Parameters<ServerSideDiffArgs>,
+        Parameters(args): Parameters<GetProjectArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let summary = client
+            .get_project_events(args.project_name.clone())
+            .await
+            .or_api_error(&client, "Failed to get project events", None)
+            .await?;
+
+        if summary.total_events == 0 {
+            return Ok(CallToolResult::success(vec![Content::text(format!(
+                "No events found for project '{}'",
+                args.project_name
+            ))]));
+        }
+
+        // Format as readable text
+        let mut output = format!("Events for project '{}'\n", args.project_name);
+        output.push_str(&format!("Total events: {}\n\n", summary.total_events));
+        push_events(&mut output, &summary, self.limits.max_events);
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Create a project
+    #[tool(
+        description = "Create an ArgoCD project (AppProject) from a spec: source repositories, destinations, cluster- and namespace-scoped resource allow/deny lists, roles and sync windows. Set upsert to replace an existing project with the same name. Blocked in read-only mode."
+    )]
+    async fn create_project(
+        &self,
+        Parameters(args): Parameters<CreateProjectArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot create project in read-only mode. This operation modifies project state.",
                 None,
-            )
+            ));
+        }
+
+        // Call ArgoCD API
+        let project = client
+            .create_project(args.project_name, args.spec, args.upsert)
+            .await
+            .or_api_error(&client, "Failed to create project", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!("✅ Project '{}' created\n", project.name);
+        push_project_detail(&mut output, &project);
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&project).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
         })?;
 
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Replace a project's spec
+    #[tool(
+        description = "Replace the spec of an ArgoCD project (AppProject) and report which top-level fields changed. The new spec replaces the current one entirely, so call get_project first and include unchanged fields. Role tokens are kept. Blocked in read-only mode."
+    )]
+    async fn update_project(
+        &self,
+        Parameters(args): Parameters<UpdateProjectArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot update project in read-only mode. This operation modifies project state.",
+                None,
+            ));
+        }
+
         // Call ArgoCD API
-        let summaries = client
-            .server_side_diff(
-                args.app_name.clone(),
-                args.app_namespace,
-                args.project,
-                args.target_manifests,
-            )
+        let summary = client
+            .update_project(args.project_name, args.spec)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to perform server-side diff: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to update project", None)
+            .await?;
 
-        if summaries.is_empty() {
-            Ok(CallToolResult::success(vec![Content::text(format!(
-                "No resources found for application '{}'",
-                args.app_name
-            ))]))
+        // Format as readable text
+        let mut output = format!("✅ Project '{}' updated\n", summary.project.name);
+        if summary.changed_fields.is_empty() {
+            output.push_str("Changed fields: none\n");
         } else {
-            // Count modified resources
-            let modified_count = summaries.iter().filter(|s| s.modified).count();
-            let total_count = summaries.len();
-
-            // Format as readable text
-            let mut output = format!("Server-Side Diff for application '{}'\n", args.app_name);
             output.push_str(&format!(
-                "Total resources: {}, Modified: {}, In sync: {}\n\n",
-                total_count,
-                modified_count,
-                total_count - modified_count
+                "Changed fields: {}\n",
+                summary.changed_fields.join(", ")
             ));
+        }
+        push_project_detail(&mut output, &summary.project);
 
-            // Group by modified status
-            output.push_str("Modified Resources:\n");
-            let modified: Vec<_> = summaries.iter().filter(|s| s.modified).collect();
-            if modified.is_empty() {
-                output.push_str("  (none)\n\n");
-            } else {
-                for (idx, res) in modified.iter().enumerate() {
-                    output.push_str(&format!(
-                        "{}. {} ({})",
-                        idx + 1,
-                        res.resource_name,
-                        res.kind
-                    ));
-                    if let Some(ns) = &res.namespace {
-                        output.push_str(&format!(" in namespace '{}'", ns));
-                    }
-                    output.push('\n');
-                    if let Some(summary) = &res.diff_summary {
-                        output.push_str(&format!("   Status: {}\n", summary));
-                    }
-                }
-                output.push('\n');
-            }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            output.push_str("In Sync Resources:\n");
-            let in_sync: Vec<_> = summaries.iter().filter(|s| !s.modified).collect();
-            if in_sync.is_empty() {
-                output.push_str("  (none)\n");
-            } else {
-                for (idx, res) in in_sync.iter().enumerate() {
-                    output.push_str(&format!(
-                        "{}. {} ({})",
-                        idx + 1,
-                        res.resource_name,
-                        res.kind
-                    ));
-                    if let Some(ns) = &res.namespace {
-                        output.push_str(&format!(" in namespace '{}'", ns));
-                    }
-                    output.push('\n');
-                }
-            }
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-            // Also include JSON for structured consumption
-            let json_data = serde_json::to_string_pretty(&summaries).map_err(|e| {
-                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-            })?;
+    /// Delete a project
+    #[tool(
+        description = "Delete an ArgoCD project (AppProject). ArgoCD refuses while applications still belong to the project. Blocked in read-only mode."
+    )]
+    async fn delete_project(
+        &self,
+        Parameters(args): Parameters<GetProjectArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-            Ok(CallToolResult::success(vec![
-                Content::text(output),
-                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
-            ]))
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot delete project in read-only mode. This operation modifies project state.",
+                None,
+            ));
         }
+
+        // Call ArgoCD API
+        client
+            .delete_project(args.project_name.clone())
+            .await
+            .or_api_error(&client, "Failed to delete project", None)
+            .await?;
+
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "✅ Project '{}' deleted\n",
+            args.project_name
+        ))]))
     }
 
-    /// Get a single application by name
+    /// Create or update a project role
     #[tool(
-        description = "Get detailed information about a specific ArgoCD application by name. Returns comprehensive application details including source repository, destination cluster, sync status, health status, and sync policy configuration. Use this when you need detailed information about a specific application."
+        description = "Create a role in an ArgoCD project, or replace the description, policies and groups of an existing one (its tokens are kept). Policies use Casbin syntax, e.g. \"p, proj:my-project:ci, applications, sync, my-project/*, allow\". Blocked in read-only mode."
     )]
-    async fn get_application(
+    async fn upsert_project_role(
         &self,
-        Parameters(args): Parameters<GetApplicationArgs>,
+        Parameters(args): Parameters<UpsertProjectRoleArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot modify project role in read-only mode. This operation modifies project state.",
                 None,
-            )
-        })?;
+            ));
+        }
 
-        // Call ArgoCD API
-        let detail = client
-            .get_application(
-                args.name.clone(),
-                args.app_namespace,
-                args.project,
-                args.refresh,
-                args.resource_version,
-            )
+        let role = ProjectRole {
+            name: args.role_name,
+            description: args.description,
+            policies: args.policies,
+            jwt_tokens: None,
+            groups: args.groups,
+        };
+
+        // Call ArgoCD API
+        let summary = client
+            .upsert_project_role(args.project_name, role)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to get application: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to update project role", None)
+            .await?;
 
         // Format as readable text
-        let mut output = format!("Application: {}\n\n", detail.name);
+        let mut output = format!(
+            "✅ Role '{}' {} in project '{}'\n",
+            summary.role, summary.action, summary.project
+        );
+        push_project_roles(&mut output, &summary.roles);
 
-        if let Some(ns) = &detail.namespace {
-            output.push_str(&format!("Namespace: {}\n", ns));
-        }
-        if let Some(project) = &detail.project {
-            output.push_str(&format!("Project: {}\n", project));
-        }
-        if let Some(created) = &detail.creation_timestamp {
-            output.push_str(&format!("Created: {}\n", created));
-        }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-        output.push_str("\nSource:\n");
-        if let Some(repo) = &detail.repo_url {
-            output.push_str(&format!("  Repository: {}\n", repo));
-        }
-        if let Some(path) = &detail.path {
-            output.push_str(&format!("  Path: {}\n", path));
-        }
-        if let Some(chart) = &detail.chart {
-            output.push_str(&format!("  Chart: {}\n", chart));
-        }
-        if let Some(revision) = &detail.target_revision {
-            output.push_str(&format!("  Target Revision: {}\n", revision));
-        }
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-        output.push_str("\nDestination:\n");
-        if let Some(server) = &detail.destination_server {
-            output.push_str(&format!("  Server: {}\n", server));
-        }
-        if let Some(ns) = &detail.destination_namespace {
-            output.push_str(&format!("  Namespace: {}\n", ns));
-        }
-        if let Some(name) = &detail.destination_name {
-            output.push_str(&format!("  Name: {}\n", name));
-        }
+    /// Delete a project role
+    #[tool(
+        description = "Delete a role from an ArgoCD project. Tokens issued for the role stop working. Blocked in read-only mode."
+    )]
+    async fn delete_project_role(
+        &self,
+        Parameters(args): Parameters<DeleteProjectRoleArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-        output.push_str("\nStatus:\n");
-        if let Some(sync_status) = &detail.sync_status {
-            output.push_str(&format!("  Sync Status: {}\n", sync_status));
-        }
-        if let Some(revision) = &detail.sync_revision {
-            output.push_str(&format!("  Sync Revision: {}\n", revision));
-        }
-        if let Some(health) = &detail.health_status {
-            output.push_str(&format!("  Health Status: {}\n", health));
-        }
-        if let Some(msg) = &detail.health_message {
-            output.push_str(&format!("  Health Message: {}\n", msg));
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot modify project role in read-only mode. This operation modifies project state.",
+                None,
+            ));
         }
 
-        if detail.auto_sync_enabled.is_some()
-            || detail.auto_sync_prune.is_some()
-            || detail.auto_sync_self_heal.is_some()
-        {
-            output.push_str("\nSync Policy:\n");
-            if let Some(true) = detail.auto_sync_enabled {
-                output.push_str("  Auto Sync: Enabled\n");
-            }
-            if let Some(prune) = detail.auto_sync_prune {
-                output.push_str(&format!("  Auto Prune: {}\n", prune));
-            }
-            if let Some(self_heal) = detail.auto_sync_self_heal {
-                output.push_str(&format!("  Self Heal: {}\n", self_heal));
-            }
-        }
+        // Call ArgoCD API
+        let summary = client
+            .delete_project_role(args.project_name, args.role_name)
+            .await
+            .or_api_error(&client, "Failed to delete project role", None)
+            .await?;
 
-        if let Some(labels) = &detail.labels {
-            if !labels.is_empty() {
-                output.push_str("\nLabels:\n");
-                for (key, value) in labels {
-                    output.push_str(&format!("  {}: {}\n", key, value));
-                }
-            }
-        }
+        // Format as readable text
+        let mut output = format!(
+            "✅ Role '{}' {} from project '{}'\n",
+            summary.role, summary.action, summary.project
+        );
+        push_project_roles(&mut output, &summary.roles);
 
         // Also include JSON for structured consumption
-        let json_data = serde_json::to_string_pretty(&detail).map_err(|e| {
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
             McpError::internal_error(format!("Failed to serialize response: {}", e), None)
         })?;
 
@@ -800,96 +4534,61 @@ impl ArgocdMcpHandler {
         ]))
     }
 
-    /// Get resource tree for an ArgoCD application
+    /// Issue a token for a project role
     #[tool(
-        description = "Get the resource tree for an ArgoCD application. Returns a hierarchical view of all resources managed by the application, including Deployments, Services, Pods, ConfigMaps, and more. Provides resource counts by kind, health status summary, and sample resources. Use filters to narrow results by resource type, namespace, or other attributes."
+        description = "Issue a JWT token for a role in an ArgoCD project, e.g. for a CI pipeline. The token is returned only once and cannot be retrieved again; get_project lists only its metadata. Omit expires_in_seconds for a token that never expires. Blocked in read-only mode."
     )]
-    async fn resource_tree(
+    async fn create_project_token(
         &self,
-        Parameters(args): Parameters<ResourceTreeArgs>,
+        Parameters(args): Parameters<CreateProjectTokenArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot create project token in read-only mode. This operation modifies project state.",
                 None,
-            )
-        })?;
+            ));
+        }
 
         // Call ArgoCD API
-        let summary = client
-            .resource_tree(
-                args.application_name.clone(),
-                args.namespace,
-                args.name,
-                args.version,
-                args.group,
-                args.kind,
-                args.app_namespace,
-                args.project,
+        let token = client
+            .create_project_token(
+                args.project_name.clone(),
+                args.role_name.clone(),
+                args.description,
+                args.expires_in_seconds,
+                args.token_id,
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to get resource tree: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to create project token", None)
+            .await?;
 
         // Format as readable text
         let mut output = format!(
-            "Resource Tree for application '{}'\n",
-            args.application_name
+            "✅ Token issued for role '{}' in project '{}'\n\n",
+            args.role_name, args.project_name
         );
-        output.push_str(&format!("Total resources: {}\n", summary.total_nodes));
         output.push_str(&format!(
-            "Orphaned resources: {}\n\n",
-            summary.orphaned_nodes_count
+            "Issued: {} (iat {})\n",
+            token.issued_at_time, token.issued_at
         ));
-
-        // Resources by kind
-        output.push_str("Resources by Kind:\n");
-        let mut kinds: Vec<_> = summary.nodes_by_kind.iter().collect();
-        kinds.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
-        for (kind, count) in kinds {
-            output.push_str(&format!("  {}: {}\n", kind, count));
-        }
-        output.push('\n');
-
-        // Health summary
-        output.push_str("Health Summary:\n");
-        let mut health: Vec<_> = summary.health_summary.iter().collect();
-        health.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
-        for (status, count) in health {
-            output.push_str(&format!("  {}: {}\n", status, count));
+        if let Some(id) = &token.id {
+            output.push_str(&format!("ID: {}\n", id));
         }
-        output.push('\n');
-
-        // Sample nodes
-        if !summary.sample_nodes.is_empty() {
-            output.push_str("Sample Resources (showing up to 10):\n");
-            for (idx, node) in summary.sample_nodes.iter().enumerate() {
-                output.push_str(&format!("{}. {} ({})", idx + 1, node.name, node.kind));
-                if let Some(ns) = &node.namespace {
-                    output.push_str(&format!(" in namespace '{}'", ns));
-                }
-                if let Some(health) = &node.health_status {
-                    output.push_str(&format!(" - Health: {}", health));
-                }
-                if let Some(parent_count) = node.parent_count {
-                    if parent_count > 0 {
-                        output.push_str(&format!(" - {} parent(s)", parent_count));
-                    }
-                }
-                if let Some(images) = &node.images {
-                    if !images.is_empty() {
-                        output.push_str(&format!("\n   Images: {}", images.join(", ")));
-                    }
-                }
-                output.push('\n');
-            }
+        output.push_str(&format!(
+            "Expires: {}\n",
+            token.expires_at_time.as_deref().unwrap_or("never")
+        ));
+        if let Some(value) = &token.token {
+            output.push_str(&format!("\nToken: {}\n", value));
         }
+        output.push_str("\n⚠️  Note: The token is shown only once. Store it securely now.\n");
 
         // Also include JSON for structured consumption
-        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+        let json_data = serde_json::to_string_pretty(&token).map_err(|e| {
             McpError::internal_error(format!("Failed to serialize response: {}", e), None)
         })?;
 
@@ -899,543 +4598,552 @@ impl ArgocdMcpHandler {
         ]))
     }
 
-    /// List resource events for an ArgoCD application
+    /// Revoke a project role token
     #[tool(
-        description = "List Kubernetes events for an ArgoCD application or specific resource within an application. Returns event details including type (Normal/Warning), reason, message, timestamps, and involved objects. Use filters to narrow results by resource name, namespace, or UID. Events provide insights into application lifecycle, deployments, and issues. NOTE: If no events are found or the response format is unexpected, an empty list will be returned with appropriate logging."
+        description = "Revoke a JWT token of a role in an ArgoCD project. Identify the token by its issued_at time and, if it has one, its token_id, both listed by get_project. Blocked in read-only mode."
     )]
-    async fn list_resource_events(
+    async fn delete_project_token(
         &self,
-        Parameters(args): Parameters<ListResourceEventsArgs>,
+        Parameters(args): Parameters<DeleteProjectTokenArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot delete project token in read-only mode. This operation modifies project state.",
                 None,
-            )
-        })?;
+            ));
+        }
 
         // Call ArgoCD API
-        let summary = client
-            .list_resource_events(
-                args.application_name.clone(),
-                args.resource_namespace,
-                args.resource_name,
-                args.resource_uid,
-                args.app_namespace,
-                args.project,
+        client
+            .delete_project_token(
+                args.project_name.clone(),
+                args.role_name.clone(),
+                args.issued_at,
+                args.token_id,
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to list resource events: {}", e), None)
-            })?;
-
-        if summary.total_events == 0 {
-            Ok(CallToolResult::success(vec![Content::text(format!(
-                "No events found for application '{}'",
-                args.application_name
-            ))]))
-        } else {
-            // Format as readable text
-            let mut output = format!("Events for application '{}'\n", args.application_name);
-            output.push_str(&format!("Total events: {}\n\n", summary.total_events));
-
-            // Event counts by type
-            if !summary.events_by_type.is_empty() {
-                output.push_str("Events by Type:\n");
-                let mut types: Vec<_> = summary.events_by_type.iter().collect();
-                types.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
-                for (event_type, count) in types {
-                    output.push_str(&format!("  {}: {}\n", event_type, count));
-                }
-                output.push('\n');
-            }
-
-            // Event counts by reason
-            if !summary.events_by_reason.is_empty() {
-                output.push_str("Events by Reason:\n");
-                let mut reasons: Vec<_> = summary.events_by_reason.iter().collect();
-                reasons.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
-                for (reason, count) in reasons.iter().take(10) {
-                    output.push_str(&format!("  {}: {}\n", reason, count));
-                }
-                if reasons.len() > 10 {
-                    output.push_str(&format!("  ... and {} more\n", reasons.len() - 10));
-                }
-                output.push('\n');
-            }
+            .or_api_error(&client, "Failed to delete project token", None)
+            .await?;
 
-            // List individual events (limited to prevent context overflow)
-            let event_limit = 20;
-            output.push_str(&format!("Recent Events (showing up to {}):\n", event_limit));
-            for (idx, event) in summary.events.iter().take(event_limit).enumerate() {
-                output.push_str(&format!("\n{}. ", idx + 1));
-
-                if let Some(event_type) = &event.event_type {
-                    output.push_str(&format!("[{}] ", event_type));
-                }
+        Ok(CallToolResult::success(vec![Content::text(format!(
+            "✅ Token issued at {} revoked for role '{}' in project '{}'\n",
+            args.issued_at, args.role_name, args.project_name
+        ))]))
+    }
 
-                if let Some(reason) = &event.reason {
-                    output.push_str(&format!("{}", reason));
-                }
+    /// List clusters registered in ArgoCD
+    #[tool(
+        description = "List the clusters registered in ArgoCD with their server URL, name, connection state, Kubernetes version, cache info (resource and API counts, last sync), namespace scope, and the applications deployed to each (matched by application destination). Cluster credentials are never returned. A cluster no application targets may show connection state Unknown."
+    )]
+    async fn list_clusters(
+        &self,
+        Parameters(args): Parameters<ListClustersArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-                if let Some(involved_kind) = &event.involved_object_kind {
-                    output.push_str(&format!(" - {}", involved_kind));
-                    if let Some(involved_name) = &event.involved_object_name {
-                        output.push_str(&format!("/{}", involved_name));
-                    }
-                }
+        // Call ArgoCD API
+        let clusters = client
+            .list_clusters()
+            .await
+            .or_api_error(&client, "Failed to list clusters", None)
+            .await?;
 
-                output.push('\n');
+        // Format as readable text
+        let mut output = format!("Found {} cluster(s)\n", clusters.len());
+        for cluster in &clusters {
+            output.push_str(&format!(
+                "\n• {} ({})\n",
+                cluster.name.as_deref().unwrap_or("(unnamed)"),
+                cluster.server.as_deref().unwrap_or("no server")
+            ));
+            push_cluster_summary(&mut output, cluster);
+        }
 
-                if let Some(message) = &event.message {
-                    output.push_str(&format!("   Message: {}\n", message));
-                }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&clusters).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-                if let Some(count) = event.count {
-                    if count > 1 {
-                        output.push_str(&format!("   Count: {}\n", count));
-                    }
-                }
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-                if let Some(first_ts) = &event.first_timestamp {
-                    output.push_str(&format!("   First: {}", first_ts));
-                    if let Some(last_ts) = &event.last_timestamp {
-                        if first_ts != last_ts {
-                            output.push_str(&format!(" | Last: {}", last_ts));
-                        }
-                    }
-                    output.push('\n');
-                }
+    /// Get a cluster registered in ArgoCD
+    #[tool(
+        description = "Get a cluster registered in ArgoCD by server URL or name: connection state and last connection attempt, Kubernetes version, cache info, namespace scope, shard, labels, and the applications deployed to it. Cluster credentials are never returned."
+    )]
+    async fn get_cluster(
+        &self,
+        Parameters(args): Parameters<GetClusterArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-                if let Some(source) = &event.source_component {
-                    output.push_str(&format!("   Source: {}\n", source));
-                }
-            }
+        // Call ArgoCD API
+        let cluster = client
+            .get_cluster(args.cluster)
+            .await
+            .or_api_error(&client, "Failed to get cluster", None)
+            .await?;
 
-            if summary.total_events > event_limit {
-                output.push_str(&format!(
-                    "\n... and {} more events (total: {})\n",
-                    summary.total_events - event_limit,
-                    summary.total_events
-                ));
+        // Format as readable text
+        let summary = &cluster.summary;
+        let mut output = format!(
+            "Cluster '{}'\n",
+            summary.name.as_deref().unwrap_or("(unnamed)")
+        );
+        if let Some(server) = &summary.server {
+            output.push_str(&format!("Server: {}\n", server));
+        }
+        push_cluster_summary(&mut output, summary);
+        if let Some(attempted) = &cluster.connection_attempted_at {
+            output.push_str(&format!("Last Connection Attempt: {}\n", attempted));
+        }
+        if cluster.api_versions_count > 0 {
+            output.push_str(&format!("API Versions: {}\n", cluster.api_versions_count));
+        }
+        if let Some(cluster_resources) = cluster.cluster_resources {
+            output.push_str(&format!("Cluster Resources: {}\n", cluster_resources));
+        }
+        if let Some(shard) = cluster.shard {
+            output.push_str(&format!("Shard: {}\n", shard));
+        }
+        if let Some(labels) = &cluster.labels {
+            let mut labels: Vec<_> = labels.iter().collect();
+            labels.sort();
+            output.push_str("Labels:\n");
+            for (key, value) in labels {
+                output.push_str(&format!("  {}={}\n", key, value));
             }
+        }
+        if let Some(requested) = &cluster.refresh_requested_at {
+            output.push_str(&format!("Refresh Requested: {}\n", requested));
+        }
 
-            // Also include JSON for structured consumption
-            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
-                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-            })?;
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&cluster).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            Ok(CallToolResult::success(vec![
-                Content::text(output),
-                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
-            ]))
-        }
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
     }
 
-    /// Get pod logs for an ArgoCD application resource
+    /// Invalidate ArgoCD's cache of a cluster
     #[tool(
-        description = "Get container logs from pods in an ArgoCD application. Supports filtering for errors/warnings, tailing logs, and analyzing log levels. Essential for troubleshooting deployments, investigating crashes, and monitoring application behavior. Use 'errors_only' parameter to filter for issues automatically."
+        description = "Invalidate ArgoCD's cache of a cluster (by server URL or name) so it is rebuilt from the Kubernetes API server. Useful when resource trees or health look stale. Rebuilding the cache of a large cluster takes a while. Blocked in read-only mode."
     )]
-    async fn pod_logs(
+    async fn invalidate_cluster_cache(
         &self,
-        Parameters(args): Parameters<PodLogsArgs>,
+        Parameters(args): Parameters<GetClusterArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-        // Default tail_lines to 100 if not specified (context-efficient default)
-        let tail_lines = args.tail_lines.or(Some(100));
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot invalidate cluster cache in read-only mode. This operation modifies cluster state.",
+                None,
+            ));
+        }
 
         // Call ArgoCD API
         let summary = client
-            .pod_logs(
-                args.application_name.clone(),
-                args.namespace,
-                args.pod_name.clone(),
-                args.container.clone(),
-                args.since_seconds,
-                tail_lines,
-                args.previous,
-                args.filter,
-                args.kind,
-                args.group,
-                args.resource_name,
-                args.app_namespace,
-                args.project,
-                args.errors_only,
-            )
+            .invalidate_cluster_cache(args.cluster)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to get pod logs: {}", e), None)
-            })?;
-
-        if summary.total_lines == 0 {
-            let mut no_logs_msg =
-                format!("No logs found for application '{}'", args.application_name);
-            if let Some(pod) = &args.pod_name {
-                no_logs_msg.push_str(&format!(" pod '{}'", pod));
-            }
-            if let Some(container) = &args.container {
-                no_logs_msg.push_str(&format!(" container '{}'", container));
-            }
-            if args.errors_only {
-                no_logs_msg.push_str(" (no errors or warnings detected)");
-            }
+            .or_api_error(&client, "Failed to invalidate cluster cache", None)
+            .await?;
 
-            Ok(CallToolResult::success(vec![Content::text(no_logs_msg)]))
-        } else {
-            // Format as readable text
-            let mut output = format!("Pod Logs for application '{}'\n", args.application_name);
+        // Format as readable text
+        let mut output = format!("✅ Cache invalidated for cluster '{}'\n", summary.cluster);
+        if let Some(requested) = &summary.refresh_requested_at {
+            output.push_str(&format!("Refresh Requested: {}\n", requested));
+        }
+        output.push_str("    Use get_cluster to follow the cache rebuild.\n");
 
-            if let Some(pod) = &summary.pod_name {
-                output.push_str(&format!("Pod: {}\n", pod));
-            }
-            if let Some(container) = &summary.container {
-                output.push_str(&format!("Container: {}\n", container));
-            }
-            if let Some(tail) = summary.tail_lines {
-                output.push_str(&format!("Tail Lines: {}\n", tail));
-            }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            output.push_str(&format!("\nTotal lines: {}\n", summary.total_lines));
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-            if summary.filtered {
-                output.push_str("🔍 Filtered to show errors and potential issues only\n");
-            }
+    /// Rotate the credentials ArgoCD uses for a cluster
+    #[tool(
+        description = "Rotate the bearer token ArgoCD uses to access a cluster (by server URL or name). ArgoCD creates a new token for its service account in the cluster and revokes the old one. Only works for clusters added with a service account token. Blocked in read-only mode."
+    )]
+    async fn rotate_cluster_auth(
+        &self,
+        Parameters(args): Parameters<GetClusterArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-            // Log statistics
-            if summary.error_count > 0
-                || summary.warning_count > 0
-                || summary.potential_issue_count > 0
-            {
-                output.push_str("\n📊 Log Analysis:\n");
-                if summary.error_count > 0 {
-                    output.push_str(&format!("  ❌ Errors: {}\n", summary.error_count));
-                }
-                if summary.warning_count > 0 {
-                    output.push_str(&format!("  ⚠️  Warnings: {}\n", summary.warning_count));
-                }
-                if summary.potential_issue_count > 0 {
-                    output.push_str(&format!(
-                        "  🔍 Potential Issues: {}\n",
-                        summary.potential_issue_count
-                    ));
-                }
-            }
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot rotate cluster auth in read-only mode. This operation modifies cluster credentials.",
+                None,
+            ));
+        }
 
-            // Log levels breakdown
-            if !summary.logs_by_level.is_empty() {
-                output.push_str("\nLogs by Level:\n");
-                let mut levels: Vec<_> = summary.logs_by_level.iter().collect();
-                levels.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
-                for (level, count) in levels {
-                    output.push_str(&format!("  {}: {}\n", level, count));
-                }
-            }
+        // Call ArgoCD API
+        let summary = client
+            .rotate_cluster_auth(args.cluster)
+            .await
+            .or_api_error(&client, "Failed to rotate cluster auth", None)
+            .await?;
 
-            // Show log entries
-            output.push_str(&format!(
-                "\n📝 Log Entries (showing {}):\n",
-                summary.total_lines
-            ));
-            output.push_str(&"─".repeat(80));
-            output.push('\n');
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            for (idx, entry) in summary.log_entries.iter().enumerate() {
-                // Add visual indicators for issues
-                let indicator = match entry.level {
-                    crate::models::LogLevel::Fatal => "💀",
-                    crate::models::LogLevel::Error => "❌",
-                    crate::models::LogLevel::Warning => "⚠️ ",
-                    crate::models::LogLevel::Info => "ℹ️ ",
-                    crate::models::LogLevel::Debug => "🐛",
-                    crate::models::LogLevel::Unknown => "  ",
-                };
+        Ok(CallToolResult::success(vec![
+            Content::text(format!(
+                "✅ Credentials rotated for cluster '{}'\n",
+                summary.cluster
+            )),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-                // Format timestamp if available
-                let timestamp_str = if let Some(ts) = &entry.timestamp {
-                    format!("[{}] ", ts)
-                } else {
-                    String::new()
-                };
+    /// List repositories registered in ArgoCD
+    #[tool(
+        description = "List the Git and Helm repositories registered in ArgoCD with their type, project scope, connection state (Successful/Failed with the error message) and the kind of credentials configured, plus the credential templates that apply to repositories by URL prefix. Credentials are always redacted. Useful when syncs fail with repository authentication errors."
+    )]
+    async fn list_repositories(
+        &self,
+        Parameters(args): Parameters<ListRepositoriesArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-                output.push_str(&format!(
-                    "{}{} {}{}\n",
-                    indicator,
-                    timestamp_str,
-                    entry.level.as_str(),
-                    if entry.level.as_str() != "UNKNOWN" {
-                        ": "
-                    } else {
-                        ""
-                    }
-                ));
-                output.push_str(&format!("   {}\n", entry.content));
+        // Call ArgoCD API
+        let summary = client
+            .list_repositories()
+            .await
+            .or_api_error(&client, "Failed to list repositories", None)
+            .await?;
 
-                // Add separator between entries for readability
-                if idx < summary.total_lines - 1 {
-                    output.push('\n');
-                }
+        // Format as readable text
+        let mut output = format!("Found {} repository(ies)\n", summary.repositories.len());
+        for repository in &summary.repositories {
+            output.push_str(&format!("\n• {}", repository.repo));
+            if let Some(name) = &repository.name {
+                output.push_str(&format!(" ({})", name));
             }
-
-            output.push_str(&"─".repeat(80));
             output.push('\n');
-
-            // Add helpful notes
-            if !summary.filtered && (summary.error_count > 0 || summary.potential_issue_count > 0) {
+            push_repository_summary(&mut output, repository);
+        }
+        if !summary.credential_templates.is_empty() {
+            output.push_str(&format!(
+                "\nCredential Templates ({}):\n",
+                summary.credential_templates.len()
+            ));
+            for template in &summary.credential_templates {
                 output.push_str(&format!(
-                    "\n💡 Tip: Use 'errors_only: true' to filter {} errors and {} potential issues\n",
-                    summary.error_count, summary.potential_issue_count
+                    "  - {} ({}, {})\n",
+                    template.url,
+                    template.repo_type.as_deref().unwrap_or("git"),
+                    template.auth
                 ));
             }
+        }
 
-            if summary.total_lines >= 100 && tail_lines == Some(100) {
-                output.push_str("\n💡 Tip: Increase 'tail_lines' to see more logs or use 'since_seconds' for time-based filtering\n");
-            }
-
-            // Also include JSON for structured consumption
-            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
-                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-            })?;
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            Ok(CallToolResult::success(vec![
-                Content::text(output),
-                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
-            ]))
-        }
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
     }
 
-    /// Get application manifests
+    /// Get a repository and its connection status
     #[tool(
-        description = "Get Kubernetes manifests for an ArgoCD application. Returns parsed YAML/JSON manifests with metadata including kind, API version, name, and namespace. Useful for reviewing what will be deployed, validating configurations, and understanding application structure."
+        description = "Get a repository registered in ArgoCD by URL: type, project scope, connection state with the error message and time of the last attempt, and the kind of credentials configured (redacted). Set refresh=true to make ArgoCD test the connection again instead of reporting the cached state."
     )]
-    async fn get_manifests(
+    async fn get_repository(
         &self,
-        Parameters(args): Parameters<GetManifestsArgs>,
+        Parameters(args): Parameters<GetRepositoryArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
-        let summary = client
-            .get_manifests(
-                args.application_name.clone(),
-                args.revision,
-                args.app_namespace,
-                args.project,
-                args.source_positions,
-                args.revisions,
-            )
+        let repository = client
+            .get_repository(args.repo, args.refresh)
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to get manifests: {}", e), None)
-            })?;
-
-        if summary.total_manifests == 0 {
-            Ok(CallToolResult::success(vec![Content::text(format!(
-                "No manifests found for application '{}'",
-                args.application_name
-            ))]))
-        } else {
-            // Format as readable text
-            let mut output = format!("Manifests for application '{}'\n", args.application_name);
+            .or_api_error(&client, "Failed to get repository", None)
+            .await?;
 
-            if let Some(rev) = &summary.revision {
-                output.push_str(&format!("Revision: {}\n", rev));
-            }
-            if let Some(ns) = &summary.namespace {
-                output.push_str(&format!("Namespace: {}\n", ns));
-            }
-            if let Some(server) = &summary.server {
-                output.push_str(&format!("Server: {}\n", server));
-            }
-            if let Some(source_type) = &summary.source_type {
-                output.push_str(&format!("Source Type: {}\n", source_type));
-            }
-
-            output.push_str(&format!("\nTotal manifests: {}\n", summary.total_manifests));
-
-            // Manifests by kind
-            if !summary.manifests_by_kind.is_empty() {
-                output.push_str("\nManifests by Kind:\n");
-                let mut kinds: Vec<_> = summary.manifests_by_kind.iter().collect();
-                kinds.sort_by(|a, b| b.1.cmp(a.1)); // Sort by count descending
-                for (kind, count) in kinds {
-                    output.push_str(&format!("  {}: {}\n", kind, count));
-                }
-            }
+        // Format as readable text
+        let mut output = format!("Repository '{}'\n", repository.repo);
+        if let Some(name) = &repository.name {
+            output.push_str(&format!("  Name: {}\n", name));
+        }
+        push_repository_summary(&mut output, &repository);
+        if let Some(attempted) = &repository.connection_attempted_at {
+            output.push_str(&format!("  Last Connection Attempt: {}\n", attempted));
+        }
 
-            // Commands used
-            if let Some(commands) = &summary.commands {
-                if !commands.is_empty() {
-                    output.push_str(&format!(
-                        "\nCommands used to generate manifests ({}):\n",
-                        commands.len()
-                    ));
-                    for (idx, cmd) in commands.iter().enumerate() {
-                        output.push_str(&format!("  {}. {}\n", idx + 1, cmd));
-                    }
-                }
-            }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&repository).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            // List manifests
-            output.push_str(&format!(
-                "\n📄 Manifest Summaries ({})\n",
-                summary.total_manifests
-            ));
-            output.push_str(&"─".repeat(80));
-            output.push('\n');
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-            for (idx, manifest) in summary.manifests.iter().enumerate() {
-                output.push_str(&format!(
-                    "\n{}. {} - {}/{}\n",
-                    idx + 1,
-                    manifest.kind,
-                    manifest.api_version,
-                    manifest.name
-                ));
+    /// Check whether ArgoCD can access a repository
+    #[tool(
+        description = "Check whether ArgoCD can access a Git or Helm repository, optionally with credentials (username/password or SSH private key) that are not yet registered. Nothing is saved. Matching credential templates are used when no credentials are given. Reports the Git/Helm error when access fails; credentials are never echoed back. To re-test a registered repository, use get_repository with refresh=true."
+    )]
+    async fn validate_repository_access(
+        &self,
+        Parameters(args): Parameters<ValidateRepositoryAccessArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        let query = RepoAccessQuery {
+            repo: args.repo,
+            repo_type: args.repo_type,
+            username: args.username,
+            password: args.password,
+            ssh_private_key: args.ssh_private_key,
+            insecure: args.insecure,
+            enable_oci: args.enable_oci,
+            project: args.project,
+        };
 
-                if let Some(ns) = &manifest.namespace {
-                    output.push_str(&format!("   Namespace: {}\n", ns));
-                }
+        // Call ArgoCD API
+        let summary = client
+            .validate_repository_access(query)
+            .await
+            .or_api_error(&client, "Failed to validate repository access", None)
+            .await?;
 
-                // Show first few lines of YAML for context (limited to prevent overflow)
-                let lines: Vec<&str> = manifest.raw_yaml.lines().collect();
-                let preview_lines = std::cmp::min(5, lines.len());
-                output.push_str("   Preview:\n");
-                for line in lines.iter().take(preview_lines) {
-                    output.push_str(&format!("   {}\n", line));
-                }
-                if lines.len() > preview_lines {
-                    output.push_str(&format!(
-                        "   ... ({} more lines)\n",
-                        lines.len() - preview_lines
-                    ));
-                }
-            }
+        // Format as readable text
+        let mut output = if summary.valid {
+            format!("✅ ArgoCD can access repository '{}'\n", summary.repo)
+        } else {
+            format!("❌ ArgoCD cannot access repository '{}'\n", summary.repo)
+        };
+        if let Some(message) = &summary.message {
+            output.push_str(&format!("Error: {}\n", message));
+        }
 
-            output.push_str("\n");
-            output.push_str(&"─".repeat(80));
-            output.push('\n');
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-            // Add helpful note
-            output.push_str(
-                "\n💡 Tip: Use this to review what will be deployed and validate configurations\n",
-            );
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-            // Also include JSON for structured consumption
-            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
-                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-            })?;
+    /// List the branches and tags of a repository
+    #[tool(
+        description = "List the branches and tags of a Git repository registered in ArgoCD, e.g. to pick a targetRevision or check that a revision exists. Returns at most 'limit' (default 100) of each along with the totals."
+    )]
+    async fn list_repo_refs(
+        &self,
+        Parameters(args): Parameters<ListRepoRefsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-            Ok(CallToolResult::success(vec![
-                Content::text(output),
-                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
-            ]))
+        // Call ArgoCD API
+        let refs = client
+            .list_repo_refs(args.repo, args.limit.unwrap_or(100))
+            .await
+            .or_api_error(&client, "Failed to list repository refs", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!("Refs of repository '{}'\n", refs.repo);
+        for (title, names, total) in [
+            ("Branches", &refs.branches, refs.total_branches),
+            ("Tags", &refs.tags, refs.total_tags),
+        ] {
+            output.push_str(&format!("\n{} ({}):\n", title, total));
+            for name in names {
+                output.push_str(&format!("  - {}\n", name));
+            }
+            if total > names.len() {
+                output.push_str(&format!("  ... and {} more\n", total - names.len()));
+            }
         }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&refs).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
     }
 
-    /// Get revision metadata
+    /// Find the applications in a repository
     #[tool(
-        description = "Get metadata (author, date, message, tags) for a specific revision of an ArgoCD application. Returns commit information including author, timestamp, commit message, associated Git tags, and signature verification status. Useful for tracking changes, auditing deployments, and understanding revision history."
+        description = "Find the directories in a Git repository that ArgoCD can deploy as applications, with the detected type (Helm, Kustomize, Directory or Plugin), at a given revision (default HEAD). Useful for choosing the path of a new application."
     )]
-    async fn revision_metadata(
+    async fn list_repo_apps(
         &self,
-        Parameters(args): Parameters<RevisionMetadataArgs>,
+        Parameters(args): Parameters<ListRepoAppsArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
-        let summary = client
-            .revision_metadata(
-                args.application_name.clone(),
+        let apps = client
+            .list_repo_apps(
+                args.repo.clone(),
                 args.revision.clone(),
-                args.app_namespace,
-                args.project,
-                args.source_index,
-                args.version_id,
+                args.app_name,
+                args.app_project,
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to get revision metadata: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to list repository apps", None)
+            .await?;
 
         // Format as readable text
         let mut output = format!(
-            "Revision Metadata for application '{}' at revision '{}'\n\n",
-            args.application_name, args.revision
+            "Found {} application path(s) in '{}' at {}\n\n",
+            apps.len(),
+            redact_url_credentials(&args.repo),
+            args.revision.as_deref().unwrap_or("HEAD")
         );
-
-        if let Some(author) = &summary.author {
-            output.push_str(&format!("Author: {}\n", author));
-        }
-        if let Some(date) = &summary.date {
-            output.push_str(&format!("Date: {}\n", date));
+        for app in &apps {
+            output.push_str(&format!(
+                "  - {} ({})\n",
+                app.path,
+                app.app_type.as_deref().unwrap_or("unknown")
+            ));
         }
 
-        // Show short message first
-        if let Some(msg_short) = &summary.message_short {
-            output.push_str(&format!("\nCommit Message:\n  {}\n", msg_short));
-        }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&apps).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
 
-        // Show full message if it's multi-line
-        if let Some(msg_full) = &summary.message_full {
-            let lines: Vec<&str> = msg_full.lines().collect();
-            if lines.len() > 1 {
-                output.push_str("\nFull Message:\n");
-                for line in lines {
-                    output.push_str(&format!("  {}\n", line));
-                }
-            }
-        }
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-        // Tags information
-        if summary.tag_count > 0 {
-            output.push_str(&format!("\nTags ({}):\n", summary.tag_count));
-            if let Some(tags) = &summary.tags {
-                for tag in tags {
-                    output.push_str(&format!("  - {}\n", tag));
-                }
+    /// List the charts of a Helm repository
+    #[tool(
+        description = "List the charts of a Helm repository registered in ArgoCD, with the number of versions and the 10 most recent ones for each chart. Useful for choosing a chart and targetRevision."
+    )]
+    async fn get_helm_charts(
+        &self,
+        Parameters(args): Parameters<GetHelmChartsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let charts = client
+            .get_helm_charts(args.repo.clone())
+            .await
+            .or_api_error(&client, "Failed to get Helm charts", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "Found {} chart(s) in '{}'\n\n",
+            charts.len(),
+            redact_url_credentials(&args.repo)
+        );
+        for chart in &charts {
+            output.push_str(&format!(
+                "• {} ({} versions): {}",
+                chart.name,
+                chart.versions_count,
+                chart.versions.join(", ")
+            ));
+            if chart.versions_count > chart.versions.len() {
+                output.push_str(", ...");
             }
-        } else {
-            output.push_str("\nTags: None\n");
+            output.push('\n');
         }
 
-        // Signature information
-        output.push_str("\nSignature Status: ");
-        if summary.is_signed {
-            if let Some(sig_summary) = &summary.signature_summary {
-                output.push_str(&format!("{}\n", sig_summary));
-            } else {
-                output.push_str("Signed\n");
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&charts).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// List ApplicationSets
+    #[tool(
+        description = "List ArgoCD ApplicationSets with their generator kinds, template project, the number of applications each generated, and any error condition. Filter by projects, label selector or namespace."
+    )]
+    async fn list_applicationsets(
+        &self,
+        Parameters(args): Parameters<ListApplicationSetsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Call ArgoCD API
+        let appsets = client
+            .list_applicationsets(args.projects, args.selector, args.appset_namespace)
+            .await
+            .or_api_error(&client, "Failed to list ApplicationSets", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!("Found {} ApplicationSet(s)\n\n", appsets.len());
+        for appset in &appsets {
+            output.push_str(&format!(
+                "• {} [{}] - {} application(s)",
+                appset.name,
+                appset.generators.join(", "),
+                appset.applications_count
+            ));
+            if let Some(project) = &appset.project {
+                output.push_str(&format!(", project {}", project));
+            }
+            output.push('\n');
+            if let Some(error) = &appset.error {
+                output.push_str(&format!("  ❌ Error: {}\n", error));
             }
-        } else {
-            output.push_str("Not signed\n");
         }
 
         // Also include JSON for structured consumption
-        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+        let json_data = serde_json::to_string_pretty(&appsets).map_err(|e| {
             McpError::internal_error(format!("Failed to serialize response: {}", e), None)
         })?;
 
@@ -1445,147 +5153,177 @@ impl ArgocdMcpHandler {
         ]))
     }
 
-    /// Get application sync windows
+    /// Get an ApplicationSet
     #[tool(
-        description = "Get synchronization windows for an ArgoCD application. Returns a list of configured sync windows, including their schedule, duration, and affected applications/namespaces/clusters. Useful for understanding when an application can be synced or is blocked from syncing. NOTE: This feature requires ArgoCD v2.6+. If unavailable, a 404 error will be returned."
+        description = "Get an ArgoCD ApplicationSet: its generators (list, clusters, git, matrix, merge, SCM provider, pull request, plugin), the application template (name pattern, project, sources, destination, sync policy), conditions such as ErrorOccurred with their messages, and the sync and health status of each generated application, including progressive sync status when a strategy is set."
     )]
-    async fn get_application_sync_windows(
+    async fn get_applicationset(
         &self,
-        Parameters(args): Parameters<GetApplicationSyncWindowsArgs>,
+        Parameters(args): Parameters<GetApplicationSetArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
-        let summary = client
-            .get_application_sync_windows(
-                args.application_name.clone(),
-                args.app_namespace,
-                args.project,
-            )
+        let appset = client
+            .get_applicationset(args.applicationset_name, args.appset_namespace)
             .await
-            .map_err(|e| {
-                McpError::internal_error(
-                    format!("Failed to get application sync windows: {}", e),
-                    None,
-                )
-            })?;
+            .or_api_error(&client, "Failed to get ApplicationSet", None)
+            .await?;
 
-        if summary.total_windows == 0 {
-            Ok(CallToolResult::success(vec![Content::text(format!(
-                "No sync windows found for application '{}'",
-                args.application_name
-            ))]))
-        } else {
-            // Format as readable text
-            let mut output = format!(
-                "Sync Windows for application '{}' ({} total):\n\n",
-                args.application_name, summary.total_windows
-            );
+        // Format as readable text
+        let mut output = format!("ApplicationSet '{}'\n", appset.name);
+        if let Some(ns) = &appset.namespace {
+            output.push_str(&format!("Namespace: {}\n", ns));
+        }
+        push_applicationset_detail(&mut output, &appset);
 
-            for (idx, window) in summary.windows.iter().enumerate() {
-                output.push_str(&format!(
-                    "{}. Kind: {}\n",
-                    idx + 1,
-                    window.kind.as_deref().unwrap_or("Unknown")
-                ));
-                if let Some(schedule) = &window.schedule {
-                    output.push_str(&format!("   Schedule: {}\n", schedule));
-                }
-                if let Some(duration) = &window.duration {
-                    output.push_str(&format!("   Duration: {}\n", duration));
-                }
-                if let Some(start_time) = &window.start_time {
-                    output.push_str(&format!("   Start Time: {}\n", start_time));
-                }
-                if let Some(end_time) = &window.end_time {
-                    output.push_str(&format!("   End Time: {}\n", end_time));
-                }
-                if let Some(manual_sync_enabled) = window.manual_sync_enabled {
-                    output.push_str(&format!(
-                        "   Manual Sync Enabled: {}\n",
-                        manual_sync_enabled
-                    ));
-                }
-                if let Some(apps) = &window.applications {
-                    if !apps.is_empty() {
-                        output.push_str(&format!("   Applications: {}\n", apps.join(", ")));
-                    }
-                }
-                if let Some(namespaces) = &window.namespaces {
-                    if !namespaces.is_empty() {
-                        output.push_str(&format!("   Namespaces: {}\n", namespaces.join(", ")));
-                    }
-                }
-                if let Some(clusters) = &window.clusters {
-                    if !clusters.is_empty() {
-                        output.push_str(&format!("   Clusters: {}\n", clusters.join(", ")));
-                    }
-                }
-                output.push('\n');
-            }
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&appset).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
 
-            // Also include JSON for structured consumption
-            let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
-                McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-            })?;
+    /// Preview the applications an ApplicationSet spec generates
+    #[tool(
+        description = "Preview the applications an ApplicationSet spec would generate, with each application's name, project, sources and destination, without creating anything. Useful to check generators and templates before create_applicationset. Requires ArgoCD v2.10+."
+    )]
+    async fn generate_applicationset_preview(
+        &self,
+        Parameters(args): Parameters<GenerateApplicationSetPreviewArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
-            Ok(CallToolResult::success(vec![
-                Content::text(output),
-                Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
-            ]))
+        // Call ArgoCD API
+        let preview = client
+            .generate_applicationset_preview(
+                args.applicationset_name,
+                args.appset_namespace,
+                args.spec,
+            )
+            .await
+            .or_api_error(&client, "Failed to generate ApplicationSet preview", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = format!(
+            "ApplicationSet '{}' would generate {} application(s)\n",
+            preview.name,
+            preview.applications.len()
+        );
+        for app in &preview.applications {
+            output.push_str(&format!("\n• {}\n", app.name));
+            if let Some(spec) = &app.spec {
+                push_spec_summary(&mut output, spec);
+            }
         }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&preview).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
     }
 
-    /// Rollback an application to a previous deployed version by History ID
+    /// Create an ApplicationSet
     #[tool(
-        description = "Rollback an ArgoCD application to a previous deployed version by History ID. This operation reverts the application to a specific point in its deployment history. Use dry_run mode to preview changes before applying. Use prune to remove resources that were removed in the target revision. Returns the application state after rollback including sync status, health status, and the revision that was rolled back to."
+        description = "Create an ArgoCD ApplicationSet from a spec (generators, template, goTemplate, syncPolicy, strategy). Set upsert to replace an existing ApplicationSet with the same name, and dry_run to have ArgoCD validate the spec without saving it (ArgoCD v2.11+). Use generate_applicationset_preview to see the applications it would generate. Blocked in read-only mode."
     )]
-    async fn rollback_application(
+    async fn create_applicationset(
         &self,
-        Parameters(args): Parameters<RollbackApplicationArgs>,
+        Parameters(args): Parameters<CreateApplicationSetArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        // Check if read-only mode is enabled
+        if self.read_only {
+            return Err(McpError::internal_error(
+                "Cannot create ApplicationSet in read-only mode. This operation modifies application state.",
                 None,
+            ));
+        }
+
+        let dry_run = args.dry_run.unwrap_or(false);
+
+        // Call ArgoCD API
+        let appset = client
+            .create_applicationset(
+                args.applicationset_name,
+                args.appset_namespace,
+                args.spec,
+                args.upsert,
+                args.dry_run,
             )
+            .await
+            .or_api_error(&client, "Failed to create ApplicationSet", None)
+            .await?;
+
+        // Format as readable text
+        let mut output = if dry_run {
+            format!("ApplicationSet '{}' (Dry Run)\n", appset.name)
+        } else {
+            format!("✅ ApplicationSet '{}' created\n", appset.name)
+        };
+        push_applicationset_detail(&mut output, &appset);
+        if dry_run {
+            output.push_str("\n⚠️  Note: This was a dry run. No actual changes were made.\n");
+        }
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&appset).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
         })?;
 
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
+    /// Delete an ApplicationSet
+    #[tool(
+        description = "Delete an ArgoCD ApplicationSet. The applications it generated are deleted with it, and their Kubernetes resources too unless the ApplicationSet sets preserveResourcesOnDeletion. Use dry_run to list the applications that would be deleted. Blocked in read-only mode."
+    )]
+    async fn delete_applicationset(
+        &self,
+        Parameters(args): Parameters<DeleteApplicationSetArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
         // Check if read-only mode is enabled
         if self.read_only {
             return Err(McpError::internal_error(
-                "Cannot rollback application in read-only mode. This operation modifies application state.",
+                "Cannot delete ApplicationSet in read-only mode. This operation modifies application state.",
                 None,
             ));
         }
 
         // Call ArgoCD API
         let summary = client
-            .rollback_application(
-                args.application_name.clone(),
-                args.id,
-                args.dry_run,
-                args.prune,
-                args.app_namespace,
-                args.project,
+            .delete_applicationset(
+                args.applicationset_name,
+                args.appset_namespace,
+                args.dry_run.unwrap_or(false),
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to rollback application: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to delete ApplicationSet", None)
+            .await?;
 
         // Format as readable text
         let mut output = format!(
-            "Rollback {} for application '{}'\n\n",
+            "Delete {} for ApplicationSet '{}'\n",
             if summary.dry_run {
                 "(Dry Run)"
             } else {
@@ -1593,39 +5331,31 @@ impl ArgocdMcpHandler {
             },
             summary.name
         );
-
         output.push_str(&format!(
-            "Rolled back to History ID: {}\n",
-            summary.rolled_back_to_id
+            "\nGenerated Applications ({}) {}:\n",
+            summary.applications.len(),
+            if summary.dry_run {
+                "that would be deleted"
+            } else {
+                "deleted"
+            }
         ));
-
-        if let Some(target_rev) = &summary.target_revision {
-            output.push_str(&format!("Target Revision: {}\n", target_rev));
-        }
-
-        if let Some(sync_rev) = &summary.sync_revision {
-            output.push_str(&format!("Current Sync Revision: {}\n", sync_rev));
-        }
-
-        output.push_str("\nStatus:\n");
-        if let Some(sync_status) = &summary.sync_status {
-            output.push_str(&format!("  Sync Status: {}\n", sync_status));
+        for app in &summary.applications {
+            output.push_str(&format!("  - {}\n", app));
         }
-        if let Some(health_status) = &summary.health_status {
-            output.push_str(&format!("  Health Status: {}\n", health_status));
+        if summary.preserve_resources_on_deletion {
+            output.push_str(
+                "\nTheir Kubernetes resources are preserved (preserveResourcesOnDeletion).\n",
+            );
+        } else {
+            output.push_str("\nTheir Kubernetes resources are deleted with them.\n");
         }
 
-        output.push_str("\nOptions:\n");
-        output.push_str(&format!("  Dry Run: {}\n", summary.dry_run));
-        output.push_str(&format!("  Prune Enabled: {}\n", summary.prune_enabled));
-
         if summary.dry_run {
             output.push_str("\n⚠️  Note: This was a dry run. No actual changes were made.\n");
-            output.push_str("    Run without dry_run=true to perform the actual rollback.\n");
+            output.push_str("    Run without dry_run=true to delete the ApplicationSet.\n");
         } else {
-            output.push_str("\n✅ Rollback completed successfully.\n");
-            output
-                .push_str("    Monitor the application to ensure it reaches the desired state.\n");
+            output.push_str("\n✅ ApplicationSet deletion requested.\n");
         }
 
         // Also include JSON for structured consumption
@@ -1639,22 +5369,66 @@ impl ArgocdMcpHandler {
         ]))
     }
 
+    /// Wait until an application reaches a target state
+    #[tool(
+        description = "Wait until an ArgoCD application is synced, healthy and its operation has finished (pick any of sync, health, operation; all three when none is set), the operation fails, or the timeout elapses. Checks every few seconds and sends MCP progress notifications when the request carries a progress token. Returns the outcome (Succeeded, OperationFailed or TimedOut), the conditions still unmet, and a compact final state with error conditions, failed resources and hooks, and unhealthy resources. Use this instead of polling get_application after a sync or rollback."
+    )]
+    async fn wait_for_application(
+        &self,
+        Parameters(args): Parameters<WaitForApplicationArgs>,
+        peer: Peer<RoleServer>,
+        meta: Meta,
+    ) -> Result<CallToolResult, McpError> {
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
+
+        let conditions = WaitConditions::new(
+            args.sync.unwrap_or(false),
+            args.health.unwrap_or(false),
+            args.operation.unwrap_or(false),
+        );
+
+        // Call ArgoCD API until the conditions hold
+        let summary = wait_with_progress(
+            &client,
+            &args.application_name,
+            args.app_namespace,
+            args.project,
+            &conditions,
+            args.timeout_seconds,
+            args.poll_interval_seconds,
+            &peer,
+            &meta,
+        )
+        .await?;
+
+        // Format as readable text
+        let mut output = format!("⏳ Application '{}'\n\n", summary.name);
+        push_wait_summary(&mut output, &summary);
+
+        // Also include JSON for structured consumption
+        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
+            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
+        })?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(output),
+            Content::text(format!("\n--- JSON Data ---\n{}", json_data)),
+        ]))
+    }
+
     /// Sync an application to its target state
     #[tool(
-        description = "Sync an ArgoCD application to its target state in Git. This operation deploys/updates the application resources to match what's defined in the Git repository. Supports dry-run mode to preview changes, selective resource sync, force sync to override conflicts, prune to remove orphaned resources, and custom sync options. Returns the application state after sync including sync status, health status, and applied configuration."
+        description = "Sync an ArgoCD application to its target state in Git. This operation deploys/updates the application resources to match what's defined in the Git repository. Supports dry-run mode to preview changes, selective resource sync, force sync to override conflicts, prune to remove orphaned resources, and custom sync options. Returns the application state after sync including sync status, health status, and applied configuration. Set wait=true to block until the sync operation has finished and the application is synced and healthy (see wait_for_application)."
     )]
     async fn sync_application(
         &self,
         Parameters(args): Parameters<SyncApplicationArgs>,
+        peer: Peer<RoleServer>,
+        meta: Meta,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Check if read-only mode is enabled
         if self.read_only {
@@ -1693,6 +5467,21 @@ impl ArgocdMcpHandler {
             },
         });
 
+        // Remember the last operation so waiting skips it. A dry run starts
+        // no operation, so there is nothing to wait for
+        let wait = args.wait.unwrap_or(false) && !args.dry_run.unwrap_or(false);
+        let previous_operation = if wait {
+            operation_started_at(
+                &client,
+                &args.application_name,
+                args.app_namespace.clone(),
+                args.project.clone(),
+            )
+            .await?
+        } else {
+            None
+        };
+
         // Call ArgoCD API
         let summary = client
             .sync_application(
@@ -1704,13 +5493,16 @@ impl ArgocdMcpHandler {
                 resources,
                 args.sync_options.clone(),
                 retry,
-                args.app_namespace,
-                args.project,
+                args.app_namespace.clone(),
+                args.project.clone(),
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to sync application: {}", e), None)
-            })?;
+            .or_api_error(
+                &client,
+                "Failed to sync application",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
 
         // Format as readable text
         let mut output = format!(
@@ -1730,6 +5522,10 @@ impl ArgocdMcpHandler {
         if let Some(sync_rev) = &summary.sync_revision {
             output.push_str(&format!("Current Sync Revision: {}\n", sync_rev));
         }
+        if !summary.sources.is_empty() {
+            output.push_str(&format!("Sources ({}):\n", summary.sources.len()));
+            push_sources(&mut output, "  ", &summary.sources);
+        }
 
         output.push_str("\nStatus:\n");
         if let Some(sync_status) = &summary.sync_status {
@@ -1760,19 +5556,51 @@ impl ArgocdMcpHandler {
         if summary.dry_run {
             output.push_str("\n⚠️  Note: This was a dry run. No actual changes were made.\n");
             output.push_str("    Run without dry_run=true to perform the actual sync.\n");
+            if args.wait.unwrap_or(false) {
+                output.push_str("    wait=true was ignored: a dry run starts no operation.\n");
+            }
         } else {
             output.push_str("\n✅ Sync completed successfully.\n");
-            output
-                .push_str("    Monitor the application to ensure it reaches the desired state.\n");
-            if summary.sync_status == Some("OutOfSync".to_string()) {
-                output.push_str("    Note: Application may still be syncing. Check status again in a few moments.\n");
+            if !wait {
+                output.push_str(
+                    "    Monitor the application to ensure it reaches the desired state.\n",
+                );
+                if summary.sync_status == Some("OutOfSync".to_string()) {
+                    output.push_str(
+                        "    Note: Application may still be syncing. Check status again in a few moments.\n",
+                    );
+                }
             }
         }
 
+        // Wait for the sync operation to finish when asked to
+        let wait_summary = if wait {
+            let conditions = WaitConditions {
+                operation_requested: true,
+                previous_operation_started_at: previous_operation,
+                ..WaitConditions::new(false, false, false)
+            };
+            let wait_summary = wait_with_progress(
+                &client,
+                &args.application_name,
+                args.app_namespace,
+                args.project,
+                &conditions,
+                args.wait_timeout_seconds,
+                None,
+                &peer,
+                &meta,
+            )
+            .await?;
+            output.push('\n');
+            push_wait_summary(&mut output, &wait_summary);
+            Some(wait_summary)
+        } else {
+            None
+        };
+
         // Also include JSON for structured consumption
-        let json_data = serde_json::to_string_pretty(&summary).map_err(|e| {
-            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-        })?;
+        let json_data = json_with_wait(&summary, wait_summary.as_ref())?;
 
         Ok(CallToolResult::success(vec![
             Content::text(output),
@@ -1788,14 +5616,8 @@ impl ArgocdMcpHandler {
         &self,
         Parameters(args): Parameters<GetResourceArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
         let summary = client
@@ -1810,9 +5632,8 @@ impl ArgocdMcpHandler {
                 args.project,
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to get resource: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to get resource", Some(args.application_name.as_str()))
+            .await?;
 
         // Format as readable text
         let mut output = format!(
@@ -1900,14 +5721,8 @@ impl ArgocdMcpHandler {
         &self,
         Parameters(args): Parameters<PatchResourceArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Check if read-only mode is enabled
         if self.read_only {
@@ -1932,9 +5747,8 @@ impl ArgocdMcpHandler {
                 args.project,
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(format!("Failed to patch resource: {}", e), None)
-            })?;
+            .or_api_error(&client, "Failed to patch resource", Some(args.application_name.as_str()))
+            .await?;
 
         // Format as readable text
         let mut output = format!(
@@ -2021,14 +5835,8 @@ impl ArgocdMcpHandler {
         &self,
         Parameters(args): Parameters<GetApplicationHistoryArgs>,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
         let history = client
@@ -2038,12 +5846,12 @@ impl ArgocdMcpHandler {
                 args.project,
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(
-                    format!("Failed to get application history: {}", e),
-                    None,
-                )
-            })?;
+            .or_api_error(
+                &client,
+                "Failed to get application history",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
 
         // Format as readable text
         let mut output = format!(
@@ -2058,7 +5866,7 @@ impl ArgocdMcpHandler {
             output.push_str("\n⚠️  No deployment history available for this application.\n");
             output.push_str("   This could mean the application has never been synced.\n");
         } else {
-            output.push_str("\n");
+            output.push('\n');
 
             // Show up to 20 most recent deployments
             let display_count = std::cmp::min(20, history.entries.len());
@@ -2098,6 +5906,11 @@ impl ArgocdMcpHandler {
                 if let Some(target) = &entry.source_target_revision {
                     output.push_str(&format!("   Target Revision: {}\n", target));
                 }
+
+                if !entry.sources.is_empty() {
+                    output.push_str(&format!("   Sources ({}):\n", entry.sources.len()));
+                    push_sources(&mut output, "     ", &entry.sources);
+                }
             }
 
             output.push_str(&"─".repeat(80));
@@ -2130,36 +5943,32 @@ impl ArgocdMcpHandler {
 
     /// Refresh an application from Git repository
     #[tool(
-        description = "Refresh an ArgoCD application from the Git repository. Forces ArgoCD to re-fetch the application manifests from Git and recompute the sync status. This is a read-only operation that does not modify cluster state - it only updates ArgoCD's cached view of the application. Use this to resolve stale sync status, update after Git changes, or troubleshoot 'stuck' applications. Returns before/after comparison showing what changed."
+        description = "Refresh an ArgoCD application from the Git repository. Forces ArgoCD to re-fetch the application manifests from Git and recompute the sync status. This is a read-only operation that does not modify cluster state - it only updates ArgoCD's cached view of the application. Use this to resolve stale sync status, update after Git changes, or troubleshoot 'stuck' applications. Returns before/after comparison showing what changed. Set wait=true to also wait until the application is synced and healthy, e.g. for automated sync to deploy a new commit."
     )]
     async fn refresh_application(
         &self,
         Parameters(args): Parameters<RefreshApplicationArgs>,
+        peer: Peer<RoleServer>,
+        meta: Meta,
     ) -> Result<CallToolResult, McpError> {
-        // Check if client is initialized
-        let client_guard = self.client.read().await;
-        let client = client_guard.as_ref().ok_or_else(|| {
-            McpError::internal_error(
-                "ArgoCD client not initialized. Please ensure ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables are set.",
-                None,
-            )
-        })?;
+        // Resolve the client for the requested context
+        let client = self.client_for(args.context.as_deref()).await?;
 
         // Call ArgoCD API
         let refresh_summary = client
             .refresh_application(
                 args.application_name.clone(),
                 args.refresh_type,
-                args.app_namespace,
-                args.project,
+                args.app_namespace.clone(),
+                args.project.clone(),
             )
             .await
-            .map_err(|e| {
-                McpError::internal_error(
-                    format!("Failed to refresh application: {}", e),
-                    None,
-                )
-            })?;
+            .or_api_error(
+                &client,
+                "Failed to refresh application",
+                Some(args.application_name.as_str()),
+            )
+            .await?;
 
         // Format as readable text
         let mut output = format!(
@@ -2177,8 +5986,12 @@ impl ArgocdMcpHandler {
         if let Some(target) = &refresh_summary.target_revision {
             output.push_str(&format!("Target Revision: {}\n", target));
         }
+        if !refresh_summary.sources.is_empty() {
+            output.push_str(&format!("Sources ({}):\n", refresh_summary.sources.len()));
+            push_sources(&mut output, "  ", &refresh_summary.sources);
+        }
 
-        output.push_str("\n");
+        output.push('\n');
         output.push_str(&"─".repeat(80));
         output.push_str("\n\n");
 
@@ -2270,10 +6083,29 @@ impl ArgocdMcpHandler {
         output.push_str("   - Use 'hard' refresh to force re-fetch from Git repository\n");
         output.push_str("   - If sync status changed to 'OutOfSync', use 'sync_application' to deploy\n");
 
+        // Wait for automated sync to settle the application when asked to
+        let wait_summary = if args.wait.unwrap_or(false) {
+            let wait_summary = wait_with_progress(
+                &client,
+                &args.application_name,
+                args.app_namespace,
+                args.project,
+                &WaitConditions::new(false, false, false),
+                args.wait_timeout_seconds,
+                None,
+                &peer,
+                &meta,
+            )
+            .await?;
+            output.push('\n');
+            push_wait_summary(&mut output, &wait_summary);
+            Some(wait_summary)
+        } else {
+            None
+        };
+
         // Also include JSON for structured consumption
-        let json_data = serde_json::to_string_pretty(&refresh_summary).map_err(|e| {
-            McpError::internal_error(format!("Failed to serialize response: {}", e), None)
-        })?;
+        let json_data = json_with_wait(&refresh_summary, wait_summary.as_ref())?;
 
         Ok(CallToolResult::success(vec![
             Content::text(output),
@@ -2282,8 +6114,170 @@ impl ArgocdMcpHandler {
     }
 }
 
-#[tool_handler]
 impl ServerHandler for ArgocdMcpHandler {
+    // Written out instead of #[tool_handler] so retries made by the ArgoCD
+    // client can be reported alongside each tool result
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        let tcc = ToolCallContext::new(self, request, context);
+        let (result, retries) = track_retries(self.tool_router.call(tcc)).await;
+
+        match (result, summarize_retries(&retries)) {
+            (Ok(mut result), Some(summary)) => {
+                result.content.push(Content::text(summary));
+                Ok(result)
+            }
+            (Err(mut error), Some(summary)) => {
+                error.message = format!("{}\n{}", error.message, summary).into();
+                Err(error)
+            }
+            (result, None) => result,
+        }
+    }
+
+    async fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult::with_all_items(self.tool_router.list_all()))
+    }
+
+    async fn set_level(
+        &self,
+        request: SetLevelRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<(), McpError> {
+        *self.log_level.lock().unwrap() = request.level;
+        Ok(())
+    }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        // Any application can be read through the templates; listing them all
+        // would mean a request per context, so only subscriptions are listed
+        let resources = self
+            .watches
+            .subscriptions(self.subscriber)
+            .into_iter()
+            .map(|key| {
+                let name = match key.target {
+                    WatchTarget::Application => key.name.clone(),
+                    WatchTarget::ResourceTree => format!("{} resource tree", key.name),
+                };
+                let mut resource = RawResource::new(key.uri(), name);
+                resource.mime_type = Some("application/json".to_string());
+                resource.no_annotation()
+            })
+            .collect();
+        Ok(ListResourcesResult::with_all_items(resources))
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        let template = |uri_template: &str, name: &str, description: &str| {
+            RawResourceTemplate {
+                uri_template: uri_template.to_string(),
+                name: name.to_string(),
+                title: None,
+                description: Some(description.to_string()),
+                mime_type: Some("application/json".to_string()),
+            }
+            .no_annotation()
+        };
+        Ok(ListResourceTemplatesResult::with_all_items(vec![
+            template(
+                "argocd://{context}/applications/{name}",
+                "application",
+                "Application details (as get_application). Subscribe to be notified when sync, health or operation state changes; append ?appNamespace={namespace} for applications outside the control plane namespace",
+            ),
+            template(
+                "argocd://{context}/applications/{name}/resource-tree",
+                "application resource tree",
+                "Resource tree summary of an application (as resource_tree). Subscribe to be notified when resources or their health change",
+            ),
+        ]))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        let key = parse_resource_uri(&request.uri)?;
+        let text = self.read_application_resource(&key).await?;
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: request.uri,
+                mime_type: Some("application/json".to_string()),
+                text,
+                meta: None,
+            }],
+        })
+    }
+
+    async fn subscribe(
+        &self,
+        request: SubscribeRequestParam,
+        context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<(), McpError> {
+        let key = parse_resource_uri(&request.uri)?;
+
+        // Resolve the client for the resource's context
+        let client = self.client_for(Some(&key.context)).await?;
+
+        // Fail now on a misspelled application rather than in the background stream
+        client
+            .get_application_full(
+                key.name.clone(),
+                key.app_namespace.clone(),
+                None,
+                None,
+                None,
+            )
+            .await
+            .or_api_error(&client, "Failed to subscribe to application", Some(&key.name))
+            .await?;
+
+        // Forward changes to this session until it goes away; dropping the
+        // receiver then ends the subscription
+        let (updates, mut changed) = tokio::sync::mpsc::unbounded_channel::<String>();
+        let peer = context.peer.clone();
+        tokio::spawn(async move {
+            while let Some(uri) = changed.recv().await {
+                if peer
+                    .notify_resource_updated(ResourceUpdatedNotificationParam { uri })
+                    .await
+                    .is_err()
+                {
+                    break;
+                }
+            }
+        });
+
+        self.watches.subscribe(key, client, self.subscriber, updates);
+        Ok(())
+    }
+
+    async fn unsubscribe(
+        &self,
+        request: UnsubscribeRequestParam,
+        _context: rmcp::service::RequestContext<rmcp::RoleServer>,
+    ) -> Result<(), McpError> {
+        let key = parse_resource_uri(&request.uri)?;
+        self.watches.unsubscribe(&key, self.subscriber);
+        Ok(())
+    }
+
     fn get_info(&self) -> ServerInfo {
         let mode_info = if self.read_only {
             " [READ-ONLY MODE: All tools are read-only GET requests only]"
@@ -2292,13 +6286,18 @@ impl ServerHandler for ArgocdMcpHandler {
         };
 
         let instructions = format!(
-            "ArgoCD MCP Server{} - provides tools to interact with ArgoCD API. Currently supports: list_applications (list and filter ArgoCD applications with full details), list_application_names (get only application names for efficient name lookup and typo correction), get_application (get detailed information about a specific application by name), server_side_diff (perform server-side diff calculation using dry-run apply to compare live and target states), resource_tree (get hierarchical resource tree view with health status and resource details), list_resource_events (list Kubernetes events for applications or specific resources with filtering capabilities), pod_logs (get container logs with intelligent error/warning filtering and log level analysis), get_manifests (get Kubernetes manifests with parsing and analysis), revision_metadata (get metadata for a specific revision including author, date, message, tags, and signature status), get_application_sync_windows (get synchronization windows for an application), get_application_history (get deployment history with history IDs for rollback operations), refresh_application (refresh application from Git repository without modifying cluster state), sync_application (sync an application to its target state in Git with dry-run, force, prune, and selective resource sync options), rollback_application (rollback an application to a previous deployed version by History ID with dry-run and prune options), get_resource (get a specific Kubernetes resource from an application with detailed manifest and parsed metadata), patch_resource (patch a Kubernetes resource in an application using JSON patch, merge patch, or strategic merge patch formats). Set ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables before starting. Optional: Set ARGOCD_READ_ONLY=true to enforce read-only mode (sync_application, rollback_application, and patch_resource are write operations and blocked in read-only mode).",
+            "ArgoCD MCP Server{} - provides tools to interact with ArgoCD API. Currently supports: list_contexts (list the configured ArgoCD contexts; every other tool accepts an optional 'context' argument to target a specific ArgoCD instance), list_applications (list and filter ArgoCD applications with full details), list_application_names (get only application names for efficient name lookup and typo correction), get_application (get detailed information about a specific application by name), server_side_diff (perform server-side diff calculation using dry-run apply to compare live and target states), resource_tree (get hierarchical resource tree view with health status and resource details), list_resource_events (list Kubernetes events for applications or specific resources with filtering capabilities), pod_logs (get container logs with intelligent error/warning filtering and log level analysis; follow=true streams new lines as log notifications until a time, line or pattern limit; cluster/top_clusters collapse repeated lines into patterns with counts), workload_logs (get the merged logs of every pod of a Deployment, StatefulSet or Rollout, showing which replicas are erroring), get_manifests (get Kubernetes manifests with parsing and analysis), revision_metadata (get metadata for a specific revision including author, date, message, tags, and signature status), get_application_sync_windows (get synchronization windows for an application), get_application_history (get deployment history with history IDs for rollback operations), refresh_application (refresh application from Git repository without modifying cluster state), sync_application (sync an application to its target state in Git with dry-run, force, prune, and selective resource sync options), rollback_application (rollback an application to a previous deployed version by History ID with dry-run and prune options), get_resource (get a specific Kubernetes resource from an application with detailed manifest and parsed metadata), patch_resource (patch a Kubernetes resource in an application using JSON patch, merge patch, or strategic merge patch formats), delete_resource (delete a resource from an application, previewing it and its child resources unless dry_run=false), list_resource_actions (list the actions available on a resource, marking disabled ones), run_resource_action (run a resource action such as restart, resume or promote), terminate_operation (terminate a running sync or rollback, reporting the operation phase before and after), create_application (create an application from a full spec with upsert, validate and dry-run options), update_application_spec (replace an application spec, with a dry run showing the changed fields), delete_application (delete an application with cascade and propagation policy options and a dry-run resource preview), list_projects (list projects with counts of repos, destinations, roles and sync windows), get_project (get a project's source repos, destinations, resource allow/deny lists, roles with token metadata, and sync windows), get_project_events (list Kubernetes events for a project), create_project, update_project and delete_project (manage projects), upsert_project_role and delete_project_role (manage project roles), create_project_token and delete_project_token (issue and revoke project role tokens), list_clusters (list registered clusters with connection state, Kubernetes version, cache info and the applications deployed to each), get_cluster (get a cluster by server URL or name), invalidate_cluster_cache (rebuild ArgoCD's cache of a cluster), rotate_cluster_auth (rotate the bearer token ArgoCD uses for a cluster), list_repositories (list repositories and credential templates with connection state; credentials are redacted), get_repository (get a repository's connection status), validate_repository_access (check whether ArgoCD can access a repository, optionally with new credentials), list_repo_refs (list the branches and tags of a Git repository), list_repo_apps (find deployable application paths in a Git repository), get_helm_charts (list the charts and versions of a Helm repository), list_applicationsets (list ApplicationSets with generator kinds, application counts and errors), get_applicationset (get an ApplicationSet's generators, template, conditions and the status of each generated application), generate_applicationset_preview (preview the applications an ApplicationSet spec would generate), create_applicationset and delete_applicationset (manage ApplicationSets), wait_for_application (wait until an application is synced, healthy and its operation has finished, with progress notifications; sync_application, rollback_application and refresh_application accept wait=true to do the same). Applications are also exposed as resources (argocd://{{context}}/applications/{{name}} and argocd://{{context}}/applications/{{name}}/resource-tree); subscribing to one watches it through the ArgoCD streaming API and sends a resource-updated notification whenever it changes. Set ARGOCD_BASE_URL and ARGOCD_ACCESS_TOKEN environment variables before starting. Optional: Set ARGOCD_READ_ONLY=true to enforce read-only mode (sync_application, rollback_application, patch_resource, delete_resource, run_resource_action, terminate_operation, create_application, update_application_spec, delete_application, create_project, update_project, delete_project, upsert_project_role, delete_project_role, create_project_token, delete_project_token, invalidate_cluster_cache, rotate_cluster_auth, create_applicationset and delete_applicationset are write operations and blocked in read-only mode).",
             mode_info
         );
 
         ServerInfo {
             protocol_version: ProtocolVersion::V_2024_11_05,
-            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_logging()
+                .enable_resources()
+                .enable_resources_subscribe()
+                .build(),
             server_info: Implementation {
                 name: "argocd-mcp-server".to_string(),
                 version: "0.1.0".to_string(),
@@ -2324,7 +6323,7 @@ mod tests {
     #[tokio::test]
     async fn test_handler_creation() {
         let handler = ArgocdMcpHandler::new();
-        assert!(handler.client.read().await.is_none());
+        assert!(handler.contexts.read().await.is_empty());
     }
 
     #[tokio::test]
@@ -2337,6 +6336,50 @@ mod tests {
             )
             .await;
         assert!(result.is_ok());
-        assert!(handler.client.read().await.is_some());
+        assert_eq!(handler.context_names().await, vec!["default".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_not_found_error_suggests_application() {
+        use wiremock::{
+            matchers::{method, path},
+            Mock, MockServer, ResponseTemplate,
+        };
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/applications/guestbok"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": "applications.argoproj.io \"guestbok\" not found",
+                "code": 5,
+                "message": "applications.argoproj.io \"guestbok\" not found"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/applications"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "items": [
+                    { "metadata": { "name": "guestbook" } },
+                    { "metadata": { "name": "payments" } }
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string()).unwrap();
+        let error = client
+            .get_application("guestbok".to_string(), None, None, None, None)
+            .await
+            .or_api_error(&client, "Failed to get application", Some("guestbok"))
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.code, ErrorCode::RESOURCE_NOT_FOUND);
+        assert!(error.message.contains("Did you mean 'guestbook'?"));
+        let data = error.data.unwrap();
+        assert_eq!(data["kind"], "not_found");
+        assert_eq!(data["grpc_code"], 5);
+        assert_eq!(data["suggestions"], serde_json::json!(["guestbook"]));
     }
 }
@@ -0,0 +1,266 @@
+use anyhow::{Context, Result};
+use axum::{extract::State, routing::get, Json, Router};
+use rmcp::{
+    transport::{
+        sse_server::{SseServer, SseServerConfig},
+        streamable_http_server::{
+            session::{local::LocalSessionManager, SessionManager},
+            StreamableHttpServerConfig, StreamableHttpService,
+        },
+    },
+    ServiceExt,
+};
+use std::{net::SocketAddr, str::FromStr, sync::Arc, time::Duration};
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+use crate::tools::ArgocdMcpHandler;
+
+/// Default bind address for the HTTP based transports
+pub const DEFAULT_BIND_ADDRESS: &str = "127.0.0.1:8080";
+
+/// Path serving the MCP streamable HTTP endpoint
+pub const MCP_PATH: &str = "/mcp";
+
+/// Path opening an SSE stream (legacy SSE transport)
+pub const SSE_PATH: &str = "/sse";
+
+/// Path receiving client messages (legacy SSE transport)
+pub const SSE_POST_PATH: &str = "/message";
+
+/// Path of the health endpoint exposed by the HTTP based transports
+pub const HEALTH_PATH: &str = "/health";
+
+/// Keep-alive interval for SSE connections
+const SSE_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
+/// Transport used to serve the MCP protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportMode {
+    /// Single client over stdin/stdout (default)
+    Stdio,
+    /// MCP streamable HTTP, one session per client
+    StreamableHttp,
+    /// Legacy HTTP + SSE transport
+    Sse,
+}
+
+impl TransportMode {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TransportMode::Stdio => "stdio",
+            TransportMode::StreamableHttp => "streamable-http",
+            TransportMode::Sse => "sse",
+        }
+    }
+}
+
+impl FromStr for TransportMode {
+    type Err = anyhow::Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "stdio" => Ok(TransportMode::Stdio),
+            "http" | "streamable-http" | "streamable_http" => Ok(TransportMode::StreamableHttp),
+            "sse" => Ok(TransportMode::Sse),
+            other => anyhow::bail!(
+                "Unknown transport '{}'. Expected one of: stdio, streamable-http, sse",
+                other
+            ),
+        }
+    }
+}
+
+/// Transport settings (see `config` for how they are resolved)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransportConfig {
+    pub mode: TransportMode,
+    pub bind: SocketAddr,
+}
+
+impl Default for TransportConfig {
+    fn default() -> Self {
+        Self {
+            mode: TransportMode::Stdio,
+            bind: DEFAULT_BIND_ADDRESS.parse().expect("valid default bind address"),
+        }
+    }
+}
+
+/// Parse a listen address such as `0.0.0.0:8080`
+pub fn parse_bind_address(value: &str) -> Result<SocketAddr> {
+    value
+        .trim()
+        .parse()
+        .with_context(|| format!("'{}' is not a valid socket address (e.g. 0.0.0.0:8080)", value))
+}
+
+#[derive(Clone)]
+struct HealthState {
+    transport: TransportMode,
+    read_only: bool,
+}
+
+async fn health(State(state): State<Arc<HealthState>>) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "transport": state.transport.as_str(),
+        "readOnly": state.read_only,
+    }))
+}
+
+fn health_router(transport: TransportMode, read_only: bool) -> Router {
+    Router::new()
+        .route(HEALTH_PATH, get(health))
+        .with_state(Arc::new(HealthState {
+            transport,
+            read_only,
+        }))
+}
+
+/// Build the axum router for the streamable HTTP transport.
+///
+/// Every MCP session gets its own handler instance; all sessions share the
+/// underlying ArgoCD client and application watch streams. Sessions are
+/// closed when `ct` is cancelled, which ends their open SSE streams.
+pub fn streamable_http_router(handler: ArgocdMcpHandler, ct: CancellationToken) -> Router {
+    let read_only = handler.is_read_only();
+    let session_manager = Arc::new(LocalSessionManager::default());
+    tokio::spawn(close_sessions_on_shutdown(session_manager.clone(), ct));
+    let service = StreamableHttpService::new(
+        move || Ok(handler.new_session()),
+        session_manager,
+        StreamableHttpServerConfig {
+            sse_keep_alive: Some(SSE_KEEP_ALIVE),
+            stateful_mode: true,
+        },
+    );
+
+    Router::new()
+        .nest_service(MCP_PATH, service)
+        .merge(health_router(TransportMode::StreamableHttp, read_only))
+}
+
+/// Close every streamable HTTP session once `ct` is cancelled
+///
+/// An open SSE stream would otherwise keep the graceful shutdown waiting.
+async fn close_sessions_on_shutdown(sessions: Arc<LocalSessionManager>, ct: CancellationToken) {
+    ct.cancelled().await;
+    let ids: Vec<_> = sessions.sessions.read().await.keys().cloned().collect();
+    for id in ids {
+        if let Err(e) = sessions.close_session(&id).await {
+            tracing::warn!("Failed to close MCP session {}: {}", id, e);
+        }
+    }
+}
+
+/// Build the axum router for the SSE transport and start dispatching sessions.
+///
+/// Sessions are torn down when `ct` is cancelled.
+pub fn sse_router(handler: ArgocdMcpHandler, bind: SocketAddr, ct: CancellationToken) -> Router {
+    let read_only = handler.is_read_only();
+    let (sse_server, router) = SseServer::new(SseServerConfig {
+        bind,
+        sse_path: SSE_PATH.to_string(),
+        post_path: SSE_POST_PATH.to_string(),
+        ct,
+        sse_keep_alive: Some(SSE_KEEP_ALIVE),
+    });
+    sse_server.with_service(move || handler.new_session());
+
+    router.merge(health_router(TransportMode::Sse, read_only))
+}
+
+/// Serve the handler over an HTTP based transport on an already bound listener.
+///
+/// Returns once `ct` is cancelled and in-flight connections have drained.
+pub async fn serve_http(
+    handler: ArgocdMcpHandler,
+    mode: TransportMode,
+    listener: TcpListener,
+    ct: CancellationToken,
+) -> Result<()> {
+    let bind = listener.local_addr()?;
+    let router = match mode {
+        TransportMode::StreamableHttp => streamable_http_router(handler, ct.child_token()),
+        TransportMode::Sse => sse_router(handler, bind, ct.child_token()),
+        TransportMode::Stdio => anyhow::bail!("stdio transport cannot be served over HTTP"),
+    };
+
+    tracing::info!(
+        "MCP Server listening on http://{} ({} transport)",
+        bind,
+        mode.as_str()
+    );
+
+    axum::serve(listener, router)
+        .with_graceful_shutdown(async move { ct.cancelled().await })
+        .await
+        .context("HTTP server error")?;
+
+    Ok(())
+}
+
+/// Serve the handler using the configured transport until the client
+/// disconnects (stdio) or a shutdown signal is received (HTTP transports).
+pub async fn serve(handler: ArgocdMcpHandler, config: TransportConfig) -> Result<()> {
+    match config.mode {
+        TransportMode::Stdio => {
+            tracing::info!("Starting stdio transport");
+
+            let service = handler.serve(rmcp::transport::stdio()).await.inspect_err(|e| {
+                tracing::error!("Failed to serve: {:?}", e);
+            })?;
+
+            tracing::info!("MCP Server running");
+            service.waiting().await?;
+        }
+        mode => {
+            let listener = TcpListener::bind(config.bind)
+                .await
+                .with_context(|| format!("Failed to bind to {}", config.bind))?;
+
+            let ct = CancellationToken::new();
+            let shutdown = ct.clone();
+            tokio::spawn(async move {
+                shutdown_signal().await;
+                tracing::info!("Shutdown signal received, closing sessions");
+                shutdown.cancel();
+            });
+
+            serve_http(handler, mode, listener, ct).await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::error!("Failed to listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::error!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}
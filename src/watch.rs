@@ -0,0 +1,505 @@
+use anyhow::Result;
+use reqwest::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::{HashMap, VecDeque};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::sync::mpsc::UnboundedSender;
+use tokio::task::JoinHandle;
+
+use crate::argocd_client::ArgocdClient;
+use crate::error::ArgocdError;
+use crate::models::{Application, ApplicationDetailOutput, ApplicationTree, ResourceTreeSummary};
+
+/// Scheme of the MCP resource URIs served for watched applications
+pub const RESOURCE_SCHEME: &str = "argocd://";
+/// Delay before reconnecting a watch stream that ended or failed
+pub const RECONNECT_INITIAL_DELAY: Duration = Duration::from_secs(1);
+/// Upper bound for the reconnect backoff
+pub const RECONNECT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+static NEXT_SUBSCRIBER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Generate a process-unique subscriber ID, one per MCP session
+pub fn next_subscriber_id() -> u64 {
+    NEXT_SUBSCRIBER_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Splits a watch stream into JSON messages
+///
+/// The REST gateway writes one JSON message per line; as server-sent events
+/// each message is a `data:` line. Both forms are accepted; blank lines, SSE
+/// comments and other SSE fields are skipped.
+#[derive(Debug, Default)]
+pub struct StreamDecoder {
+    buffer: Vec<u8>,
+}
+
+impl StreamDecoder {
+    /// Add a chunk of the response body and return the messages it completed
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.buffer.extend_from_slice(chunk);
+        let mut messages = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            messages.extend(Self::payload(&line));
+        }
+        messages
+    }
+
+    /// Return the last message when the stream ended without a newline
+    pub fn finish(&mut self) -> Option<String> {
+        let line = std::mem::take(&mut self.buffer);
+        Self::payload(&line)
+    }
+
+    fn payload(line: &[u8]) -> Option<String> {
+        let line = String::from_utf8_lossy(line);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(':') {
+            return None;
+        }
+        if let Some(data) = line.strip_prefix("data:") {
+            let data = data.trim();
+            return (!data.is_empty()).then(|| data.to_string());
+        }
+        // `event:`, `id:` and `retry:` fields carry nothing we use
+        if line.starts_with('{') {
+            Some(line.to_string())
+        } else {
+            None
+        }
+    }
+}
+
+/// One message of a watch stream: a result or the error that ended the stream
+#[derive(Debug, Deserialize)]
+struct StreamMessage<T> {
+    result: Option<T>,
+    error: Option<StreamError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StreamError {
+    #[serde(default)]
+    message: String,
+    #[serde(default, alias = "grpc_code")]
+    code: Option<i32>,
+    #[serde(default)]
+    http_code: Option<u16>,
+}
+
+/// Decode a stream message; `None` for messages without a result (keep-alives)
+fn decode_message<T: DeserializeOwned>(payload: &str, type_name: &str) -> Result<Option<T>> {
+    let message: StreamMessage<T> =
+        serde_json::from_str(payload).map_err(|e| ArgocdError::Decode {
+            type_name: type_name.to_string(),
+            message: e.to_string(),
+        })?;
+    if let Some(error) = message.error {
+        let status = error
+            .http_code
+            .and_then(|code| StatusCode::from_u16(code).ok())
+            .unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        return Err(ArgocdError::from_response(status, error.code, error.message).into());
+    }
+    Ok(message.result)
+}
+
+/// A stream opened by `ArgocdClient::watch_applications`,
+/// `ArgocdClient::watch_resource_tree` or `ArgocdClient::pod_logs_follow`
+pub struct WatchStream<T> {
+    response: reqwest::Response,
+    decoder: StreamDecoder,
+    pending: VecDeque<String>,
+    type_name: &'static str,
+    finished: bool,
+    _message: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> WatchStream<T> {
+    pub(crate) fn new(response: reqwest::Response, type_name: &'static str) -> Self {
+        Self {
+            response,
+            decoder: StreamDecoder::default(),
+            pending: VecDeque::new(),
+            type_name,
+            finished: false,
+            _message: PhantomData,
+        }
+    }
+
+    /// Wait for the next message; `None` once ArgoCD closes the stream
+    pub async fn next(&mut self) -> Result<Option<T>> {
+        loop {
+            if let Some(payload) = self.pending.pop_front() {
+                match decode_message(&payload, self.type_name)? {
+                    Some(message) => return Ok(Some(message)),
+                    None => continue,
+                }
+            }
+            if self.finished {
+                return Ok(None);
+            }
+            match self
+                .response
+                .chunk()
+                .await
+                .map_err(|e| ArgocdError::from_reqwest(&e))?
+            {
+                Some(chunk) => self.pending.extend(self.decoder.push(&chunk)),
+                None => {
+                    self.finished = true;
+                    self.pending.extend(self.decoder.finish());
+                }
+            }
+        }
+    }
+}
+
+/// What a watched resource URI refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WatchTarget {
+    /// `argocd://{context}/applications/{name}`
+    Application,
+    /// `argocd://{context}/applications/{name}/resource-tree`
+    ResourceTree,
+}
+
+/// An application exposed as an MCP resource
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WatchKey {
+    pub context: String,
+    pub name: String,
+    pub app_namespace: Option<String>,
+    pub target: WatchTarget,
+}
+
+impl WatchKey {
+    /// Parse `argocd://{context}/applications/{name}[/resource-tree][?appNamespace=ns]`
+    pub fn parse(uri: &str) -> Option<Self> {
+        let rest = uri.strip_prefix(RESOURCE_SCHEME)?;
+        let (path, query) = match rest.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (rest, None),
+        };
+
+        let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+        let target = match segments.as_slice() {
+            [_, "applications", _] => WatchTarget::Application,
+            [_, "applications", _, "resource-tree"] => WatchTarget::ResourceTree,
+            _ => return None,
+        };
+        let decode = |s: &str| urlencoding::decode(s).ok().map(|s| s.into_owned());
+        let context = decode(segments[0]).filter(|c| !c.is_empty())?;
+        let name = decode(segments[2]).filter(|n| !n.is_empty())?;
+
+        let app_namespace = query
+            .into_iter()
+            .flat_map(|q| q.split('&'))
+            .filter_map(|pair| pair.strip_prefix("appNamespace="))
+            .filter_map(decode)
+            .find(|ns| !ns.is_empty());
+
+        Some(Self {
+            context,
+            name,
+            app_namespace,
+            target,
+        })
+    }
+
+    /// The resource URI, the inverse of `parse`
+    pub fn uri(&self) -> String {
+        let mut uri = format!(
+            "{}{}/applications/{}",
+            RESOURCE_SCHEME,
+            urlencoding::encode(&self.context),
+            urlencoding::encode(&self.name)
+        );
+        if self.target == WatchTarget::ResourceTree {
+            uri.push_str("/resource-tree");
+        }
+        if let Some(ns) = &self.app_namespace {
+            uri.push_str(&format!("?appNamespace={}", urlencoding::encode(ns)));
+        }
+        uri
+    }
+}
+
+/// Latest state received for a watched resource
+#[derive(Debug, Clone)]
+pub enum WatchSnapshot {
+    Application(Box<Application>),
+    ResourceTree(ApplicationTree),
+    /// The application was deleted while being watched
+    Deleted,
+}
+
+impl WatchSnapshot {
+    /// The summary served as the resource contents
+    pub fn render(&self) -> serde_json::Value {
+        let rendered = match self {
+            Self::Application(app) => {
+                serde_json::to_value(ApplicationDetailOutput::from(app.as_ref().clone()))
+            }
+            Self::ResourceTree(tree) => {
+                serde_json::to_value(ResourceTreeSummary::from(tree.clone()))
+            }
+            Self::Deleted => Ok(serde_json::json!({ "deleted": true })),
+        };
+        rendered.unwrap_or(serde_json::Value::Null)
+    }
+}
+
+/// Receives the URI of a watched resource whenever its contents change
+pub type UpdateSender = UnboundedSender<String>;
+
+struct Watch {
+    subscribers: HashMap<u64, UpdateSender>,
+    /// Cached state and its rendering, compared to detect changes
+    snapshot: Option<(WatchSnapshot, serde_json::Value)>,
+    task: JoinHandle<()>,
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Watch streams shared by all MCP sessions, with the latest state of each
+/// watched application and resource tree
+///
+/// A stream is opened on the first subscription to a resource and closed
+/// when the last subscriber leaves or its update channel is closed.
+#[derive(Clone, Default)]
+pub struct WatchHub {
+    watches: Arc<Mutex<HashMap<WatchKey, Watch>>>,
+}
+
+impl WatchHub {
+    /// Subscribe to a resource, opening its watch stream if needed
+    pub fn subscribe(
+        &self,
+        key: WatchKey,
+        client: ArgocdClient,
+        subscriber: u64,
+        updates: UpdateSender,
+    ) {
+        let mut watches = self.watches.lock().unwrap();
+        let watch = watches.entry(key.clone()).or_insert_with(|| {
+            tracing::info!("Opening watch for {}", key.uri());
+            Watch {
+                subscribers: HashMap::new(),
+                snapshot: None,
+                task: tokio::spawn(run_watch(Arc::downgrade(&self.watches), key, client)),
+            }
+        });
+        watch.subscribers.insert(subscriber, updates);
+    }
+
+    /// Remove a subscription; returns false if there was none
+    pub fn unsubscribe(&self, key: &WatchKey, subscriber: u64) -> bool {
+        let mut watches = self.watches.lock().unwrap();
+        let Some(watch) = watches.get_mut(key) else {
+            return false;
+        };
+        let removed = watch.subscribers.remove(&subscriber).is_some();
+        if watch.subscribers.is_empty() {
+            tracing::info!("Closing watch for {}", key.uri());
+            watches.remove(key);
+        }
+        removed
+    }
+
+    /// Resources a subscriber is subscribed to, sorted by URI
+    pub fn subscriptions(&self, subscriber: u64) -> Vec<WatchKey> {
+        let watches = self.watches.lock().unwrap();
+        let mut keys: Vec<WatchKey> = watches
+            .iter()
+            .filter(|(_, watch)| watch.subscribers.contains_key(&subscriber))
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.sort_by_key(|key| key.uri());
+        keys
+    }
+
+    /// Cached state of a watched resource, once its stream has delivered it
+    pub fn snapshot(&self, key: &WatchKey) -> Option<WatchSnapshot> {
+        let watches = self.watches.lock().unwrap();
+        watches
+            .get(key)
+            .and_then(|watch| watch.snapshot.as_ref())
+            .map(|(snapshot, _)| snapshot.clone())
+    }
+}
+
+/// Store a new state and notify subscribers if the rendered contents changed
+///
+/// Returns false once the watch has been closed.
+fn publish(
+    watches: &Weak<Mutex<HashMap<WatchKey, Watch>>>,
+    key: &WatchKey,
+    snapshot: WatchSnapshot,
+) -> bool {
+    let Some(watches) = watches.upgrade() else {
+        return false;
+    };
+    let mut watches = watches.lock().unwrap();
+    let Some(watch) = watches.get_mut(key) else {
+        return false;
+    };
+
+    let rendered = snapshot.render();
+    // The first state is what a subscriber reads, not an update
+    let changed = watch
+        .snapshot
+        .as_ref()
+        .is_some_and(|(_, previous)| *previous != rendered);
+    watch.snapshot = Some((snapshot, rendered));
+
+    if changed {
+        let uri = key.uri();
+        tracing::debug!("Watched resource changed: {}", uri);
+        watch
+            .subscribers
+            .retain(|_, updates| updates.send(uri.clone()).is_ok());
+        if watch.subscribers.is_empty() {
+            tracing::info!("Closing watch for {}: no subscribers left", uri);
+            watches.remove(key);
+            return false;
+        }
+    }
+    true
+}
+
+/// Keep a watch stream open, reconnecting with backoff when it ends or fails
+async fn run_watch(
+    watches: Weak<Mutex<HashMap<WatchKey, Watch>>>,
+    key: WatchKey,
+    client: ArgocdClient,
+) {
+    let mut delay = RECONNECT_INITIAL_DELAY;
+    loop {
+        let result = match key.target {
+            WatchTarget::Application => stream_application(&watches, &key, &client).await,
+            WatchTarget::ResourceTree => stream_resource_tree(&watches, &key, &client).await,
+        };
+        match result {
+            Ok(None) => return,
+            // A stream that delivered something was healthy; start the backoff over
+            Ok(Some(true)) => delay = RECONNECT_INITIAL_DELAY,
+            Ok(Some(false)) => {}
+            Err(e) => tracing::warn!("Watch stream for {} failed: {}", key.uri(), e),
+        }
+        tracing::debug!(
+            "Reconnecting watch for {} in {}ms",
+            key.uri(),
+            delay.as_millis()
+        );
+        tokio::time::sleep(delay).await;
+        delay = (delay * 2).min(RECONNECT_MAX_DELAY);
+    }
+}
+
+/// Consume one application stream; `None` when the watch was closed,
+/// otherwise whether any event was received
+async fn stream_application(
+    watches: &Weak<Mutex<HashMap<WatchKey, Watch>>>,
+    key: &WatchKey,
+    client: &ArgocdClient,
+) -> Result<Option<bool>> {
+    let mut stream = client
+        .watch_applications(Some(key.name.clone()), key.app_namespace.clone(), None, None)
+        .await?;
+    let mut received = false;
+    while let Some(event) = stream.next().await? {
+        // The name filter does not pin the namespace when none was given
+        let name = event.application.metadata.as_ref().map(|m| m.name.as_str());
+        if name != Some(key.name.as_str()) {
+            continue;
+        }
+        received = true;
+        let snapshot = if event.event_type == "DELETED" {
+            WatchSnapshot::Deleted
+        } else {
+            WatchSnapshot::Application(Box::new(event.application))
+        };
+        if !publish(watches, key, snapshot) {
+            return Ok(None);
+        }
+    }
+    Ok(Some(received))
+}
+
+/// Consume one resource tree stream, as `stream_application`
+async fn stream_resource_tree(
+    watches: &Weak<Mutex<HashMap<WatchKey, Watch>>>,
+    key: &WatchKey,
+    client: &ArgocdClient,
+) -> Result<Option<bool>> {
+    let mut stream = client
+        .watch_resource_tree(key.name.clone(), key.app_namespace.clone(), None)
+        .await?;
+    let mut received = false;
+    while let Some(tree) = stream.next().await? {
+        received = true;
+        if !publish(watches, key, WatchSnapshot::ResourceTree(tree)) {
+            return Ok(None);
+        }
+    }
+    Ok(Some(received))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decoder_accepts_sse_and_ndjson() {
+        let mut decoder = StreamDecoder::default();
+        let mut messages =
+            decoder.push(b": keep-alive\n\nevent: message\ndata: {\"a\":1}\n\n{\"b\"");
+        assert_eq!(messages, ["{\"a\":1}"]);
+
+        messages = decoder.push(b":2}\n{\"c\":3}");
+        assert_eq!(messages, ["{\"b\":2}"]);
+        assert_eq!(decoder.finish().as_deref(), Some("{\"c\":3}"));
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[test]
+    fn test_stream_error_message() {
+        let payload = r#"{"error":{"grpc_code":5,"http_code":404,"message":"app not found"}}"#;
+        let error = decode_message::<serde_json::Value>(payload, "Value").unwrap_err();
+        assert!(matches!(
+            ArgocdError::from_anyhow(&error),
+            Some(ArgocdError::NotFound(_))
+        ));
+        assert!(decode_message::<serde_json::Value>("{}", "Value")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_watch_key_round_trip() {
+        let uri = "argocd://prod/applications/guestbook/resource-tree?appNamespace=team-a";
+        let key = WatchKey::parse(uri).unwrap();
+        assert_eq!(key.context, "prod");
+        assert_eq!(key.name, "guestbook");
+        assert_eq!(key.app_namespace.as_deref(), Some("team-a"));
+        assert_eq!(key.target, WatchTarget::ResourceTree);
+        assert_eq!(key.uri(), uri);
+
+        let key = WatchKey::parse("argocd://default/applications/guestbook").unwrap();
+        assert_eq!(key.target, WatchTarget::Application);
+        assert_eq!(key.app_namespace, None);
+
+        assert!(WatchKey::parse("argocd://default/projects/guestbook").is_none());
+        assert!(WatchKey::parse("argocd://default/applications/").is_none());
+        assert!(WatchKey::parse("file:///applications/guestbook").is_none());
+    }
+}
@@ -0,0 +1,380 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::models::{Application, ApplicationSpec, ObjectMeta};
+
+fn guestbook_spec() -> serde_json::Value {
+    json!({
+        "project": "default",
+        "source": {
+            "repoURL": "https://github.com/argoproj/argocd-example-apps",
+            "path": "guestbook",
+            "targetRevision": "HEAD"
+        },
+        "destination": {
+            "server": "https://kubernetes.default.svc",
+            "namespace": "guestbook"
+        },
+        "syncPolicy": {
+            "automated": { "prune": true, "selfHeal": true },
+            "syncOptions": ["CreateNamespace=true"]
+        }
+    })
+}
+
+fn guestbook(spec: serde_json::Value) -> Result<Application> {
+    Ok(Application {
+        metadata: Some(ObjectMeta {
+            name: "guestbook".to_string(),
+            namespace: Some("argocd".to_string()),
+            ..ObjectMeta::default()
+        }),
+        spec: Some(serde_json::from_value(spec)?),
+        status: None,
+    })
+}
+
+async fn mount_not_found(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "applications.argoproj.io \"guestbook\" not found",
+            "code": 5,
+            "message": "applications.argoproj.io \"guestbook\" not found"
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_application() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications"))
+        .and(query_param("upsert", "true"))
+        .and(query_param("validate", "false"))
+        .and(body_json(json!({
+            "metadata": { "name": "guestbook", "namespace": "argocd" },
+            "spec": guestbook_spec()
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "name": "guestbook", "namespace": "argocd" },
+            "spec": guestbook_spec()
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .create_application(guestbook(guestbook_spec())?, Some(true), Some(false), None)
+        .await?;
+
+    assert_eq!(summary.name, "guestbook");
+    assert!(!summary.dry_run);
+    assert!(summary.upsert);
+    assert!(!summary.validate);
+    assert_eq!(
+        summary.spec.sources,
+        ["https://github.com/argoproj/argocd-example-apps guestbook@HEAD"]
+    );
+    assert!(summary.spec.automated_sync);
+    assert_eq!(summary.spec.sync_options, ["CreateNamespace=true"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_application_dry_run_reports_problems() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_not_found(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let mut spec = guestbook_spec();
+    spec.as_object_mut().unwrap().remove("destination");
+    spec["sources"] = json!([{ "repoURL": "https://charts.example.com", "chart": "redis" }]);
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .create_application(guestbook(spec)?, None, None, Some(true))
+        .await?;
+
+    assert!(summary.dry_run);
+    assert_eq!(summary.already_exists, Some(false));
+    assert!(summary
+        .problems
+        .contains(&"spec.destination is required".to_string()));
+    assert!(summary
+        .problems
+        .contains(&"set either spec.source or spec.sources, not both".to_string()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_application_dry_run_existing_without_upsert() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .and(query_param("appNamespace", "argocd"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "name": "guestbook" },
+            "spec": guestbook_spec()
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .create_application(guestbook(guestbook_spec())?, None, None, Some(true))
+        .await?;
+    assert_eq!(summary.already_exists, Some(true));
+    assert_eq!(summary.problems.len(), 1);
+    assert!(summary.problems[0].contains("set upsert"));
+
+    let summary = client
+        .create_application(guestbook(guestbook_spec())?, Some(true), None, Some(true))
+        .await?;
+    assert!(summary.problems.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_application_spec() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let mut new_spec = guestbook_spec();
+    new_spec["source"]["targetRevision"] = json!("v2.0.0");
+    new_spec["revisionHistoryLimit"] = json!(3);
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "name": "guestbook" },
+            "spec": guestbook_spec()
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/applications/guestbook/spec"))
+        .and(query_param("validate", "true"))
+        .and(body_json(&new_spec))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&new_spec))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .update_application_spec(
+            "guestbook".to_string(),
+            serde_json::from_value(new_spec)?,
+            Some(true),
+            None,
+            None,
+            None,
+        )
+        .await?;
+
+    assert!(!summary.dry_run);
+    assert_eq!(summary.changed_fields, ["revisionHistoryLimit", "source"]);
+    assert_eq!(
+        summary.spec.sources,
+        ["https://github.com/argoproj/argocd-example-apps guestbook@v2.0.0"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_application_spec_dry_run() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "name": "guestbook" },
+            "spec": guestbook_spec()
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/applications/guestbook/spec"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .update_application_spec(
+            "guestbook".to_string(),
+            serde_json::from_value(guestbook_spec())?,
+            None,
+            Some(true),
+            None,
+            None,
+        )
+        .await?;
+
+    assert!(summary.dry_run);
+    assert!(summary.changed_fields.is_empty());
+    assert!(summary.problems.is_empty());
+
+    Ok(())
+}
+
+fn guestbook_tree() -> serde_json::Value {
+    json!({
+        "nodes": [
+            { "kind": "Deployment", "name": "guestbook-ui", "namespace": "guestbook" },
+            { "kind": "ReplicaSet", "name": "guestbook-ui-5d8f", "namespace": "guestbook" },
+            { "kind": "Pod", "name": "guestbook-ui-5d8f-x2k", "namespace": "guestbook" },
+            { "kind": "Pod", "name": "guestbook-ui-5d8f-a7q", "namespace": "guestbook" }
+        ]
+    })
+}
+
+#[tokio::test]
+async fn test_delete_application() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/resource-tree"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(guestbook_tree()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook"))
+        .and(query_param("cascade", "true"))
+        .and(query_param("propagationPolicy", "foreground"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .delete_application(
+            "guestbook".to_string(),
+            Some(true),
+            Some("foreground".to_string()),
+            None,
+            None,
+            None,
+        )
+        .await?;
+
+    assert!(!summary.dry_run);
+    assert!(summary.cascade);
+    assert_eq!(summary.resource_count, Some(4));
+    assert_eq!(
+        summary.resources_by_kind,
+        ["Deployment: 1", "Pod: 2", "ReplicaSet: 1"]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_application_dry_run() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/resource-tree"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(guestbook_tree()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .delete_application(
+            "guestbook".to_string(),
+            Some(false),
+            None,
+            Some(true),
+            None,
+            None,
+        )
+        .await?;
+
+    assert!(summary.dry_run);
+    assert!(!summary.cascade);
+    assert_eq!(summary.resource_count, Some(4));
+
+    Ok(())
+}
+
+#[test]
+fn test_full_spec_round_trip() -> Result<()> {
+    let spec = json!({
+        "project": "platform",
+        "sources": [
+            {
+                "repoURL": "https://charts.bitnami.com/bitnami",
+                "chart": "redis",
+                "targetRevision": "19.0.1",
+                "helm": {
+                    "releaseName": "cache",
+                    "valueFiles": ["$values/redis/values-prod.yaml"],
+                    "parameters": [{ "name": "replica.replicaCount", "value": "3" }],
+                    "valuesObject": { "auth": { "enabled": false } }
+                }
+            },
+            {
+                "repoURL": "https://github.com/example/values",
+                "targetRevision": "main",
+                "ref": "values"
+            },
+            {
+                "repoURL": "https://github.com/example/overlays",
+                "path": "redis/prod",
+                "kustomize": {
+                    "images": ["redis=redis:7.2"],
+                    "commonLabels": { "team": "platform" },
+                    "replicas": [{ "name": "redis", "count": 2 }]
+                }
+            }
+        ],
+        "destination": { "name": "prod-eu", "namespace": "cache" },
+        "syncPolicy": {
+            "automated": { "prune": true, "allowEmpty": false },
+            "syncOptions": ["CreateNamespace=true", "ServerSideApply=true"],
+            "retry": {
+                "limit": 5,
+                "backoff": { "duration": "5s", "factor": 2, "maxDuration": "3m" }
+            },
+            "managedNamespaceMetadata": { "labels": { "istio-injection": "enabled" } }
+        },
+        "ignoreDifferences": [
+            { "group": "apps", "kind": "Deployment", "jsonPointers": ["/spec/replicas"] }
+        ],
+        "info": [{ "name": "Owner", "value": "platform-team" }],
+        "revisionHistoryLimit": 5
+    });
+
+    let parsed: ApplicationSpec = serde_json::from_value(spec.clone())?;
+    assert!(parsed.problems().is_empty());
+    assert_eq!(serde_json::to_value(&parsed)?, spec);
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+
+fn app_with_status(status: serde_json::Value) -> serde_json::Value {
+    json!({
+        "metadata": { "name": "payments", "namespace": "argocd" },
+        "spec": {
+            "project": "default",
+            "source": { "repoURL": "https://github.com/example/payments.git", "path": "deploy", "targetRevision": "main" },
+            "destination": { "server": "https://kubernetes.default.svc", "namespace": "payments" }
+        },
+        "status": status
+    })
+}
+
+#[tokio::test]
+async fn test_get_application_failed_sync_details() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/payments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(app_with_status(json!({
+            "sync": { "status": "OutOfSync", "revision": "9f8e7d6c" },
+            "health": { "status": "Degraded" },
+            "reconciledAt": "2026-10-17T09:00:00Z",
+            "sourceType": "Kustomize",
+            "conditions": [
+                { "type": "OrphanedResourceWarning", "message": "Application has 1 orphaned resources" },
+                {
+                    "type": "ComparisonError",
+                    "message": "Failed to load target state: kustomize build failed",
+                    "lastTransitionTime": "2026-10-17T08:59:00Z"
+                }
+            ],
+            "operationState": {
+                "phase": "Failed",
+                "message": "one or more synchronization tasks completed unsuccessfully",
+                "startedAt": "2026-10-17T08:55:00Z",
+                "finishedAt": "2026-10-17T08:56:00Z",
+                "retryCount": 2,
+                "operation": {
+                    "sync": { "revision": "9f8e7d6c", "prune": true },
+                    "initiatedBy": { "username": "alice" }
+                },
+                "syncResult": {
+                    "revision": "9f8e7d6c",
+                    "resources": [
+                        { "group": "", "version": "v1", "kind": "Service", "namespace": "payments", "name": "api", "status": "Synced", "syncPhase": "Sync" },
+                        {
+                            "group": "apps", "version": "v1", "kind": "Deployment", "namespace": "payments", "name": "api",
+                            "status": "SyncFailed", "message": "admission webhook denied the request", "syncPhase": "Sync"
+                        },
+                        {
+                            "group": "batch", "version": "v1", "kind": "Job", "namespace": "payments", "name": "db-migrate",
+                            "hookType": "PreSync", "hookPhase": "Succeeded", "syncPhase": "PreSync", "status": "Synced"
+                        },
+                        {
+                            "group": "batch", "version": "v1", "kind": "Job", "namespace": "payments", "name": "smoke-test",
+                            "hookType": "PostSync", "hookPhase": "Failed", "message": "Job has reached the specified backoff limit", "syncPhase": "PostSync"
+                        }
+                    ]
+                }
+            },
+            "resources": [
+                { "version": "v1", "kind": "Service", "namespace": "payments", "name": "api", "status": "Synced", "health": { "status": "Healthy" } },
+                {
+                    "group": "apps", "version": "v1", "kind": "Deployment", "namespace": "payments", "name": "api",
+                    "status": "OutOfSync", "health": { "status": "Degraded", "message": "Deployment exceeded its progress deadline" }
+                },
+                { "version": "v1", "kind": "ConfigMap", "namespace": "payments", "name": "api-config", "status": "Synced" }
+            ]
+        }))))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let detail = client
+        .get_application("payments".to_string(), None, None, None, None)
+        .await?;
+
+    assert_eq!(detail.source_type.as_deref(), Some("Kustomize"));
+    assert_eq!(
+        detail.reconciled_at.as_deref(),
+        Some("2026-10-17T09:00:00Z")
+    );
+
+    // Errors are listed before warnings
+    assert_eq!(detail.conditions[0].condition_type, "ComparisonError");
+    assert!(detail.conditions[0].is_error());
+    assert!(!detail.conditions[1].is_error());
+
+    let operation = detail.operation.expect("operation state is summarized");
+    assert_eq!(operation.phase, "Failed");
+    assert!(!operation.in_progress);
+    assert_eq!(operation.initiated_by.as_deref(), Some("alice"));
+    assert_eq!(operation.revision.as_deref(), Some("9f8e7d6c"));
+    assert_eq!(operation.retry_count, Some(2));
+    assert_eq!(operation.result_counts.get("Synced"), Some(&2));
+    assert_eq!(operation.result_counts.get("SyncFailed"), Some(&1));
+    assert_eq!(operation.result_counts.get("Unknown"), Some(&1));
+
+    // Plain successes are only counted; failures and hooks are listed
+    let listed: Vec<&str> = operation
+        .resource_results
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect();
+    assert_eq!(listed, ["api", "db-migrate", "smoke-test"]);
+    assert!(operation.resource_results[2].is_failure());
+    assert_eq!(
+        operation.resource_results[2].hook_phase.as_deref(),
+        Some("Failed")
+    );
+
+    assert_eq!(detail.resources_total, 3);
+    assert_eq!(detail.resource_issues.len(), 1);
+    assert_eq!(
+        detail.resource_issues[0].kind.as_deref(),
+        Some("Deployment")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_application_running_operation() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/payments"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(app_with_status(json!({
+                "sync": { "status": "OutOfSync" },
+                "sourceTypes": ["Helm", "Directory"],
+                "operationState": {
+                    "phase": "Running",
+                    "message": "waiting for healthy state of apps/Deployment/api",
+                    "startedAt": "2026-10-17T09:10:00Z",
+                    "operation": {
+                        "sync": { "revisions": ["1.2.0", "abc123"] },
+                        "initiatedBy": { "automated": true }
+                    }
+                }
+            }))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let detail = client
+        .get_application("payments".to_string(), None, None, None, None)
+        .await?;
+
+    assert_eq!(detail.source_type.as_deref(), Some("Helm, Directory"));
+    assert!(detail.conditions.is_empty());
+    assert_eq!(detail.resources_total, 0);
+
+    let operation = detail.operation.expect("operation state is summarized");
+    assert!(operation.in_progress);
+    assert_eq!(operation.initiated_by.as_deref(), Some("Automated"));
+    assert_eq!(operation.revisions, ["1.2.0", "abc123"]);
+    assert!(operation.result_counts.is_empty());
+
+    Ok(())
+}
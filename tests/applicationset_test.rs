@@ -0,0 +1,263 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+
+fn appset_spec() -> serde_json::Value {
+    json!({
+        "goTemplate": true,
+        "generators": [{
+            "matrix": {
+                "generators": [
+                    {
+                        "git": {
+                            "repoURL": "https://github.com/example/apps.git",
+                            "revision": "main",
+                            "directories": [{ "path": "services/*" }]
+                        }
+                    },
+                    { "clusters": { "selector": { "matchLabels": { "env": "prod" } } } }
+                ]
+            }
+        }],
+        "template": {
+            "metadata": { "name": "{{.path.basename}}-{{.name}}" },
+            "spec": {
+                "project": "platform",
+                "source": {
+                    "repoURL": "https://github.com/example/apps.git",
+                    "targetRevision": "main",
+                    "path": "{{.path.path}}"
+                },
+                "destination": { "server": "{{.server}}", "namespace": "{{.path.basename}}" }
+            }
+        },
+        "syncPolicy": { "preserveResourcesOnDeletion": true },
+        "strategy": { "type": "RollingSync" }
+    })
+}
+
+fn appset_json() -> serde_json::Value {
+    json!({
+        "metadata": { "name": "services", "namespace": "argocd" },
+        "spec": appset_spec(),
+        "status": {
+            "conditions": [
+                {
+                    "type": "ErrorOccurred",
+                    "status": "False",
+                    "message": "Successfully generated parameters for all Applications"
+                },
+                { "type": "ResourcesUpToDate", "status": "True", "message": "All applications have been generated successfully" }
+            ],
+            "applicationStatus": [
+                { "application": "api-prod", "status": "Progressing", "step": "1", "message": "Application resource became Progressing" }
+            ],
+            "resources": [
+                {
+                    "group": "argoproj.io", "version": "v1alpha1", "kind": "Application",
+                    "name": "api-prod", "namespace": "argocd", "status": "Synced",
+                    "health": { "status": "Progressing", "message": "Waiting for rollout" }
+                },
+                {
+                    "group": "argoproj.io", "version": "v1alpha1", "kind": "Application",
+                    "name": "web-prod", "namespace": "argocd", "status": "OutOfSync",
+                    "health": { "status": "Healthy" }
+                }
+            ]
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_list_applicationsets() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applicationsets"))
+        .and(query_param("projects", "platform"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [
+                appset_json(),
+                {
+                    "metadata": { "name": "broken" },
+                    "spec": { "generators": [{ "list": { "elements": [] } }] },
+                    "status": { "conditions": [{
+                        "type": "ErrorOccurred",
+                        "status": "True",
+                        "message": "failed to execute go template: map has no entry for key \"cluster\""
+                    }] }
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let appsets = client
+        .list_applicationsets(Some(vec!["platform".to_string()]), None, None)
+        .await?;
+
+    assert_eq!(appsets[0].generators, ["matrix"]);
+    assert_eq!(appsets[0].project.as_deref(), Some("platform"));
+    assert_eq!(appsets[0].applications_count, 2);
+    assert_eq!(appsets[0].error, None);
+    assert!(appsets[1]
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("no entry for key"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_applicationset_detail() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applicationsets/services"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(appset_json()))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let appset = client
+        .get_applicationset("services".to_string(), None)
+        .await?;
+
+    assert_eq!(
+        appset.generators,
+        ["matrix [git https://github.com/example/apps.git@main directories: services/*; clusters matching {\"env\":\"prod\"}]"]
+    );
+    assert!(appset.go_template);
+    assert!(appset.progressive_sync);
+    assert!(appset.preserve_resources_on_deletion);
+    assert_eq!(
+        appset.template_name.as_deref(),
+        Some("{{.path.basename}}-{{.name}}")
+    );
+    assert_eq!(
+        appset.template.as_ref().unwrap().project.as_deref(),
+        Some("platform")
+    );
+    assert_eq!(appset.conditions.len(), 2);
+
+    let api = &appset.applications[0];
+    assert_eq!(api.health_status.as_deref(), Some("Progressing"));
+    assert_eq!(api.health_message.as_deref(), Some("Waiting for rollout"));
+    assert_eq!(api.rollout_status.as_deref(), Some("Progressing"));
+    assert_eq!(api.rollout_step.as_deref(), Some("1"));
+    assert_eq!(
+        appset.applications[1].sync_status.as_deref(),
+        Some("OutOfSync")
+    );
+    assert_eq!(appset.applications[1].rollout_status, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_generate_applicationset_preview() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applicationsets/generate"))
+        .and(body_partial_json(json!({
+            "applicationSet": { "metadata": { "name": "services" }, "spec": { "goTemplate": true } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "applications": [{
+                "metadata": { "name": "api-prod" },
+                "spec": {
+                    "project": "platform",
+                    "source": {
+                        "repoURL": "https://github.com/example/apps.git",
+                        "targetRevision": "main",
+                        "path": "services/api"
+                    },
+                    "destination": { "server": "https://10.0.0.1:6443", "namespace": "api" }
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let preview = client
+        .generate_applicationset_preview("services".to_string(), None, appset_spec())
+        .await?;
+
+    assert_eq!(preview.applications.len(), 1);
+    let app = &preview.applications[0];
+    assert_eq!(app.name, "api-prod");
+    let spec = app.spec.as_ref().unwrap();
+    assert_eq!(
+        spec.sources,
+        ["https://github.com/example/apps.git services/api@main"]
+    );
+    assert_eq!(spec.destination_namespace.as_deref(), Some("api"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_and_delete_applicationset() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applicationsets"))
+        .and(query_param("upsert", "true"))
+        .and(query_param("dryRun", "true"))
+        .and(body_partial_json(json!({
+            "metadata": { "name": "services", "namespace": "argocd" },
+            "spec": { "strategy": { "type": "RollingSync" } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(appset_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applicationsets/services"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(appset_json()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applicationsets/services"))
+        .and(query_param("appsetNamespace", "argocd"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let created = client
+        .create_applicationset(
+            "services".to_string(),
+            Some("argocd".to_string()),
+            appset_spec(),
+            Some(true),
+            Some(true),
+        )
+        .await?;
+    assert_eq!(created.applications.len(), 2);
+
+    // A dry run only reads the ApplicationSet
+    let preview = client
+        .delete_applicationset("services".to_string(), Some("argocd".to_string()), true)
+        .await?;
+    assert_eq!(preview.applications, ["api-prod", "web-prod"]);
+
+    let deleted = client
+        .delete_applicationset("services".to_string(), Some("argocd".to_string()), false)
+        .await?;
+    assert!(!deleted.dry_run);
+    assert!(deleted.preserve_resources_on_deletion);
+
+    Ok(())
+}
@@ -0,0 +1,387 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use base64::Engine;
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use wiremock::{
+    matchers::{body_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::{ArgocdClient, ClientOptions};
+use argocd_mcp_server::auth::{CredentialProvider, Credentials, ExecConfig, SessionToken};
+use argocd_mcp_server::error::ArgocdError;
+
+fn login_client(base_url: String) -> Result<ArgocdClient> {
+    ArgocdClient::with_credentials(
+        base_url,
+        Credentials::Login {
+            username: "admin".to_string(),
+            password: "secret".to_string(),
+        },
+        ClientOptions::default(),
+    )
+}
+
+/// Unsigned JWT expiring `expires_in` seconds from now
+fn jwt_expiring_in(expires_in: u64) -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let encode = |value: &[u8]| base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value);
+    format!(
+        "{}.{}.signature",
+        encode(br#"{"alg":"HS256","typ":"JWT"}"#),
+        encode(
+            json!({ "sub": "admin", "exp": now + expires_in })
+                .to_string()
+                .as_bytes()
+        )
+    )
+}
+
+async fn mount_applications(mock_server: &MockServer, token: &str) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .and(header(
+            "Authorization",
+            format!("Bearer {}", token).as_str(),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_login_once_and_reuse_session() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let token = jwt_expiring_in(3600);
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/session"))
+        .and(body_json(
+            json!({ "username": "admin", "password": "secret" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": token })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mount_applications(&mock_server, &token).await;
+
+    let client = login_client(mock_server.uri())?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_renew_session_before_expiry() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    // Inside the refresh margin, so every call logs in again
+    let token = jwt_expiring_in(30);
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/session"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": token })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    mount_applications(&mock_server, &token).await;
+
+    let client = login_client(mock_server.uri())?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_relogin_after_unauthorized() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/session"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": "revoked" })))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/session"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": "renewed" })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .and(header("Authorization", "Bearer revoked"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "invalid session: token is expired",
+            "code": 16,
+            "message": "invalid session: token is expired"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mount_applications(&mock_server, "renewed").await;
+
+    let client = login_client(mock_server.uri())?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_static_token_unauthorized_is_not_retried() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "expired-token".to_string())?;
+    let err = client
+        .list_applications(None, None, None, None, None)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        ArgocdError::from_anyhow(&err).unwrap().kind(),
+        "unauthenticated"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_login_failure() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/session"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error": "Invalid username or password",
+            "code": 16,
+            "message": "Invalid username or password"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = login_client(mock_server.uri())?;
+    let err = client
+        .list_applications(None, None, None, None, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        ArgocdError::from_anyhow(&err).unwrap().kind(),
+        "unauthenticated"
+    );
+    assert!(err.to_string().contains("Invalid username or password"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_login_without_token_is_an_error() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/session"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": "" })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = login_client(mock_server.uri())?;
+    let err = client
+        .list_applications(None, None, None, None, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(ArgocdError::from_anyhow(&err).unwrap().kind(), "decode");
+    assert!(err.to_string().contains("no session token"));
+
+    Ok(())
+}
+
+#[test]
+fn test_login_requires_username_and_password() {
+    let result = ArgocdClient::with_credentials(
+        "https://argocd.example.com".to_string(),
+        Credentials::Login {
+            username: "admin".to_string(),
+            password: String::new(),
+        },
+        ClientOptions::default(),
+    );
+    assert!(result.is_err());
+}
+
+fn shell(script: &str) -> Credentials {
+    Credentials::Exec(ExecConfig {
+        command: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        ..ExecConfig::default()
+    })
+}
+
+#[tokio::test]
+async fn test_token_file_reread_after_rotation() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_applications(&mock_server, "token-one").await;
+    mount_applications(&mock_server, "token-rotated").await;
+
+    let token_path =
+        std::env::temp_dir().join(format!("argocd-mcp-{}-rotating-token", std::process::id()));
+    std::fs::write(&token_path, "token-one\n")?;
+
+    let client = ArgocdClient::with_credentials(
+        mock_server.uri(),
+        Credentials::TokenFile(token_path.clone()),
+        ClientOptions::default(),
+    )?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    std::fs::write(&token_path, "token-rotated\n")?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    let received = mock_server.received_requests().await.unwrap();
+    let authorization: Vec<_> = received
+        .iter()
+        .map(|r| r.headers.get("Authorization").unwrap().to_str().unwrap())
+        .collect();
+    assert_eq!(authorization, ["Bearer token-one", "Bearer token-rotated"]);
+
+    std::fs::remove_file(token_path)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exec_token_cached_until_expiry() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let token = jwt_expiring_in(3600);
+    mount_applications(&mock_server, &token).await;
+
+    let counter = std::env::temp_dir().join(format!("argocd-mcp-{}-exec-runs", std::process::id()));
+    let _ = std::fs::remove_file(&counter);
+    let credential = json!({
+        "apiVersion": "client.authentication.k8s.io/v1",
+        "kind": "ExecCredential",
+        "status": { "token": token }
+    });
+    let client = ArgocdClient::with_credentials(
+        mock_server.uri(),
+        shell(&format!(
+            "echo run >> {} && echo '{}'",
+            counter.display(),
+            credential
+        )),
+        ClientOptions::default(),
+    )?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    assert_eq!(std::fs::read_to_string(&counter)?.lines().count(), 1);
+
+    std::fs::remove_file(counter)?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_exec_token_failure() -> Result<()> {
+    let client = ArgocdClient::with_credentials(
+        "http://127.0.0.1:1".to_string(),
+        shell("echo 'vault is sealed' >&2; exit 2"),
+        ClientOptions::default(),
+    )?;
+    let err = client
+        .list_applications(None, None, None, None, None)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        ArgocdError::from_anyhow(&err).unwrap().kind(),
+        "credentials"
+    );
+    assert!(err.to_string().contains("vault is sealed"));
+
+    Ok(())
+}
+
+/// Provider handing out a new token every time the previous one is rejected
+struct CountingProvider {
+    generation: AtomicUsize,
+}
+
+#[async_trait]
+impl CredentialProvider for CountingProvider {
+    async fn token(&self) -> Result<SessionToken, ArgocdError> {
+        let generation = self.generation.load(Ordering::SeqCst);
+        Ok(SessionToken::new(format!("token-{}", generation)))
+    }
+
+    async fn invalidate(&self, _rejected: &str) -> bool {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        true
+    }
+
+    fn describe(&self) -> String {
+        "counting provider".to_string()
+    }
+}
+
+#[tokio::test]
+async fn test_custom_provider_refreshed_after_unauthorized() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .and(header("Authorization", "Bearer token-0"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mount_applications(&mock_server, "token-1").await;
+
+    let client = ArgocdClient::with_provider(
+        mock_server.uri(),
+        Arc::new(CountingProvider {
+            generation: AtomicUsize::new(0),
+        }),
+        ClientOptions::default(),
+    )?;
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    Ok(())
+}
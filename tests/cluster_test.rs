@@ -0,0 +1,189 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+
+fn cluster_json() -> serde_json::Value {
+    json!({
+        "server": "https://10.0.0.1:6443",
+        "name": "prod",
+        "namespaces": ["payments", "orders"],
+        "shard": 1,
+        "labels": { "env": "prod" },
+        "config": {
+            "bearerToken": "super-secret-token",
+            "tlsClientConfig": { "insecure": false, "keyData": "c2VjcmV0LWtleQ==" }
+        },
+        "info": {
+            "connectionState": {
+                "status": "Successful",
+                "attemptedAt": "2025-01-01T10:00:00Z"
+            },
+            "serverVersion": "1.29",
+            "cacheInfo": {
+                "resourcesCount": 1532,
+                "apisCount": 87,
+                "lastCacheSyncTime": "2025-01-01T09:58:00Z"
+            },
+            "applicationsCount": 2,
+            "apiVersions": ["v1", "apps/v1"]
+        }
+    })
+}
+
+fn application(name: &str, destination: serde_json::Value) -> serde_json::Value {
+    json!({
+        "metadata": { "name": name },
+        "spec": { "project": "default", "destination": destination }
+    })
+}
+
+async fn mount_applications(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [
+                application("payments", json!({ "server": "https://10.0.0.1:6443", "namespace": "payments" })),
+                application("orders", json!({ "name": "prod", "namespace": "orders" })),
+                application("guestbook", json!({ "server": "https://kubernetes.default.svc", "namespace": "default" }))
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_list_clusters_cross_references_applications() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/clusters"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [
+                cluster_json(),
+                {
+                    "server": "https://kubernetes.default.svc",
+                    "name": "in-cluster",
+                    "connectionState": { "status": "Failed", "message": "dial tcp: i/o timeout" }
+                }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    mount_applications(&mock_server).await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let clusters = client.list_clusters().await?;
+
+    assert_eq!(clusters.len(), 2);
+    let prod = &clusters[0];
+    assert_eq!(prod.connection_status.as_deref(), Some("Successful"));
+    assert_eq!(prod.kubernetes_version.as_deref(), Some("1.29"));
+    assert_eq!(prod.resources_count, Some(1532));
+    assert_eq!(prod.apis_count, Some(87));
+    assert_eq!(prod.namespaces, ["payments", "orders"]);
+    // Matched by server URL and by cluster name
+    assert_eq!(
+        prod.applications,
+        ["orders -> orders", "payments -> payments"]
+    );
+
+    // Older ArgoCD versions report the connection state at the top level
+    let in_cluster = &clusters[1];
+    assert_eq!(in_cluster.connection_status.as_deref(), Some("Failed"));
+    assert_eq!(
+        in_cluster.connection_message.as_deref(),
+        Some("dial tcp: i/o timeout")
+    );
+    assert_eq!(in_cluster.applications, ["guestbook -> default"]);
+
+    // Credentials are never part of the output
+    assert!(!serde_json::to_string(&clusters)?.contains("super-secret-token"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_cluster_by_url_and_name() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/clusters/https%3A%2F%2F10.0.0.1%3A6443"))
+        .and(query_param("id.type", "url"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(cluster_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/clusters/prod"))
+        .and(query_param("id.type", "name"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(cluster_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    mount_applications(&mock_server).await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let by_url = client
+        .get_cluster("https://10.0.0.1:6443".to_string())
+        .await?;
+    assert_eq!(by_url.shard, Some(1));
+    assert_eq!(by_url.api_versions_count, 2);
+    assert_eq!(
+        by_url.connection_attempted_at.as_deref(),
+        Some("2025-01-01T10:00:00Z")
+    );
+    assert_eq!(by_url.summary.applications.len(), 2);
+
+    let by_name = client.get_cluster("prod".to_string()).await?;
+    assert_eq!(
+        by_name.summary.server.as_deref(),
+        Some("https://10.0.0.1:6443")
+    );
+    assert!(!serde_json::to_string(&by_name)?.contains("keyData"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cluster_cache_invalidation_and_auth_rotation() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/clusters/prod/invalidate-cache"))
+        .and(query_param("id.type", "name"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "server": "https://10.0.0.1:6443",
+            "name": "prod",
+            "refreshRequestedAt": "2025-01-01T10:05:00Z"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(
+            "/api/v1/clusters/https%3A%2F%2F10.0.0.1%3A6443/rotate-auth",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let invalidated = client.invalidate_cluster_cache("prod".to_string()).await?;
+    assert_eq!(
+        invalidated.refresh_requested_at.as_deref(),
+        Some("2025-01-01T10:05:00Z")
+    );
+
+    let rotated = client
+        .rotate_cluster_auth("https://10.0.0.1:6443".to_string())
+        .await?;
+    assert_eq!(rotated.action, "auth rotated");
+
+    Ok(())
+}
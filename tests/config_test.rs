@@ -0,0 +1,525 @@
+use anyhow::Result;
+use argocd_mcp_server::auth::{Credentials, ExecConfig};
+use argocd_mcp_server::config::Config;
+use argocd_mcp_server::transport::TransportMode;
+use serial_test::serial;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const CONFIG_VARS: &[&str] = &[
+    "ARGOCD_MCP_CONFIG",
+    "ARGOCD_MCP_TRANSPORT",
+    "ARGOCD_MCP_BIND",
+    "ARGOCD_BASE_URL",
+    "ARGOCD_ACCESS_TOKEN",
+    "ARGOCD_ACCESS_TOKEN_FILE",
+    "ARGOCD_TOKEN_COMMAND",
+    "ARGOCD_CA_CERT",
+    "ARGOCD_CLIENT_CERT",
+    "ARGOCD_CLIENT_KEY",
+    "ARGOCD_PROXY",
+    "ARGOCD_NO_PROXY",
+    "ARGOCD_ROOT_PATH",
+    "ARGOCD_GRPC_WEB",
+    "ARGOCD_STARTUP_PROBE",
+    "ARGOCD_USERNAME",
+    "ARGOCD_PASSWORD",
+    "ARGOCD_READ_ONLY",
+    "ARGOCD_INSECURE",
+    "ARGOCD_TIMEOUT_SECS",
+    "ARGOCD_CONTEXTS",
+    "ARGOCD_DEFAULT_CONTEXT",
+    "ARGOCD_MAX_EVENTS",
+    "ARGOCD_MAX_RETRIES",
+    "ARGOCD_RETRY_MUTATIONS",
+    "ARGOCD_CONTEXT_PROD_EU_BASE_URL",
+    "ARGOCD_CONTEXT_PROD_EU_ACCESS_TOKEN",
+    "TEST_STAGING_TOKEN",
+];
+
+fn clear_env() {
+    for var in CONFIG_VARS {
+        std::env::remove_var(var);
+    }
+}
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+fn write_config(name: &str, contents: &str) -> Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!("argocd-mcp-{}-{}", std::process::id(), name));
+    std::fs::write(&path, contents)?;
+    Ok(path)
+}
+
+const TOML_CONFIG: &str = r#"
+read_only = true
+default_context = "staging"
+
+[server]
+transport = "streamable-http"
+bind = "0.0.0.0:9090"
+
+[client]
+timeout_secs = 10
+
+[output]
+default_tail_lines = 50
+max_events = 5
+
+[contexts.staging]
+base_url = "https://argocd.staging.example.com"
+access_token_env = "TEST_STAGING_TOKEN"
+insecure = true
+
+[contexts.prod-eu]
+base_url = "https://argocd.eu.example.com"
+access_token = "prod-token"
+timeout_secs = 60
+"#;
+
+#[test]
+#[serial]
+fn test_config_defaults_from_legacy_env() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_ACCESS_TOKEN", "token");
+
+    let config = Config::load(Vec::<String>::new())?;
+    assert_eq!(config.transport.mode, TransportMode::Stdio);
+    assert_eq!(config.transport.bind.to_string(), "127.0.0.1:8080");
+    assert!(!config.read_only);
+    assert_eq!(config.output.default_tail_lines, 100);
+    assert_eq!(config.output.max_events, 20);
+    assert!(config.config_file.is_none());
+
+    let context = &config.contexts.contexts[0];
+    assert_eq!(context.name, "default");
+    assert_eq!(context.options.timeout, Duration::from_secs(30));
+    assert!(!context.options.insecure);
+    assert_eq!(context.options.retry.max_retries, 3);
+    assert!(!context.options.retry.retry_mutations);
+
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_toml_file() -> Result<()> {
+    clear_env();
+    std::env::set_var("TEST_STAGING_TOKEN", "staging-token");
+    let path = write_config("full.toml", TOML_CONFIG)?;
+
+    let config = Config::load(args(&["--config", path.to_str().unwrap()]))?;
+    assert_eq!(config.config_file.as_deref(), Some(path.as_path()));
+    assert_eq!(config.transport.mode, TransportMode::StreamableHttp);
+    assert_eq!(config.transport.bind.to_string(), "0.0.0.0:9090");
+    assert!(config.read_only);
+    assert_eq!(config.output.default_tail_lines, 50);
+    assert_eq!(config.output.max_events, 5);
+    assert_eq!(config.contexts.default_context.as_deref(), Some("staging"));
+
+    let prod = &config.contexts.contexts[0];
+    assert_eq!(prod.name, "prod-eu");
+    assert_eq!(
+        prod.credentials,
+        Credentials::Token("prod-token".to_string())
+    );
+    assert_eq!(prod.options.timeout, Duration::from_secs(60));
+    assert!(!prod.options.insecure);
+
+    let staging = &config.contexts.contexts[1];
+    assert_eq!(staging.name, "staging");
+    assert_eq!(
+        staging.credentials,
+        Credentials::Token("staging-token".to_string())
+    );
+    assert_eq!(staging.options.timeout, Duration::from_secs(10));
+    assert!(staging.options.insecure);
+
+    std::fs::remove_file(path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_yaml_file_from_env_path() -> Result<()> {
+    clear_env();
+    let token_path = write_config("token", "file-token\n")?;
+    let path = write_config(
+        "config.yaml",
+        &format!(
+            "server:\n  transport: sse\ncontexts:\n  staging:\n    base_url: https://argocd.staging.example.com\n    access_token_file: {}\n",
+            token_path.display()
+        ),
+    )?;
+    std::env::set_var("ARGOCD_MCP_CONFIG", &path);
+
+    let config = Config::load(Vec::<String>::new())?;
+    assert_eq!(config.transport.mode, TransportMode::Sse);
+    assert_eq!(
+        config.contexts.contexts[0].credentials,
+        Credentials::TokenFile(token_path.clone())
+    );
+
+    std::fs::remove_file(path)?;
+    std::fs::remove_file(token_path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_env_and_cli_override_file() -> Result<()> {
+    clear_env();
+    std::env::set_var("TEST_STAGING_TOKEN", "staging-token");
+    std::env::set_var("ARGOCD_MCP_TRANSPORT", "sse");
+    std::env::set_var("ARGOCD_MCP_BIND", "127.0.0.1:9000");
+    std::env::set_var("ARGOCD_READ_ONLY", "false");
+    std::env::set_var("ARGOCD_MAX_EVENTS", "7");
+    std::env::set_var("ARGOCD_MAX_RETRIES", "5");
+    std::env::set_var("ARGOCD_RETRY_MUTATIONS", "true");
+    std::env::set_var("ARGOCD_CONTEXT_PROD_EU_ACCESS_TOKEN", "rotated-token");
+    let path = write_config("override.toml", TOML_CONFIG)?;
+
+    let from_env = Config::load(args(&["--config", path.to_str().unwrap()]))?;
+    assert_eq!(from_env.transport.mode, TransportMode::Sse);
+    assert_eq!(from_env.transport.bind.to_string(), "127.0.0.1:9000");
+    assert!(!from_env.read_only);
+    assert_eq!(from_env.output.max_events, 7);
+    assert_eq!(
+        from_env.contexts.contexts[0].credentials,
+        Credentials::Token("rotated-token".to_string())
+    );
+    assert_eq!(from_env.contexts.contexts[0].options.retry.max_retries, 5);
+    assert!(from_env.contexts.contexts[0].options.retry.retry_mutations);
+
+    let from_cli = Config::load(args(&[
+        "--config",
+        path.to_str().unwrap(),
+        "--transport",
+        "http",
+        "--bind=0.0.0.0:3000",
+        "--read-only",
+        "--max-retries",
+        "0",
+        "--default-context",
+        "prod-eu",
+    ]))?;
+    assert_eq!(from_cli.transport.mode, TransportMode::StreamableHttp);
+    assert_eq!(from_cli.transport.bind.to_string(), "0.0.0.0:3000");
+    assert!(from_cli.read_only);
+    assert_eq!(
+        from_cli.contexts.default_context.as_deref(),
+        Some("prod-eu")
+    );
+    assert_eq!(from_cli.contexts.contexts[1].options.retry.max_retries, 0);
+
+    std::fs::remove_file(path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_reports_all_problems() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_MCP_BIND", "not-an-address");
+    std::env::set_var("ARGOCD_READ_ONLY", "maybe");
+    let path = write_config(
+        "invalid.toml",
+        r#"
+default_context = "prod-us"
+
+[contexts.staging]
+base_url = "argocd.staging.example.com"
+access_token_env = "TEST_STAGING_TOKEN"
+"#,
+    )?;
+
+    let err = Config::load(args(&[
+        "--config",
+        path.to_str().unwrap(),
+        "--transport",
+        "carrier-pigeon",
+    ]))
+    .unwrap_err();
+    let report = err.to_string();
+    assert!(report.contains("ARGOCD_MCP_BIND"));
+    assert!(report.contains("ARGOCD_READ_ONLY"));
+    assert!(report.contains("--transport"));
+    assert!(report.contains("TEST_STAGING_TOKEN"));
+    assert!(report.contains("must start with http:// or https://"));
+    assert!(report.contains("'prod-us'"));
+    assert!(err.problems.len() >= 6);
+
+    std::fs::remove_file(path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_rejects_unknown_file_fields() -> Result<()> {
+    clear_env();
+    let path = write_config(
+        "typo.toml",
+        "[contexts.staging]\nbase_url = \"https://argocd.example.com\"\naccess_tokn = \"token\"\n",
+    )?;
+
+    let err = Config::load(args(&["--config", path.to_str().unwrap()])).unwrap_err();
+    assert!(err.to_string().contains("access_tokn"));
+
+    std::fs::remove_file(path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_username_password() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_USERNAME", "admin");
+    std::env::set_var("ARGOCD_PASSWORD", "secret");
+
+    let config = Config::load(Vec::<String>::new())?;
+    assert_eq!(
+        config.contexts.contexts[0].credentials,
+        Credentials::Login {
+            username: "admin".to_string(),
+            password: "secret".to_string(),
+        }
+    );
+
+    clear_env();
+    let path = write_config(
+        "login.toml",
+        "[contexts.staging]\nbase_url = \"https://argocd.example.com\"\nusername = \"admin\"\naccess_token = \"token\"\n",
+    )?;
+    let err = Config::load(args(&["--config", path.to_str().unwrap()])).unwrap_err();
+    assert!(err.to_string().contains("not both"));
+
+    std::fs::remove_file(path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_token_file_and_command() -> Result<()> {
+    clear_env();
+    let path = write_config(
+        "exec.toml",
+        "[contexts.staging]\nbase_url = \"https://argocd.example.com\"\n\n[contexts.staging.token_command]\ncommand = \"vault-argocd-token\"\nargs = [\"--role\", \"mcp\"]\nenv = { VAULT_ADDR = \"https://vault.example.com\" }\n",
+    )?;
+
+    let config = Config::load(args(&["--config", path.to_str().unwrap()]))?;
+    assert_eq!(
+        config.contexts.contexts[0].credentials,
+        Credentials::Exec(ExecConfig {
+            command: "vault-argocd-token".to_string(),
+            args: vec!["--role".to_string(), "mcp".to_string()],
+            env: [(
+                "VAULT_ADDR".to_string(),
+                "https://vault.example.com".to_string()
+            )]
+            .into(),
+        })
+    );
+    std::fs::remove_file(path)?;
+
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_TOKEN_COMMAND", "argocd-token --audience mcp");
+    let config = Config::load(Vec::<String>::new())?;
+    assert_eq!(
+        config.contexts.contexts[0].credentials,
+        Credentials::Exec(ExecConfig {
+            command: "argocd-token".to_string(),
+            args: vec!["--audience".to_string(), "mcp".to_string()],
+            ..ExecConfig::default()
+        })
+    );
+
+    std::env::set_var("ARGOCD_ACCESS_TOKEN_FILE", "/nonexistent/argocd-token");
+    let err = Config::load(Vec::<String>::new()).unwrap_err().to_string();
+    assert!(err.contains("not both"));
+
+    std::env::remove_var("ARGOCD_TOKEN_COMMAND");
+    let err = Config::load(Vec::<String>::new()).unwrap_err().to_string();
+    assert!(err.contains("cannot read access_token_file /nonexistent/argocd-token"));
+
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_tls_and_proxy() -> Result<()> {
+    clear_env();
+    let ca_path = write_config("ca.pem", "")?;
+    let path = write_config(
+        "tls.toml",
+        &format!(
+            "[client]\nca_cert = \"{}\"\nproxy = \"http://proxy.example.com:3128\"\n\n[contexts.prod-eu]\nbase_url = \"https://argocd.eu.example.com\"\naccess_token = \"prod-token\"\n\n[contexts.staging]\nbase_url = \"https://argocd.staging.example.com\"\naccess_token = \"staging-token\"\nproxy = \"http://staging-proxy.example.com:3128\"\nno_proxy = \".internal.example.com\"\n",
+            ca_path.display()
+        ),
+    )?;
+
+    let config = Config::load(args(&["--config", path.to_str().unwrap()]))?;
+    let prod = &config.contexts.contexts[0].options;
+    assert_eq!(prod.ca_cert.as_deref(), Some(ca_path.as_path()));
+    assert_eq!(prod.proxy.as_deref(), Some("http://proxy.example.com:3128"));
+    let staging = &config.contexts.contexts[1].options;
+    assert_eq!(staging.ca_cert.as_deref(), Some(ca_path.as_path()));
+    assert_eq!(
+        staging.proxy.as_deref(),
+        Some("http://staging-proxy.example.com:3128")
+    );
+    assert_eq!(staging.no_proxy.as_deref(), Some(".internal.example.com"));
+
+    std::env::set_var("ARGOCD_CLIENT_CERT", ca_path.to_str().unwrap());
+    std::env::set_var("ARGOCD_PROXY", "socks5://proxy.example.com");
+    let err = Config::load(args(&["--config", path.to_str().unwrap()]))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("client_cert and client_key must be set together"));
+    assert!(err.contains("unsupported scheme 'socks5'"));
+
+    std::fs::remove_file(path)?;
+    std::fs::remove_file(ca_path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_root_path_and_startup_probe() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_ACCESS_TOKEN", "token");
+    std::env::set_var("ARGOCD_ROOT_PATH", "argocd/");
+
+    let config = Config::load(Vec::<String>::new())?;
+    let options = &config.contexts.contexts[0].options;
+    assert_eq!(options.root_path.as_deref(), Some("/argocd"));
+    assert!(config.startup_probe);
+
+    let config = Config::load(args(&["--rootpath", "/", "--startup-probe=false"]))?;
+    let options = &config.contexts.contexts[0].options;
+    assert_eq!(options.root_path, None);
+    assert!(!config.startup_probe);
+
+    let err = Config::load(args(&["--rootpath", "/argocd?x=1"]))
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("must be a plain path"));
+
+    // gRPC-web is not supported, so asking for it fails instead of being ignored
+    let err = Config::load(args(&["--grpc-web"])).unwrap_err().to_string();
+    assert!(err.contains("Unknown argument '--grpc-web'"));
+    std::env::set_var("ARGOCD_GRPC_WEB", "true");
+    let err = Config::load(Vec::<String>::new()).unwrap_err().to_string();
+    assert!(err.contains("gRPC-web is not supported"));
+
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_missing_file() {
+    clear_env();
+    let err = Config::load(args(&["--config", "/nonexistent/argocd-mcp.toml"])).unwrap_err();
+    assert!(err.to_string().contains("Failed to read config file"));
+}
+
+#[test]
+#[serial]
+fn test_cli_false_overrides_env_true() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_ACCESS_TOKEN", "token");
+    std::env::set_var("ARGOCD_READ_ONLY", "true");
+    std::env::set_var("ARGOCD_INSECURE", "true");
+
+    let from_env = Config::load(Vec::<String>::new())?;
+    assert!(from_env.read_only);
+    assert!(from_env.contexts.contexts[0].options.insecure);
+
+    let from_cli = Config::load(args(&["--read-only", "false", "--insecure=false"]))?;
+    assert!(!from_cli.read_only);
+    assert!(!from_cli.contexts.contexts[0].options.insecure);
+
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_env_and_cli_override_context_client_settings() -> Result<()> {
+    clear_env();
+    let path = write_config(
+        "context-client.toml",
+        r#"
+[client]
+timeout_secs = 10
+
+[contexts.prod]
+base_url = "https://argocd.example.com"
+access_token = "token"
+insecure = true
+timeout_secs = 60
+proxy = "http://file-proxy.example.com:3128"
+"#,
+    )?;
+    let config_arg = ["--config", path.to_str().unwrap()];
+
+    // File-global < file-context
+    let from_file = Config::load(args(&config_arg))?;
+    let options = &from_file.contexts.contexts[0].options;
+    assert!(options.insecure);
+    assert_eq!(options.timeout.as_secs(), 60);
+
+    // file-context < env
+    std::env::set_var("ARGOCD_TIMEOUT_SECS", "20");
+    std::env::set_var("ARGOCD_PROXY", "http://env-proxy.example.com:3128");
+    let from_env = Config::load(args(&config_arg))?;
+    let options = &from_env.contexts.contexts[0].options;
+    assert!(options.insecure);
+    assert_eq!(options.timeout.as_secs(), 20);
+    assert_eq!(
+        options.proxy.as_deref(),
+        Some("http://env-proxy.example.com:3128")
+    );
+
+    // env < CLI
+    let mut cli = config_arg.to_vec();
+    cli.extend(["--insecure=false", "--timeout", "5"]);
+    let from_cli = Config::load(args(&cli))?;
+    let options = &from_cli.contexts.contexts[0].options;
+    assert!(!options.insecure);
+    assert_eq!(options.timeout.as_secs(), 5);
+
+    std::fs::remove_file(path)?;
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_config_invalid_cli_flags() {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_ACCESS_TOKEN", "token");
+
+    assert!(Config::load(args(&["--transport"])).is_err());
+    assert!(Config::load(args(&["--bind", "not-an-address"])).is_err());
+    assert!(Config::load(args(&["--unknown"])).is_err());
+
+    clear_env();
+}
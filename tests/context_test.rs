@@ -0,0 +1,147 @@
+use anyhow::Result;
+use argocd_mcp_server::auth::Credentials;
+use argocd_mcp_server::config::Config;
+use argocd_mcp_server::context::DEFAULT_CONTEXT_NAME;
+use argocd_mcp_server::tools::ArgocdMcpHandler;
+use serial_test::serial;
+
+const CONTEXT_VARS: &[&str] = &[
+    "ARGOCD_BASE_URL",
+    "ARGOCD_ACCESS_TOKEN",
+    "ARGOCD_CONTEXTS",
+    "ARGOCD_DEFAULT_CONTEXT",
+    "ARGOCD_CONTEXT_STAGING_BASE_URL",
+    "ARGOCD_CONTEXT_STAGING_ACCESS_TOKEN",
+    "ARGOCD_CONTEXT_PROD_EU_BASE_URL",
+    "ARGOCD_CONTEXT_PROD_EU_ACCESS_TOKEN",
+    "ARGOCD_MCP_CONFIG",
+];
+
+fn clear_env() {
+    for var in CONTEXT_VARS {
+        std::env::remove_var(var);
+    }
+}
+
+#[test]
+#[serial]
+fn test_contexts_from_legacy_env() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_ACCESS_TOKEN", "token");
+
+    let config = Config::load(Vec::<String>::new())?.contexts;
+    assert_eq!(config.contexts.len(), 1);
+    assert_eq!(config.contexts[0].name, DEFAULT_CONTEXT_NAME);
+    assert_eq!(config.contexts[0].base_url, "https://argocd.example.com");
+    assert!(config.default_context.is_none());
+
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_contexts_from_named_env() -> Result<()> {
+    clear_env();
+    std::env::set_var("ARGOCD_CONTEXTS", "staging, prod-eu");
+    std::env::set_var(
+        "ARGOCD_CONTEXT_STAGING_BASE_URL",
+        "https://staging.example.com",
+    );
+    std::env::set_var("ARGOCD_CONTEXT_STAGING_ACCESS_TOKEN", "staging-token");
+    std::env::set_var(
+        "ARGOCD_CONTEXT_PROD_EU_BASE_URL",
+        "https://prod-eu.example.com",
+    );
+    std::env::set_var("ARGOCD_CONTEXT_PROD_EU_ACCESS_TOKEN", "prod-token");
+    std::env::set_var("ARGOCD_DEFAULT_CONTEXT", "prod-eu");
+
+    let config = Config::load(Vec::<String>::new())?.contexts;
+    let names: Vec<&str> = config.contexts.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["prod-eu", "staging"]);
+    assert_eq!(
+        config.contexts[0].credentials,
+        Credentials::Token("prod-token".to_string())
+    );
+    assert_eq!(config.default_context.as_deref(), Some("prod-eu"));
+
+    clear_env();
+    Ok(())
+}
+
+#[test]
+#[serial]
+fn test_contexts_missing_settings() {
+    clear_env();
+    std::env::set_var("ARGOCD_CONTEXTS", "staging");
+    std::env::set_var(
+        "ARGOCD_CONTEXT_STAGING_BASE_URL",
+        "https://staging.example.com",
+    );
+
+    let err = Config::load(Vec::<String>::new()).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("ARGOCD_CONTEXT_STAGING_ACCESS_TOKEN"));
+
+    clear_env();
+}
+
+#[test]
+#[serial]
+fn test_contexts_unknown_default() {
+    clear_env();
+    std::env::set_var("ARGOCD_BASE_URL", "https://argocd.example.com");
+    std::env::set_var("ARGOCD_ACCESS_TOKEN", "token");
+    std::env::set_var("ARGOCD_DEFAULT_CONTEXT", "prod-us");
+
+    let err = Config::load(Vec::<String>::new()).unwrap_err();
+    assert!(err.to_string().contains("prod-us"));
+
+    clear_env();
+}
+
+#[test]
+#[serial]
+fn test_contexts_none_configured() {
+    clear_env();
+    assert!(Config::load(Vec::<String>::new()).is_err());
+}
+
+#[tokio::test]
+async fn test_handler_registers_contexts() -> Result<()> {
+    let handler = ArgocdMcpHandler::new();
+    handler
+        .add_context(
+            "staging".to_string(),
+            "https://staging.example.com".to_string(),
+            "token".to_string(),
+        )
+        .await?;
+    handler
+        .add_context(
+            "prod-eu".to_string(),
+            "https://prod-eu.example.com".to_string(),
+            "token".to_string(),
+        )
+        .await?;
+
+    assert_eq!(
+        handler.context_names().await,
+        vec!["prod-eu".to_string(), "staging".to_string()]
+    );
+    handler.set_default_context("staging").await?;
+    assert!(handler.set_default_context("prod-us").await.is_err());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_handler_rejects_invalid_context() {
+    let handler = ArgocdMcpHandler::new();
+    let result = handler
+        .add_context("staging".to_string(), String::new(), "token".to_string())
+        .await;
+    assert!(result.is_err());
+    assert!(handler.context_names().await.is_empty());
+}
@@ -0,0 +1,186 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::models::ApplicationTree;
+
+const JOB_MANIFEST: &str = r#"{"apiVersion":"batch/v1","kind":"Job","metadata":{"name":"db-migrate","namespace":"default","creationTimestamp":"2025-01-01T00:00:00Z"},"status":{"active":1}}"#;
+
+fn tree() -> serde_json::Value {
+    json!({
+        "nodes": [
+            {
+                "group": "batch", "version": "v1", "kind": "Job",
+                "namespace": "default", "name": "db-migrate", "uid": "job-1"
+            },
+            {
+                "version": "v1", "kind": "Pod", "namespace": "default", "name": "db-migrate-x2k",
+                "uid": "pod-1", "health": { "status": "Progressing" },
+                "parentRefs": [{ "group": "batch", "kind": "Job", "namespace": "default", "name": "db-migrate", "uid": "job-1" }]
+            },
+            {
+                "group": "apps", "version": "v1", "kind": "Deployment",
+                "namespace": "default", "name": "web", "uid": "deploy-1"
+            },
+            {
+                "group": "apps", "version": "v1", "kind": "ReplicaSet",
+                "namespace": "default", "name": "web-5d8f", "uid": "rs-1",
+                "parentRefs": [{ "group": "apps", "kind": "Deployment", "namespace": "default", "name": "web", "uid": "deploy-1" }]
+            },
+            {
+                "version": "v1", "kind": "Pod", "namespace": "default", "name": "web-5d8f-a7q",
+                "parentRefs": [{ "group": "apps", "kind": "ReplicaSet", "namespace": "default", "name": "web-5d8f" }]
+            }
+        ]
+    })
+}
+
+async fn mount_preview(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/resource"))
+        .and(query_param("resourceName", "db-migrate"))
+        .and(query_param("kind", "Job"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "manifest": JOB_MANIFEST })))
+        .mount(mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/resource-tree"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tree()))
+        .mount(mock_server)
+        .await;
+}
+
+async fn delete_job(
+    client: &ArgocdClient,
+    force: Option<bool>,
+    orphan: Option<bool>,
+    dry_run: bool,
+) -> Result<argocd_mcp_server::models::ResourceDeleteSummary> {
+    client
+        .delete_resource(
+            "guestbook".to_string(),
+            Some("default".to_string()),
+            "db-migrate".to_string(),
+            "v1".to_string(),
+            Some("batch".to_string()),
+            "Job".to_string(),
+            force,
+            orphan,
+            dry_run,
+            None,
+            None,
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_delete_resource_dry_run_preview() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_preview(&mock_server).await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook/resource"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = delete_job(&client, None, None, true).await?;
+
+    assert!(summary.dry_run);
+    assert_eq!(summary.resource.kind, "Job");
+    assert_eq!(
+        summary
+            .resource
+            .manifest_summary
+            .creation_timestamp
+            .as_deref(),
+        Some("2025-01-01T00:00:00Z")
+    );
+    assert_eq!(summary.children.len(), 1);
+    assert_eq!(summary.children[0].name, "db-migrate-x2k");
+    assert_eq!(
+        summary.children[0].health_status.as_deref(),
+        Some("Progressing")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_resource() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_preview(&mock_server).await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook/resource"))
+        .and(query_param("resourceName", "db-migrate"))
+        .and(query_param("namespace", "default"))
+        .and(query_param("version", "v1"))
+        .and(query_param("group", "batch"))
+        .and(query_param("kind", "Job"))
+        .and(query_param("force", "true"))
+        .and(query_param("orphan", "false"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = delete_job(&client, Some(true), Some(false), false).await?;
+
+    assert!(!summary.dry_run);
+    assert!(summary.force);
+    assert!(!summary.orphan);
+    assert_eq!(summary.children.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delete_missing_resource_sends_nothing() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/resource"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "batch/Job \"db-migrate\" not found",
+            "code": 5,
+            "message": "batch/Job \"db-migrate\" not found"
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook/resource"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    assert!(delete_job(&client, None, None, false).await.is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_tree_descendants() -> Result<()> {
+    let tree: ApplicationTree = serde_json::from_value(tree())?;
+
+    // Matched by uid, then by kind/name/namespace where the ref has no uid
+    let names: Vec<&str> = tree
+        .descendants("Deployment", "web", Some("default"))
+        .iter()
+        .map(|node| node.name.as_deref().unwrap())
+        .collect();
+    assert_eq!(names, ["web-5d8f", "web-5d8f-a7q"]);
+
+    assert!(tree
+        .descendants("Pod", "web-5d8f-a7q", Some("default"))
+        .is_empty());
+    assert!(tree.descendants("Job", "db-migrate", None).is_empty());
+
+    Ok(())
+}
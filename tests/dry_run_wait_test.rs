@@ -0,0 +1,149 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::tools::ArgocdMcpHandler;
+use argocd_mcp_server::transport::{serve_http, TransportMode};
+
+fn app() -> serde_json::Value {
+    // No operation state: a real operation would still count as pending
+    json!({
+        "metadata": { "name": "guestbook", "namespace": "argocd" },
+        "spec": {
+            "project": "default",
+            "source": { "repoURL": "https://github.com/argoproj/argocd-example-apps", "path": "guestbook" },
+            "destination": { "server": "https://kubernetes.default.svc", "namespace": "guestbook" }
+        },
+        "status": {
+            "sync": { "status": "OutOfSync", "revision": "abc123" },
+            "health": { "status": "Healthy" }
+        }
+    })
+}
+
+/// MCP session over streamable HTTP against a handler talking to the mock server
+struct Session {
+    client: reqwest::Client,
+    url: String,
+    session_id: String,
+}
+
+impl Session {
+    async fn start(addr: SocketAddr) -> Result<Self> {
+        let client = reqwest::Client::new();
+        let url = format!("http://{}/mcp", addr);
+        let response = client
+            .post(&url)
+            .header("Accept", "application/json, text/event-stream")
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "initialize",
+                "params": {
+                    "protocolVersion": "2024-11-05",
+                    "capabilities": {},
+                    "clientInfo": { "name": "dry-run-test", "version": "0.1.0" }
+                }
+            }))
+            .send()
+            .await?;
+        let session_id = response.headers()["mcp-session-id"].to_str()?.to_string();
+        response.text().await?;
+
+        let session = Session {
+            client,
+            url,
+            session_id,
+        };
+        session
+            .post(json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+            .await?;
+        Ok(session)
+    }
+
+    async fn post(&self, body: serde_json::Value) -> Result<String> {
+        Ok(self
+            .client
+            .post(&self.url)
+            .header("Accept", "application/json, text/event-stream")
+            .header("mcp-session-id", &self.session_id)
+            .json(&body)
+            .send()
+            .await?
+            .text()
+            .await?)
+    }
+
+    async fn call_tool(&self, name: &str, arguments: serde_json::Value) -> Result<String> {
+        self.post(json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/call",
+            "params": { "name": name, "arguments": arguments }
+        }))
+        .await
+    }
+}
+
+#[tokio::test]
+async fn test_dry_run_ignores_wait() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    for action in ["sync", "rollback"] {
+        Mock::given(method("POST"))
+            .and(path(format!("/api/v1/applications/guestbook/{}", action)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(app()))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    // Waiting would poll the application until the timeout
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(app()))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let handler = ArgocdMcpHandler::new();
+    handler
+        .initialize(mock_server.uri(), "test-token".to_string())
+        .await?;
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let ct = CancellationToken::new();
+    let task = tokio::spawn(serve_http(
+        handler,
+        TransportMode::StreamableHttp,
+        listener,
+        ct.clone(),
+    ));
+
+    let session = Session::start(addr).await?;
+    for (tool, mut arguments) in [
+        ("sync_application", json!({})),
+        ("rollback_application", json!({ "id": 3 })),
+    ] {
+        arguments["application_name"] = json!("guestbook");
+        arguments["dry_run"] = json!(true);
+        arguments["wait"] = json!(true);
+        arguments["wait_timeout_seconds"] = json!(60);
+
+        let output =
+            tokio::time::timeout(Duration::from_secs(10), session.call_tool(tool, arguments))
+                .await??;
+        assert!(output.contains("This was a dry run"), "{}", output);
+        assert!(output.contains("wait=true was ignored"), "{}", output);
+    }
+
+    ct.cancel();
+    task.await??;
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::{ArgocdClient, ClientOptions};
+use argocd_mcp_server::error::ArgocdError;
+use argocd_mcp_server::retry::RetryPolicy;
+
+/// Client that reports the first failure instead of retrying it
+fn client(base_url: String) -> Result<ArgocdClient> {
+    ArgocdClient::with_options(
+        base_url,
+        "test-token".to_string(),
+        ClientOptions {
+            retry: RetryPolicy::disabled(),
+            ..ClientOptions::default()
+        },
+    )
+}
+
+async fn error_for(status: u16, body: serde_json::Value) -> Result<ArgocdError> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(status).set_body_json(body))
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri())?;
+    let err = client
+        .get_application_full("guestbook".to_string(), None, None, None, None)
+        .await
+        .unwrap_err();
+
+    Ok(ArgocdError::from_anyhow(&err)
+        .expect("client errors are typed")
+        .clone())
+}
+
+#[tokio::test]
+async fn test_not_found_from_grpc_code() -> Result<()> {
+    let err = error_for(
+        404,
+        json!({
+            "error": "applications.argoproj.io \"guestbook\" not found",
+            "code": 5,
+            "message": "applications.argoproj.io \"guestbook\" not found"
+        }),
+    )
+    .await?;
+
+    assert!(matches!(err, ArgocdError::NotFound(_)));
+    assert_eq!(err.failure().unwrap().grpc_code, Some(5));
+    assert!(!err.is_retryable());
+    assert!(err.to_string().contains("404"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_http_status_classification() -> Result<()> {
+    let cases = [
+        (400, "bad_request"),
+        (401, "unauthenticated"),
+        (403, "permission_denied"),
+        (409, "conflict"),
+        (503, "unavailable"),
+        (504, "timeout"),
+        (500, "api_error"),
+    ];
+
+    for (status, kind) in cases {
+        let err = error_for(status, json!({ "message": "something went wrong" })).await?;
+        assert_eq!(err.kind(), kind, "status {}", status);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_gateway_timeout_keeps_status_and_grpc_code() -> Result<()> {
+    let err = error_for(
+        504,
+        json!({ "code": 4, "message": "context deadline exceeded" }),
+    )
+    .await?;
+
+    assert!(matches!(err, ArgocdError::Timeout(_)));
+    let details = err.details();
+    assert_eq!(details.kind, "timeout");
+    assert_eq!(details.http_status, Some(504));
+    assert_eq!(details.grpc_code, Some(4));
+    assert!(details.retryable);
+    assert!(err.to_string().contains("context deadline exceeded"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_window_blocked() -> Result<()> {
+    let err = error_for(
+        403,
+        json!({
+            "error": "Cannot sync: Blocked by sync window",
+            "code": 7,
+            "message": "Cannot sync: Blocked by sync window"
+        }),
+    )
+    .await?;
+
+    assert!(matches!(err, ArgocdError::SyncWindowBlocked(_)));
+    assert!(err.hint().unwrap().contains("get_application_sync_windows"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_decode_error() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("not json"))
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri())?;
+    let err = client
+        .list_applications(None, None, None, None, None)
+        .await
+        .unwrap_err();
+
+    let typed = ArgocdError::from_anyhow(&err).unwrap();
+    assert_eq!(typed.kind(), "decode");
+    assert!(err
+        .to_string()
+        .starts_with("Failed to parse ApplicationList response"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transport_error_is_retryable() -> Result<()> {
+    let client = client("http://127.0.0.1:1".to_string())?;
+    let err = client
+        .list_applications(None, None, None, None, None)
+        .await
+        .unwrap_err();
+
+    let typed = ArgocdError::from_anyhow(&err).unwrap();
+    assert_eq!(typed.kind(), "transport");
+    assert!(typed.is_retryable());
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use serde_json::json;
+
+use argocd_mcp_server::models::{log_template, LogEntry, LogLevel, PodLogsSummary};
+
+fn entries(lines: &[(&str, &str, &str)]) -> Vec<LogEntry> {
+    lines
+        .iter()
+        .map(|(pod, timestamp, content)| {
+            serde_json::from_value(json!({
+                "content": content,
+                "podName": pod,
+                "timeStampStr": timestamp
+            }))
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_log_template_masks_variable_parts() {
+    let cases = [
+        (
+            "2026-10-17T10:00:03.123456Z ERROR request 42 failed after 150ms",
+            "<ts> ERROR request <num> failed after <num>ms",
+        ),
+        (
+            "2026-10-17 10:00:03+02:00 dial tcp 10.0.12.7:6379: connection refused",
+            "<ts> dial tcp <ip>: connection refused",
+        ),
+        (
+            "order f47ac10b-58cc-4372-a567-0e02b2c3d479 not found",
+            "order <uuid> not found",
+        ),
+        (
+            "pod api-7d9f8c-x2k4p restarted, took 1.5s, v2.14.3 at 0x1f",
+            "pod api-<id>-<id> restarted, took <num>s, <id>.<num> at <id>",
+        ),
+        ("[10:00:03] INFO ready", "[<ts>] INFO ready"),
+        ("INFO ready", "INFO ready"),
+    ];
+    for (line, template) in cases {
+        assert_eq!(log_template(line), template, "template of {:?}", line);
+    }
+}
+
+#[test]
+fn test_cluster_collapses_repeated_lines() {
+    let mut summary = PodLogsSummary::from_entries(
+        entries(&[
+            (
+                "api-a",
+                "2026-10-17T10:00:00Z",
+                "INFO listening on 0.0.0.0:8080",
+            ),
+            (
+                "api-a",
+                "2026-10-17T10:00:01Z",
+                "ERROR connection refused: 10.0.0.5:5432 (attempt 1)",
+            ),
+            (
+                "api-b",
+                "2026-10-17T10:00:02Z",
+                "ERROR connection refused: 10.0.0.5:5432 (attempt 2)",
+            ),
+            ("api-a", "2026-10-17T10:00:03Z", "WARN retrying in 500ms"),
+            (
+                "api-a",
+                "2026-10-17T10:00:04Z",
+                "ERROR connection refused: 10.0.0.6:5432 (attempt 3)",
+            ),
+        ]),
+        None,
+        None,
+        Some(100),
+        false,
+    );
+    summary.cluster(None);
+
+    // Counts still describe every line; the entries are replaced by clusters
+    assert_eq!(summary.total_lines, 5);
+    assert_eq!(summary.error_count, 3);
+    assert!(summary.log_entries.is_empty());
+
+    let clusters = summary.clusters.expect("clusters are set");
+    assert_eq!(clusters.total_clusters, 3);
+    assert_eq!(clusters.omitted_lines, 0);
+
+    let top = &clusters.clusters[0];
+    assert_eq!(
+        top.pattern,
+        "ERROR connection refused: <ip> (attempt <num>)"
+    );
+    assert_eq!(top.level, LogLevel::Error);
+    assert_eq!(top.count, 3);
+    assert_eq!(top.first_seen.as_deref(), Some("2026-10-17T10:00:01Z"));
+    assert_eq!(top.last_seen.as_deref(), Some("2026-10-17T10:00:04Z"));
+    assert_eq!(
+        top.example,
+        "ERROR connection refused: 10.0.0.5:5432 (attempt 1)"
+    );
+    assert_eq!(top.pods, ["api-a", "api-b"]);
+
+    // Equally frequent clusters keep their order of appearance
+    assert_eq!(clusters.clusters[1].pattern, "INFO listening on <ip>");
+    assert!(clusters.clusters[1].pods.is_empty());
+    assert_eq!(clusters.clusters[2].pattern, "WARN retrying in <num>ms");
+}
+
+#[test]
+fn test_cluster_top_n() {
+    let mut lines = Vec::new();
+    for i in 0..500 {
+        lines.push(format!("FATAL panic: index out of range [{}]", i));
+    }
+    lines.push("INFO starting".to_string());
+    lines.push("WARN config reloaded".to_string());
+    let lines: Vec<(&str, &str, &str)> = lines
+        .iter()
+        .map(|l| ("api-a", "2026-10-17T10:00:00Z", l.as_str()))
+        .collect();
+
+    let mut summary = PodLogsSummary::from_entries(entries(&lines), None, None, None, false);
+    summary.cluster(Some(2));
+
+    let clusters = summary.clusters.expect("clusters are set");
+    assert_eq!(clusters.total_clusters, 3);
+    assert_eq!(clusters.clusters.len(), 2);
+    assert_eq!(clusters.omitted_lines, 1);
+    assert_eq!(clusters.clusters[0].count, 500);
+    assert_eq!(
+        clusters.clusters[0].pattern,
+        "FATAL panic: index out of range [<num>]"
+    );
+    assert_eq!(
+        clusters.clusters[0].example,
+        "FATAL panic: index out of range [0]"
+    );
+}
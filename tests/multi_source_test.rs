@@ -0,0 +1,220 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+
+fn multi_source_app(revisions: &[&str]) -> serde_json::Value {
+    json!({
+        "metadata": { "name": "ingress", "namespace": "argocd" },
+        "spec": {
+            "project": "platform",
+            "sources": [
+                {
+                    "repoURL": "https://kubernetes.github.io/ingress-nginx",
+                    "chart": "ingress-nginx",
+                    "targetRevision": "4.10.0",
+                    "helm": { "valueFiles": ["$values/ingress/values.yaml"] }
+                },
+                {
+                    "repoURL": "https://github.com/example/values.git",
+                    "targetRevision": "main",
+                    "ref": "values"
+                }
+            ],
+            "destination": { "server": "https://kubernetes.default.svc", "namespace": "ingress" }
+        },
+        "status": {
+            "sync": { "status": "Synced", "revisions": revisions },
+            "health": { "status": "Healthy" },
+            "history": [{
+                "id": 3,
+                "revisions": revisions,
+                "deployedAt": "2026-10-01T10:00:00Z",
+                "sources": [
+                    { "repoURL": "https://kubernetes.github.io/ingress-nginx", "chart": "ingress-nginx", "targetRevision": "4.10.0" },
+                    { "repoURL": "https://github.com/example/values.git", "targetRevision": "main", "ref": "values" }
+                ]
+            }]
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_list_applications_renders_every_source() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [multi_source_app(&["4.10.0", "a1b2c3d4e5f6"])]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let apps = client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    let app = &apps[0];
+    assert_eq!(
+        app.repo_url.as_deref(),
+        Some("https://kubernetes.github.io/ingress-nginx")
+    );
+    assert_eq!(app.target_revision.as_deref(), Some("4.10.0"));
+    assert_eq!(app.sources.len(), 2);
+    assert_eq!(app.sources[1].position, 2);
+    assert_eq!(app.sources[1].ref_name.as_deref(), Some("values"));
+    assert_eq!(app.sources[1].revision.as_deref(), Some("a1b2c3d4e5f6"));
+    assert_eq!(
+        app.sources[0].describe(),
+        "https://kubernetes.github.io/ingress-nginx ingress-nginx@4.10.0"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_application_with_source_hydrator() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/hydrated"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "name": "hydrated" },
+            "spec": {
+                "project": "default",
+                "sourceHydrator": {
+                    "drySource": {
+                        "repoURL": "https://github.com/example/dry.git",
+                        "targetRevision": "HEAD",
+                        "path": "apps/web"
+                    },
+                    "syncSource": { "targetBranch": "env/prod", "path": "apps/web" },
+                    "hydrateTo": { "targetBranch": "env/prod-next" }
+                },
+                "destination": { "server": "https://kubernetes.default.svc", "namespace": "web" }
+            },
+            "status": { "sync": { "status": "Synced", "revision": "ffee0011" } }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let detail = client
+        .get_application("hydrated".to_string(), None, None, None, None)
+        .await?;
+
+    assert_eq!(
+        detail.repo_url.as_deref(),
+        Some("https://github.com/example/dry.git")
+    );
+    assert_eq!(detail.path.as_deref(), Some("apps/web"));
+    assert!(detail.sources.is_empty());
+    let hydrator = detail.source_hydrator.expect("hydrator is kept");
+    assert_eq!(hydrator.sync_source.target_branch, "env/prod");
+    assert_eq!(hydrator.hydrate_to.unwrap().target_branch, "env/prod-next");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_history_lists_per_source_revisions() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/ingress"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(multi_source_app(&["4.10.0", "a1b2c3d4e5f6"])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let history = client
+        .get_application_history("ingress".to_string(), None, None)
+        .await?;
+
+    let entry = &history.entries[0];
+    assert_eq!(entry.id, 3);
+    assert_eq!(entry.revision_full, "4.10.0");
+    assert_eq!(entry.sources.len(), 2);
+    assert_eq!(entry.sources[0].revision.as_deref(), Some("4.10.0"));
+    assert_eq!(entry.sources[1].revision.as_deref(), Some("a1b2c3d4e5f6"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_refresh_detects_source_revision_change() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/ingress"))
+        .and(query_param("refresh", "hard"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(multi_source_app(&["4.10.0", "0123456789ab"])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/ingress"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(multi_source_app(&["4.10.0", "a1b2c3d4e5f6"])),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .refresh_application("ingress".to_string(), None, None, None)
+        .await?;
+
+    assert!(summary.revision_changed);
+    assert_eq!(
+        summary.repo_url.as_deref(),
+        Some("https://kubernetes.github.io/ingress-nginx")
+    );
+    assert_eq!(summary.sources[1].revision.as_deref(), Some("0123456789ab"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_manifests_for_selected_sources() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/ingress/manifests"))
+        .and(query_param("sourcePositions", "2"))
+        .and(query_param("revisions", "feature-x"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "manifests": [
+                "{\"apiVersion\":\"v1\",\"kind\":\"ConfigMap\",\"metadata\":{\"name\":\"values\"}}"
+            ]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .get_manifests(
+            "ingress".to_string(),
+            None,
+            None,
+            None,
+            Some(vec![2]),
+            Some(vec!["feature-x".to_string()]),
+        )
+        .await?;
+
+    assert_eq!(summary.total_manifests, 1);
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::models::{
+    AnalyzedLogEntry, LogBatch, LogEntry, LogFollowOptions, LogFollowStop, PodLogsQuery,
+};
+
+/// Newline-delimited log stream, one `{"result": LogEntry}` per line
+fn log_stream(lines: &[&str]) -> String {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, content)| {
+            json!({
+                "result": {
+                    "content": content,
+                    "podName": "guestbook-ui-abc",
+                    "timeStamp": format!("2026-10-17T10:00:{:02}Z", i)
+                }
+            })
+            .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn mount_stream(mock_server: &MockServer, body: String) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/logs"))
+        .and(query_param("follow", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn query() -> PodLogsQuery {
+    PodLogsQuery {
+        application_name: "guestbook".to_string(),
+        pod_name: Some("guestbook-ui-abc".to_string()),
+        ..PodLogsQuery::default()
+    }
+}
+
+#[tokio::test]
+async fn test_follow_stops_at_pattern() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_stream(
+        &mock_server,
+        log_stream(&[
+            "INFO loading configuration",
+            "WARN cache is cold",
+            "INFO Started server on :8080",
+            "INFO handling request",
+        ]),
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let options = LogFollowOptions {
+        stop_pattern: Some("Started server".to_string()),
+        ..LogFollowOptions::default()
+    };
+    let mut batches: Vec<LogBatch> = Vec::new();
+    let summary = client
+        .pod_logs_follow(query(), false, &options, |batch| {
+            batches.push(batch);
+            std::future::ready(())
+        })
+        .await?;
+
+    assert_eq!(summary.total_lines, 3);
+    assert_eq!(summary.warning_count, 1);
+    let follow = summary.follow.expect("follow summary is set");
+    assert_eq!(follow.stopped, LogFollowStop::PatternMatched);
+    assert_eq!(follow.lines_received, 3);
+    assert_eq!(
+        follow.matched_line.as_deref(),
+        Some("INFO Started server on :8080")
+    );
+
+    // The stop flushes the pending lines in a single batch
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].entries.len(), 3);
+    assert_eq!(batches[0].lines_received, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_follow_stops_at_line_limit_in_batches() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let lines: Vec<String> = (0..10).map(|i| format!("INFO request {}", i)).collect();
+    let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+    mount_stream(&mock_server, log_stream(&lines)).await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let options = LogFollowOptions {
+        max_lines: 5,
+        batch_lines: 2,
+        ..LogFollowOptions::default()
+    };
+    let mut batches: Vec<LogBatch> = Vec::new();
+    let summary = client
+        .pod_logs_follow(query(), false, &options, |batch| {
+            batches.push(batch);
+            std::future::ready(())
+        })
+        .await?;
+
+    assert_eq!(summary.total_lines, 5);
+    assert_eq!(
+        summary.follow.expect("follow summary is set").stopped,
+        LogFollowStop::LineLimit
+    );
+    let sizes: Vec<usize> = batches.iter().map(|b| b.entries.len()).collect();
+    assert_eq!(sizes, [2, 2, 1]);
+    assert_eq!(batches[2].lines_received, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_follow_reports_stream_end_and_filters_errors() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let mut body = log_stream(&[
+        "INFO connecting to database",
+        "ERROR connection refused: db:5432",
+        "INFO retrying",
+    ]);
+    // ArgoCD closes a finished stream with an empty last entry
+    body.push('\n');
+    body.push_str(&json!({ "result": { "content": "", "last": true } }).to_string());
+    mount_stream(&mock_server, body).await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let options = LogFollowOptions {
+        max_duration: Duration::from_secs(10),
+        ..LogFollowOptions::default()
+    };
+    let mut received = 0;
+    let summary = client
+        .pod_logs_follow(query(), true, &options, |batch| {
+            received += batch.entries.len();
+            std::future::ready(())
+        })
+        .await?;
+
+    // Every line is streamed, only the issues are kept in the summary
+    assert_eq!(received, 3);
+    assert!(summary.filtered);
+    assert_eq!(summary.error_count, 1);
+    assert_eq!(summary.log_entries.len(), 1);
+    let follow = summary.follow.expect("follow summary is set");
+    assert_eq!(follow.stopped, LogFollowStop::StreamEnded);
+    assert_eq!(follow.lines_received, 3);
+    assert!(follow.matched_line.is_none());
+
+    Ok(())
+}
+
+#[test]
+fn test_batch_message() {
+    let batch = LogBatch {
+        entries: vec![AnalyzedLogEntry::from(
+            serde_json::from_value::<LogEntry>(json!({
+                "content": "ERROR payment failed",
+                "podName": "api-1"
+            }))
+            .unwrap(),
+        )],
+        lines_received: 12,
+        elapsed_seconds: 4,
+    };
+
+    let message = batch.message();
+    assert!(message.starts_with("12 line(s) after 4s, 1 error(s) in this batch"));
+    assert!(message.contains("ERROR payment failed"));
+}
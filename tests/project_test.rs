@@ -0,0 +1,309 @@
+use anyhow::Result;
+use base64::Engine;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::models::{AppProjectSpec, GroupKind, ProjectRole};
+
+fn project_json() -> serde_json::Value {
+    json!({
+        "metadata": {
+            "name": "team-a",
+            "namespace": "argocd",
+            "resourceVersion": "42",
+            "uid": "0b6f4c5e-team-a",
+            "generation": 7,
+            "finalizers": ["resources-finalizer.argocd.argoproj.io"]
+        },
+        "spec": {
+            "description": "Team A services",
+            "sourceRepos": ["https://github.com/example/team-a.git"],
+            "destinations": [
+                { "server": "https://kubernetes.default.svc", "namespace": "team-a-*" }
+            ],
+            "clusterResourceWhitelist": [{ "group": "", "kind": "Namespace" }],
+            "namespaceResourceBlacklist": [{ "group": "", "kind": "ResourceQuota" }],
+            "roles": [{
+                "name": "ci",
+                "description": "CI pipeline",
+                "policies": ["p, proj:team-a:ci, applications, sync, team-a/*, allow"],
+                "jwtTokens": [{ "iat": 1700000000, "id": "old" }]
+            }],
+            "syncWindows": [
+                { "kind": "deny", "schedule": "0 22 * * *", "duration": "8h", "manualSync": true }
+            ]
+        },
+        "status": {
+            "jwtTokensByRole": {
+                "ci": {
+                    "items": [
+                        { "iat": 1700000000, "id": "old" },
+                        { "iat": 1710000000, "exp": 1720000000, "id": "deploy" }
+                    ]
+                }
+            }
+        }
+    })
+}
+
+async fn mount_project(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/projects/team-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(project_json()))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_list_projects() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/projects"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [project_json(), { "metadata": { "name": "default" }, "spec": {} }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let projects = client.list_projects(None).await?;
+
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0].name, "team-a");
+    assert_eq!(projects[0].source_repos_count, 1);
+    assert_eq!(projects[0].roles_count, 1);
+    assert_eq!(projects[0].sync_windows_count, 1);
+    assert_eq!(projects[1].destinations_count, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_project_detail() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_project(&mock_server).await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let project = client.get_project("team-a".to_string()).await?;
+
+    assert_eq!(
+        project.destinations,
+        ["https://kubernetes.default.svc team-a-*"]
+    );
+    assert_eq!(project.cluster_resource_whitelist, ["Namespace"]);
+    assert_eq!(project.namespace_resource_blacklist, ["ResourceQuota"]);
+    assert_eq!(project.sync_windows[0].manual_sync, Some(true));
+
+    // Tokens from the spec and the status are merged without duplicates
+    let tokens = &project.roles[0].tokens;
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(tokens[0].id.as_deref(), Some("old"));
+    assert_eq!(tokens[0].expires_at_time, None);
+    assert_eq!(tokens[1].issued_at_time, "2024-03-09T16:00:00Z");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_project_events() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/projects/team-a/events"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "items": [{
+                "type": "Normal",
+                "reason": "ResourceUpdated",
+                "message": "admin updated project",
+                "involvedObject": { "kind": "AppProject", "name": "team-a" }
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let events = client.get_project_events("team-a".to_string()).await?;
+
+    assert_eq!(events.total_events, 1);
+    assert_eq!(events.events[0].reason.as_deref(), Some("ResourceUpdated"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_project_with_upsert() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/projects"))
+        .and(body_partial_json(json!({
+            "upsert": true,
+            "project": {
+                "metadata": { "name": "team-a" },
+                "spec": {
+                    "sourceRepos": ["*"],
+                    "clusterResourceWhitelist": [{ "group": "*", "kind": "*" }]
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(project_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let spec = AppProjectSpec {
+        source_repos: Some(vec!["*".to_string()]),
+        cluster_resource_whitelist: Some(vec![GroupKind {
+            group: "*".to_string(),
+            kind: "*".to_string(),
+        }]),
+        ..AppProjectSpec::default()
+    };
+    let project = client
+        .create_project("team-a".to_string(), spec, Some(true))
+        .await?;
+    assert_eq!(project.name, "team-a");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_project_reports_changed_fields() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_project(&mock_server).await;
+
+    // The resourceVersion read is sent back, and kept roles keep their tokens
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/projects/team-a"))
+        .and(body_partial_json(json!({
+            "project": {
+                "metadata": { "resourceVersion": "42" },
+                "spec": {
+                    "description": "Team A",
+                    "roles": [{ "name": "ci", "jwtTokens": [{ "iat": 1700000000 }] }]
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(project_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let mut spec: AppProjectSpec = serde_json::from_value(project_json()["spec"].clone())?;
+    spec.description = Some("Team A".to_string());
+    spec.roles.as_mut().unwrap()[0].jwt_tokens = None;
+
+    let summary = client.update_project("team-a".to_string(), spec).await?;
+    assert_eq!(summary.changed_fields, ["description"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_project_role_upsert_and_delete() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_project(&mock_server).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/api/v1/projects/team-a"))
+        .and(body_partial_json(json!({
+            "project": {
+                // Metadata the client does not model must survive the update
+                "metadata": {
+                    "uid": "0b6f4c5e-team-a",
+                    "generation": 7,
+                    "finalizers": ["resources-finalizer.argocd.argoproj.io"]
+                },
+                "spec": { "roles": [
+                    { "name": "ci", "jwtTokens": [{ "iat": 1700000000 }] },
+                    { "name": "viewer", "groups": ["team-a-devs"] }
+                ] }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(project_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .upsert_project_role(
+            "team-a".to_string(),
+            ProjectRole {
+                name: "viewer".to_string(),
+                groups: Some(vec!["team-a-devs".to_string()]),
+                ..ProjectRole::default()
+            },
+        )
+        .await?;
+    assert_eq!(summary.action, "created");
+
+    let err = client
+        .delete_project_role("team-a".to_string(), "missing".to_string())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("role 'missing' does not exist"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_project_token_create_and_delete() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let encode = |value: &[u8]| base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(value);
+    let token = format!(
+        "{}.{}.signature",
+        encode(br#"{"alg":"HS256","typ":"JWT"}"#),
+        encode(br#"{"iat":1710000000,"exp":1720000000,"jti":"deploy"}"#)
+    );
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/projects/team-a/roles/ci/token"))
+        .and(body_partial_json(
+            json!({ "expiresIn": 3600, "id": "deploy" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "token": token })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/projects/team-a/roles/ci/token/1710000000"))
+        .and(query_param("id", "deploy"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let created = client
+        .create_project_token(
+            "team-a".to_string(),
+            "ci".to_string(),
+            None,
+            Some(3600),
+            Some("deploy".to_string()),
+        )
+        .await?;
+    assert_eq!(created.issued_at, 1710000000);
+    assert_eq!(created.id.as_deref(), Some("deploy"));
+    assert_eq!(created.token.as_deref(), Some(token.as_str()));
+
+    client
+        .delete_project_token(
+            "team-a".to_string(),
+            "ci".to_string(),
+            created.issued_at,
+            created.id,
+        )
+        .await?;
+
+    Ok(())
+}
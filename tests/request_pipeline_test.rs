@@ -0,0 +1,167 @@
+use anyhow::Result;
+use serde_json::json;
+use std::sync::{Arc, Mutex};
+use wiremock::{
+    matchers::{header_exists, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::request::{RequestHook, RequestInfo, ResponseInfo, REQUEST_ID_HEADER};
+
+/// Records every exchange seen by the client
+#[derive(Default)]
+struct RecordingHook {
+    exchanges: Mutex<Vec<(String, String, u16)>>,
+}
+
+impl RequestHook for RecordingHook {
+    fn after_response(&self, info: &RequestInfo<'_>, response: &ResponseInfo<'_>) {
+        self.exchanges.lock().unwrap().push((
+            info.request_id.to_string(),
+            info.request.operation().to_string(),
+            response.status,
+        ));
+    }
+}
+
+/// Answers every request without contacting the server
+struct CannedHook;
+
+impl RequestHook for CannedHook {
+    fn before_request(&self, _info: &RequestInfo<'_>) -> Option<String> {
+        Some(json!({ "items": [{ "metadata": { "name": "cached-app" } }] }).to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_requests_carry_request_id() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .and(header_exists(REQUEST_ID_HEADER))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    client.list_applications(None, None, None, None, None).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hooks_observe_every_exchange() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": "Not Found",
+            "message": "applications.argoproj.io \"missing\" not found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let hook = Arc::new(RecordingHook::default());
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?
+        .with_hook(hook.clone());
+
+    client.list_applications(None, None, None, None, None).await?;
+    let result = client
+        .get_application("missing".to_string(), None, None, None, None)
+        .await;
+    assert!(result.is_err());
+
+    let exchanges = hook.exchanges.lock().unwrap();
+    assert_eq!(exchanges.len(), 2);
+    assert_eq!(exchanges[0].1, "list_applications");
+    assert_eq!(exchanges[0].2, 200);
+    assert_eq!(exchanges[1].1, "get_application");
+    assert_eq!(exchanges[1].2, 404);
+    assert_ne!(exchanges[0].0, exchanges[1].0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hook_can_short_circuit_request() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?
+        .with_hook(Arc::new(CannedHook));
+
+    let names = client
+        .list_application_names(None, None, None, None)
+        .await?;
+    assert_eq!(names, vec!["cached-app".to_string()]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_errors_are_decoded_consistently() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let error_body = json!({
+        "error": "permission denied",
+        "message": "permission denied: applications, sync, default/guestbook"
+    });
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/sync"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(error_body.clone()))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook/manifests"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(error_body))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+
+    let sync_error = client
+        .sync_application_full(
+            "guestbook".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+    let manifests_error = client
+        .get_manifests_full("guestbook".to_string(), None, None, None, None, None)
+        .await
+        .unwrap_err()
+        .to_string();
+
+    let expected =
+        "ArgoCD API error (403 Forbidden): permission denied: applications, sync, default/guestbook";
+    assert_eq!(sync_error, expected);
+    assert_eq!(manifests_error, expected);
+
+    Ok(())
+}
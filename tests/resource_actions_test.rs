@@ -0,0 +1,163 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::tools::RunResourceActionArgs;
+
+#[tokio::test]
+async fn test_list_resource_actions() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/rollouts-demo/resource/actions"))
+        .and(query_param("namespace", "demo"))
+        .and(query_param("resourceName", "rollouts-demo"))
+        .and(query_param("version", "v1alpha1"))
+        .and(query_param("group", "argoproj.io"))
+        .and(query_param("kind", "Rollout"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "actions": [
+                { "name": "abort", "disabled": false, "iconClass": "fa fa-circle-xmark" },
+                { "name": "promote-full", "disabled": false },
+                { "name": "restart", "disabled": false },
+                { "name": "resume", "disabled": true },
+                { "name": "scale", "params": [{ "name": "replicas", "type": "number" }] }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .list_resource_actions(
+            "rollouts-demo".to_string(),
+            Some("demo".to_string()),
+            "rollouts-demo".to_string(),
+            "v1alpha1".to_string(),
+            Some("argoproj.io".to_string()),
+            "Rollout".to_string(),
+            None,
+            None,
+        )
+        .await?;
+
+    assert_eq!(summary.actions.len(), 5);
+    assert_eq!(summary.available_count, 4);
+    assert_eq!(summary.disabled_count, 1);
+    let resume = summary.actions.iter().find(|a| a.name == "resume").unwrap();
+    assert!(resume.disabled);
+    let scale = summary.actions.iter().find(|a| a.name == "scale").unwrap();
+    assert!(!scale.disabled);
+    assert_eq!(scale.params, ["replicas"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_resource_action() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/resource/actions"))
+        .and(query_param("namespace", "default"))
+        .and(query_param("resourceName", "guestbook-ui"))
+        .and(query_param("version", "v1"))
+        .and(query_param("group", "apps"))
+        .and(query_param("kind", "Deployment"))
+        .and(body_json(json!("restart")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .run_resource_action(
+            "guestbook".to_string(),
+            Some("default".to_string()),
+            "guestbook-ui".to_string(),
+            "v1".to_string(),
+            Some("apps".to_string()),
+            "Deployment".to_string(),
+            "restart".to_string(),
+            Vec::new(),
+            None,
+            None,
+        )
+        .await?;
+
+    assert_eq!(summary.action, "restart");
+    assert!(summary.parameters.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_parameterized_resource_action() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/resource/actions/v2"))
+        .and(body_json(json!({
+            "name": "guestbook",
+            "namespace": "default",
+            "resourceName": "guestbook-ui",
+            "version": "v1",
+            "group": "apps",
+            "kind": "Deployment",
+            "action": "scale",
+            "appNamespace": null,
+            "project": "default",
+            "resourceActionParameters": [{ "name": "replicas", "value": "3" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .run_resource_action(
+            "guestbook".to_string(),
+            Some("default".to_string()),
+            "guestbook-ui".to_string(),
+            "v1".to_string(),
+            Some("apps".to_string()),
+            "Deployment".to_string(),
+            "scale".to_string(),
+            vec![("replicas".to_string(), "3".to_string())],
+            None,
+            Some("default".to_string()),
+        )
+        .await?;
+
+    assert_eq!(summary.parameters, ["replicas=3"]);
+
+    Ok(())
+}
+
+#[test]
+fn test_run_resource_action_args_reuse_resource_fields() -> Result<()> {
+    let args: RunResourceActionArgs = serde_json::from_value(json!({
+        "application_name": "guestbook",
+        "namespace": "default",
+        "resource_name": "guestbook-ui",
+        "version": "v1",
+        "group": "apps",
+        "kind": "Deployment",
+        "action": "restart",
+        "context": "staging"
+    }))?;
+
+    assert_eq!(args.resource.application_name, "guestbook");
+    assert_eq!(args.resource.kind, "Deployment");
+    assert_eq!(args.resource.context.as_deref(), Some("staging"));
+    assert_eq!(args.action, "restart");
+    assert!(args.action_parameters.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use anyhow::Result;
+use serde_json::json;
+use std::time::{Duration, Instant};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::{ArgocdClient, ClientOptions};
+use argocd_mcp_server::retry::{track_retries, RetryPolicy};
+
+fn client(base_url: String, retry_mutations: bool) -> Result<ArgocdClient> {
+    ArgocdClient::with_options(
+        base_url,
+        "test-token".to_string(),
+        ClientOptions {
+            retry: RetryPolicy {
+                max_retries: 2,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(5),
+                retry_mutations,
+            },
+            ..ClientOptions::default()
+        },
+    )
+}
+
+async fn sync(client: &ArgocdClient) -> Result<()> {
+    client
+        .sync_application_full(
+            "guestbook".to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .await
+        .map(|_| ())
+}
+
+#[tokio::test]
+async fn test_get_retried_after_bad_gateway() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), false)?;
+    let (result, retries) =
+        track_retries(client.list_applications(None, None, None, None, None)).await;
+
+    assert!(result?.is_empty());
+    assert_eq!(retries.len(), 1);
+    assert_eq!(retries[0].operation, "list_applications");
+    assert_eq!(retries[0].reason, "unavailable");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retries_are_bounded() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), false)?;
+    let (result, retries) =
+        track_retries(client.list_applications(None, None, None, None, None)).await;
+
+    assert!(result.unwrap_err().to_string().contains("503"));
+    assert_eq!(retries.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_client_errors_are_not_retried() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), false)?;
+    let result = client
+        .get_application("guestbook".to_string(), None, None, None, None)
+        .await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_retry_after_is_honored() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), false)?;
+    let started = Instant::now();
+    let (result, retries) =
+        track_retries(client.list_applications(None, None, None, None, None)).await;
+
+    assert!(result.is_ok());
+    assert!(started.elapsed() >= Duration::from_secs(1));
+    assert_eq!(retries[0].delay, Duration::from_secs(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_not_retried_by_default() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/sync"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), false)?;
+    assert!(sync(&client).await.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_retry_opt_in() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/sync"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/sync"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "name": "guestbook" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), true)?;
+    let (result, retries) = track_retries(sync(&client)).await;
+    assert!(result.is_ok());
+    assert_eq!(retries.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sync_not_retried_when_outcome_unknown() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    // A 502 may come from a proxy after the sync reached ArgoCD
+    Mock::given(method("POST"))
+        .and(path("/api/v1/applications/guestbook/sync"))
+        .respond_with(ResponseTemplate::new(502))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = client(mock_server.uri(), true)?;
+    assert!(sync(&client).await.is_err());
+
+    Ok(())
+}
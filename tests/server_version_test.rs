@@ -0,0 +1,83 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::{ArgocdClient, ClientOptions};
+use argocd_mcp_server::tools::ArgocdMcpHandler;
+
+fn client(base_url: String, root_path: Option<&str>) -> Result<ArgocdClient> {
+    ArgocdClient::with_options(
+        base_url,
+        "test-token".to_string(),
+        ClientOptions {
+            root_path: root_path.map(str::to_string),
+            ..ClientOptions::default()
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_root_path_applies_to_all_requests() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/argocd/api/version"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Version": "v2.13.0+347f221",
+            "BuildDate": "2024-11-04T12:09:06Z",
+            "GitCommit": "347f221adba5599ef4d5f12ee572b2c17d01db4d",
+            "Platform": "linux/amd64",
+            "KustomizeVersion": "v5.4.3"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/argocd/api/v1/applications"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "items": [] })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = client(format!("{}/", mock_server.uri()), Some("argocd/"))?;
+    assert_eq!(client.base_url(), format!("{}/argocd", mock_server.uri()));
+
+    let version = client.get_version().await?;
+    assert_eq!(version.version, "v2.13.0+347f221");
+    assert_eq!(version.platform.as_deref(), Some("linux/amd64"));
+    client
+        .list_applications(None, None, None, None, None)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_startup_probe_reports_wrong_root_path() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    // Ingress answers unknown paths with an HTML page
+    Mock::given(method("GET"))
+        .and(path("/api/version"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>ArgoCD UI</html>"))
+        .mount(&mock_server)
+        .await;
+
+    let handler = ArgocdMcpHandler::new();
+    handler
+        .add_context(
+            "prod".to_string(),
+            mock_server.uri(),
+            "test-token".to_string(),
+        )
+        .await?;
+
+    let err = handler.probe_contexts().await.unwrap_err().to_string();
+    assert!(err.contains("Context 'prod' failed the startup probe"));
+    assert!(err.contains("Failed to parse VersionMessage response"));
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+
+fn app_with_operation(phase: &str, message: &str) -> serde_json::Value {
+    json!({
+        "metadata": { "name": "guestbook", "namespace": "argocd" },
+        "status": {
+            "sync": { "status": "OutOfSync" },
+            "operationState": {
+                "phase": phase,
+                "message": message,
+                "startedAt": "2025-01-01T10:00:00Z"
+            }
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_terminate_running_operation() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(app_with_operation(
+            "Running",
+            "waiting for completion of hook batch/Job/db-migrate",
+        )))
+        .up_to_n_times(1)
+        .with_priority(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(app_with_operation("Terminating", "Operation terminating")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook/operation"))
+        .and(query_param("appNamespace", "argocd"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .terminate_operation("guestbook".to_string(), Some("argocd".to_string()), None)
+        .await?;
+
+    assert!(summary.terminated);
+    assert_eq!(summary.phase_before.as_deref(), Some("Running"));
+    assert_eq!(summary.phase_after.as_deref(), Some("Terminating"));
+    assert!(summary
+        .message_before
+        .as_deref()
+        .unwrap()
+        .contains("db-migrate"));
+    assert_eq!(summary.started_at.as_deref(), Some("2025-01-01T10:00:00Z"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_terminate_without_running_operation() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(app_with_operation("Succeeded", "successfully synced")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook/operation"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client
+        .terminate_operation("guestbook".to_string(), None, None)
+        .await?;
+
+    assert!(!summary.terminated);
+    assert_eq!(summary.phase_before.as_deref(), Some("Succeeded"));
+    assert_eq!(summary.phase_after.as_deref(), Some("Succeeded"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_terminate_operation_error() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/guestbook"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(app_with_operation("Running", "syncing")),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/api/v1/applications/guestbook/operation"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "error": "permission denied: applications, sync, default/guestbook",
+            "code": 7,
+            "message": "permission denied: applications, sync, default/guestbook"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let err = client
+        .terminate_operation("guestbook".to_string(), None, None)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("permission denied"));
+
+    Ok(())
+}
@@ -0,0 +1,159 @@
+use anyhow::Result;
+use argocd_mcp_server::tools::ArgocdMcpHandler;
+use argocd_mcp_server::transport::{serve_http, TransportMode};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_util::sync::CancellationToken;
+
+async fn spawn_server(
+    mode: TransportMode,
+) -> Result<(SocketAddr, CancellationToken, tokio::task::JoinHandle<Result<()>>)> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+    let ct = CancellationToken::new();
+    let handler = ArgocdMcpHandler::with_read_only(true);
+    let task = tokio::spawn(serve_http(handler, mode, listener, ct.clone()));
+    Ok((addr, ct, task))
+}
+
+#[test]
+fn test_transport_mode_parsing() -> Result<()> {
+    assert_eq!("stdio".parse::<TransportMode>()?, TransportMode::Stdio);
+    assert_eq!("http".parse::<TransportMode>()?, TransportMode::StreamableHttp);
+    assert_eq!(
+        "Streamable-HTTP".parse::<TransportMode>()?,
+        TransportMode::StreamableHttp
+    );
+    assert_eq!("sse".parse::<TransportMode>()?, TransportMode::Sse);
+
+    let err = "websocket".parse::<TransportMode>().unwrap_err();
+    assert!(err.to_string().contains("Unknown transport"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_streamable_http_health_endpoint() -> Result<()> {
+    let (addr, ct, task) = spawn_server(TransportMode::StreamableHttp).await?;
+
+    let response = reqwest::get(format!("http://{}/health", addr)).await?;
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["transport"], "streamable-http");
+    assert_eq!(body["readOnly"], true);
+
+    ct.cancel();
+    task.await??;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_streamable_http_initialize_session() -> Result<()> {
+    let (addr, ct, task) = spawn_server(TransportMode::StreamableHttp).await?;
+
+    let response = reqwest::Client::new()
+        .post(format!("http://{}/mcp", addr))
+        .header("Accept", "application/json, text/event-stream")
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2024-11-05",
+                "capabilities": {},
+                "clientInfo": { "name": "transport-test", "version": "0.1.0" }
+            }
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), 200);
+    assert!(response.headers().contains_key("mcp-session-id"));
+
+    let body = response.text().await?;
+    assert!(body.contains("argocd-mcp-server"));
+
+    ct.cancel();
+    task.await??;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_streamable_http_shutdown_with_open_stream() -> Result<()> {
+    let (addr, ct, task) = spawn_server(TransportMode::StreamableHttp).await?;
+    let client = reqwest::Client::new();
+    let url = format!("http://{}/mcp", addr);
+
+    let response = client
+        .post(&url)
+        .header("Accept", "application/json, text/event-stream")
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2024-11-05",
+                "capabilities": {},
+                "clientInfo": { "name": "transport-test", "version": "0.1.0" }
+            }
+        }))
+        .send()
+        .await?;
+    let session_id = response.headers()["mcp-session-id"].to_str()?.to_string();
+    response.text().await?;
+    client
+        .post(&url)
+        .header("Accept", "application/json, text/event-stream")
+        .header("mcp-session-id", &session_id)
+        .json(&serde_json::json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }))
+        .send()
+        .await?;
+
+    // Standalone SSE stream the client keeps open
+    let stream = client
+        .get(&url)
+        .header("Accept", "text/event-stream")
+        .header("mcp-session-id", &session_id)
+        .send()
+        .await?;
+    assert_eq!(stream.status(), 200);
+
+    ct.cancel();
+    tokio::time::timeout(Duration::from_secs(5), task)
+        .await
+        .expect("shutdown must not wait for the open stream")??;
+    drop(stream);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sse_health_endpoint() -> Result<()> {
+    let (addr, ct, task) = spawn_server(TransportMode::Sse).await?;
+
+    let response = reqwest::get(format!("http://{}/health", addr)).await?;
+    assert_eq!(response.status(), 200);
+
+    let body: serde_json::Value = response.json().await?;
+    assert_eq!(body["transport"], "sse");
+
+    ct.cancel();
+    task.await??;
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_serve_http_rejects_stdio() -> Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let result = serve_http(
+        ArgocdMcpHandler::new(),
+        TransportMode::Stdio,
+        listener,
+        CancellationToken::new(),
+    )
+    .await;
+
+    assert!(result.is_err());
+    Ok(())
+}
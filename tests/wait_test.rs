@@ -0,0 +1,254 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::models::{WaitConditions, WaitOutcome, WaitProgress};
+
+fn app(sync: &str, health: &str, operation: serde_json::Value) -> serde_json::Value {
+    json!({
+        "metadata": { "name": "guestbook", "namespace": "argocd" },
+        "spec": {
+            "project": "default",
+            "source": { "repoURL": "https://github.com/argoproj/argocd-example-apps", "path": "guestbook" },
+            "destination": { "server": "https://kubernetes.default.svc", "namespace": "guestbook" }
+        },
+        "status": {
+            "sync": { "status": sync, "revision": "abc123" },
+            "health": { "status": health },
+            "operationState": operation
+        }
+    })
+}
+
+async fn mount_sequence(mock_server: &MockServer, responses: Vec<(serde_json::Value, u64)>) {
+    // Earlier mounts take precedence until they have answered their share of requests
+    for (body, times) in responses {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/applications/guestbook"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .up_to_n_times(times)
+            .mount(mock_server)
+            .await;
+    }
+}
+
+async fn wait(
+    client: &ArgocdClient,
+    conditions: &WaitConditions,
+    timeout: Duration,
+    progress: &mut Vec<WaitProgress>,
+) -> Result<argocd_mcp_server::models::ApplicationWaitSummary> {
+    client
+        .wait_for_application(
+            "guestbook".to_string(),
+            None,
+            None,
+            conditions,
+            timeout,
+            Duration::from_millis(10),
+            |p| {
+                progress.push(p);
+                std::future::ready(())
+            },
+        )
+        .await
+}
+
+#[tokio::test]
+async fn test_wait_until_synced_and_healthy() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_sequence(
+        &mock_server,
+        vec![
+            (
+                app(
+                    "OutOfSync",
+                    "Progressing",
+                    json!({ "phase": "Running", "startedAt": "2026-10-17T10:00:00Z" }),
+                ),
+                2,
+            ),
+            (
+                app(
+                    "Synced",
+                    "Healthy",
+                    json!({ "phase": "Succeeded", "startedAt": "2026-10-17T10:00:00Z" }),
+                ),
+                10,
+            ),
+        ],
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let conditions = WaitConditions::new(false, false, false);
+    let mut progress = Vec::new();
+    let summary = wait(&client, &conditions, Duration::from_secs(10), &mut progress).await?;
+
+    assert_eq!(summary.outcome, WaitOutcome::Succeeded);
+    assert_eq!(summary.waited_for, ["sync", "health", "operation"]);
+    assert_eq!(summary.polls, 3);
+    assert!(summary.unmet.is_empty());
+    assert_eq!(summary.health_status.as_deref(), Some("Healthy"));
+
+    assert_eq!(progress.len(), 3);
+    assert_eq!(
+        progress[0].unmet,
+        [
+            "sync is OutOfSync",
+            "health is Progressing",
+            "operation is Running"
+        ]
+    );
+    assert!(progress[2].unmet.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wait_stops_when_operation_fails() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_sequence(
+        &mock_server,
+        vec![(
+            app(
+                "OutOfSync",
+                "Degraded",
+                json!({
+                    "phase": "Failed",
+                    "message": "one or more synchronization tasks completed unsuccessfully",
+                    "startedAt": "2026-10-17T10:00:00Z",
+                    "syncResult": {
+                        "resources": [{
+                            "group": "batch", "version": "v1", "kind": "Job", "namespace": "guestbook",
+                            "name": "migrate", "hookType": "PreSync", "hookPhase": "Failed",
+                            "message": "Job has reached the specified backoff limit"
+                        }]
+                    }
+                }),
+            ),
+            10,
+        )],
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let conditions = WaitConditions::new(true, true, true);
+    let mut progress = Vec::new();
+    let summary = wait(&client, &conditions, Duration::from_secs(10), &mut progress).await?;
+
+    assert_eq!(summary.outcome, WaitOutcome::OperationFailed);
+    assert_eq!(summary.polls, 1);
+    let operation = summary.operation.expect("operation is reported");
+    assert_eq!(operation.resource_results[0].name, "migrate");
+    assert!(operation.resource_results[0].is_failure());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wait_times_out() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_sequence(
+        &mock_server,
+        vec![(app("Synced", "Progressing", serde_json::Value::Null), 100)],
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    // Only health is awaited, so the sync and missing operation do not matter
+    let conditions = WaitConditions::new(false, true, false);
+    let mut progress = Vec::new();
+    let summary = wait(
+        &client,
+        &conditions,
+        Duration::from_millis(50),
+        &mut progress,
+    )
+    .await?;
+
+    assert_eq!(summary.outcome, WaitOutcome::TimedOut);
+    assert_eq!(summary.waited_for, ["health"]);
+    assert_eq!(summary.unmet, ["health is Progressing"]);
+    assert!(summary.polls >= 2);
+    assert_eq!(progress.len() as u32, summary.polls);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wait_skips_operation_from_before_the_request() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    let previous = json!({ "phase": "Succeeded", "startedAt": "2026-10-17T09:00:00Z" });
+    mount_sequence(
+        &mock_server,
+        vec![
+            // The controller has not picked up the new sync yet
+            (app("Synced", "Healthy", previous), 2),
+            (
+                app(
+                    "Synced",
+                    "Healthy",
+                    json!({ "phase": "Succeeded", "startedAt": "2026-10-17T10:00:00Z" }),
+                ),
+                10,
+            ),
+        ],
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let conditions = WaitConditions {
+        previous_operation_started_at: Some("2026-10-17T09:00:00Z".to_string()),
+        ..WaitConditions::new(false, false, true)
+    };
+    let mut progress = Vec::new();
+    let summary = wait(&client, &conditions, Duration::from_secs(10), &mut progress).await?;
+
+    assert_eq!(summary.outcome, WaitOutcome::Succeeded);
+    assert_eq!(summary.polls, 3);
+    assert_eq!(progress[0].unmet, ["operation has not started"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wait_after_request_treats_missing_operation_as_pending() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_sequence(
+        &mock_server,
+        vec![
+            // First sync of the application: no operation has ever been recorded
+            (app("OutOfSync", "Healthy", serde_json::Value::Null), 2),
+            (
+                app(
+                    "Synced",
+                    "Healthy",
+                    json!({ "phase": "Succeeded", "startedAt": "2026-10-17T10:00:00Z" }),
+                ),
+                10,
+            ),
+        ],
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let conditions = WaitConditions {
+        operation_requested: true,
+        ..WaitConditions::new(false, false, true)
+    };
+    let mut progress = Vec::new();
+    let summary = wait(&client, &conditions, Duration::from_secs(10), &mut progress).await?;
+
+    assert_eq!(summary.outcome, WaitOutcome::Succeeded);
+    assert_eq!(summary.polls, 3);
+    assert_eq!(progress[0].unmet, ["operation has not started"]);
+
+    Ok(())
+}
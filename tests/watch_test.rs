@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::ArgocdClient;
+use argocd_mcp_server::error::ArgocdError;
+use argocd_mcp_server::watch::{WatchHub, WatchKey, WatchSnapshot};
+
+fn app(sync: &str, health: &str) -> serde_json::Value {
+    json!({
+        "metadata": { "name": "guestbook", "namespace": "argocd", "resourceVersion": "100" },
+        "spec": {
+            "project": "default",
+            "source": { "repoURL": "https://github.com/argoproj/argocd-example-apps", "path": "guestbook" },
+            "destination": { "server": "https://kubernetes.default.svc", "namespace": "guestbook" }
+        },
+        "status": {
+            "sync": { "status": sync, "revision": "abc123" },
+            "health": { "status": health }
+        }
+    })
+}
+
+/// Server-sent events body with one `data:` line per message
+fn sse(messages: &[serde_json::Value]) -> String {
+    messages
+        .iter()
+        .map(|message| format!("data: {}\n\n", message))
+        .collect()
+}
+
+fn event(event_type: &str, application: serde_json::Value) -> serde_json::Value {
+    json!({ "result": { "type": event_type, "application": application } })
+}
+
+#[tokio::test]
+async fn test_watch_applications_stream() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    // Newline-delimited JSON, as sent without an event-stream Accept header
+    let body = [
+        event("ADDED", app("OutOfSync", "Progressing")).to_string(),
+        event("MODIFIED", app("Synced", "Healthy")).to_string(),
+        event("DELETED", app("Synced", "Healthy")).to_string(),
+    ]
+    .join("\n");
+    Mock::given(method("GET"))
+        .and(path("/api/v1/stream/applications"))
+        .and(query_param("name", "guestbook"))
+        .and(query_param("projects", "default"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/json"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let mut stream = client
+        .watch_applications(
+            Some("guestbook".to_string()),
+            None,
+            Some(vec!["default".to_string()]),
+            None,
+        )
+        .await?;
+
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await? {
+        let sync = event.application.status.unwrap().sync.unwrap().status;
+        events.push((event.event_type, sync));
+    }
+    assert_eq!(
+        events,
+        [
+            ("ADDED".to_string(), "OutOfSync".to_string()),
+            ("MODIFIED".to_string(), "Synced".to_string()),
+            ("DELETED".to_string(), "Synced".to_string()),
+        ]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_watch_stream_error() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let body = sse(&[json!({
+        "error": {
+            "grpc_code": 7,
+            "http_code": 403,
+            "message": "permission denied: applications, get, default/guestbook",
+            "http_status": "Forbidden"
+        }
+    })]);
+    Mock::given(method("GET"))
+        .and(path("/api/v1/stream/applications/guestbook/resource-tree"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let mut stream = client
+        .watch_resource_tree("guestbook".to_string(), None, None)
+        .await?;
+    let error = stream.next().await.unwrap_err();
+
+    assert!(matches!(
+        ArgocdError::from_anyhow(&error),
+        Some(ArgocdError::PermissionDenied(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hub_notifies_subscribers_of_changes() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    // The repeated event changes nothing and must not be reported
+    let body = sse(&[
+        event("ADDED", app("OutOfSync", "Progressing")),
+        event("MODIFIED", app("OutOfSync", "Progressing")),
+        event("MODIFIED", app("Synced", "Healthy")),
+    ]);
+    Mock::given(method("GET"))
+        .and(path("/api/v1/stream/applications"))
+        .and(query_param("name", "guestbook"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let hub = WatchHub::default();
+    let key = WatchKey::parse("argocd://default/applications/guestbook").unwrap();
+    let (updates, mut changed) = tokio::sync::mpsc::unbounded_channel();
+    hub.subscribe(key.clone(), client, 1, updates);
+
+    let uri = tokio::time::timeout(Duration::from_secs(5), changed.recv())
+        .await?
+        .expect("a change is reported");
+    assert_eq!(uri, "argocd://default/applications/guestbook");
+    assert!(changed.try_recv().is_err());
+
+    let Some(WatchSnapshot::Application(cached)) = hub.snapshot(&key) else {
+        panic!("application is cached");
+    };
+    let status = cached.status.unwrap();
+    assert_eq!(status.sync.unwrap().status, "Synced");
+    assert_eq!(status.health.unwrap().status, "Healthy");
+
+    assert_eq!(hub.subscriptions(1), std::slice::from_ref(&key));
+    assert!(hub.unsubscribe(&key, 1));
+    assert!(hub.subscriptions(1).is_empty());
+    assert!(hub.snapshot(&key).is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_hub_caches_resource_tree() -> Result<()> {
+    let mock_server = MockServer::start().await;
+
+    let body = sse(&[json!({
+        "result": {
+            "nodes": [
+                {
+                    "group": "apps", "version": "v1", "kind": "Deployment", "namespace": "guestbook",
+                    "name": "guestbook-ui", "uid": "1", "health": { "status": "Healthy" }
+                },
+                {
+                    "version": "v1", "kind": "Pod", "namespace": "guestbook", "name": "guestbook-ui-abc",
+                    "uid": "2", "health": { "status": "Degraded" }
+                }
+            ]
+        }
+    })]);
+    Mock::given(method("GET"))
+        .and(path("/api/v1/stream/applications/guestbook/resource-tree"))
+        .and(query_param("appNamespace", "team-a"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&mock_server)
+        .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let hub = WatchHub::default();
+    let key = WatchKey::parse(
+        "argocd://default/applications/guestbook/resource-tree?appNamespace=team-a",
+    )
+    .unwrap();
+    let (updates, _changed) = tokio::sync::mpsc::unbounded_channel();
+    hub.subscribe(key.clone(), client, 1, updates);
+
+    let snapshot = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            if let Some(snapshot) = hub.snapshot(&key) {
+                return snapshot;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await?;
+
+    let rendered = snapshot.render();
+    assert_eq!(rendered["total_nodes"], 2);
+    assert_eq!(rendered["health_summary"]["Degraded"], 1);
+
+    Ok(())
+}
@@ -0,0 +1,332 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+use argocd_mcp_server::argocd_client::{ArgocdClient, ClientOptions};
+use argocd_mcp_server::error::ArgocdError;
+use argocd_mcp_server::models::{PodLogsQuery, ReplicaLogState};
+use argocd_mcp_server::retry::{track_retries, RetryPolicy};
+
+/// Deployment `api` owning a ReplicaSet with the given pods and their health
+fn tree(pods: &[(&str, &str)]) -> serde_json::Value {
+    let mut nodes = vec![
+        json!({
+            "group": "apps", "version": "v1", "kind": "Deployment", "namespace": "shop",
+            "name": "api", "uid": "deploy-1"
+        }),
+        json!({
+            "group": "apps", "version": "v1", "kind": "ReplicaSet", "namespace": "shop",
+            "name": "api-7d9f", "uid": "rs-1",
+            "parentRefs": [{ "group": "apps", "kind": "Deployment", "namespace": "shop", "name": "api", "uid": "deploy-1" }]
+        }),
+        // Not part of the workload
+        json!({
+            "version": "v1", "kind": "Pod", "namespace": "shop", "name": "worker-1", "uid": "pod-w"
+        }),
+    ];
+    for (name, health) in pods {
+        nodes.push(json!({
+            "version": "v1", "kind": "Pod", "namespace": "shop", "name": name, "uid": format!("pod-{}", name),
+            "health": { "status": health },
+            "parentRefs": [{ "group": "apps", "kind": "ReplicaSet", "namespace": "shop", "name": "api-7d9f", "uid": "rs-1" }]
+        }));
+    }
+    json!({ "nodes": nodes })
+}
+
+/// NDJSON log body for a pod; each line is (seconds past 10:00, content)
+fn logs(pod: &str, lines: &[(u32, &str)]) -> String {
+    lines
+        .iter()
+        .map(|(second, content)| {
+            json!({
+                "result": {
+                    "content": content,
+                    "podName": pod,
+                    "timeStampStr": format!("2026-10-17T10:00:{:02}Z", second)
+                }
+            })
+            .to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn mount_tree(mock_server: &MockServer, pods: &[(&str, &str)]) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/shop/resource-tree"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(tree(pods)))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_logs(mock_server: &MockServer, pod: &str, response: ResponseTemplate) {
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/shop/logs"))
+        .and(query_param("podName", pod))
+        .and(query_param("namespace", "shop"))
+        .respond_with(response)
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn query() -> PodLogsQuery {
+    PodLogsQuery {
+        application_name: "shop".to_string(),
+        kind: Some("Deployment".to_string()),
+        resource_name: Some("api".to_string()),
+        tail_lines: Some(100),
+        ..PodLogsQuery::default()
+    }
+}
+
+#[tokio::test]
+async fn test_workload_logs_merges_replicas() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_tree(
+        &mock_server,
+        &[
+            ("api-a", "Healthy"),
+            ("api-b", "Degraded"),
+            ("api-c", "Healthy"),
+        ],
+    )
+    .await;
+    mount_logs(
+        &mock_server,
+        "api-a",
+        ResponseTemplate::new(200).set_body_string(logs(
+            "api-a",
+            &[(1, "INFO listening on :8080"), (4, "INFO GET /health 200")],
+        )),
+    )
+    .await;
+    mount_logs(
+        &mock_server,
+        "api-b",
+        ResponseTemplate::new(200).set_body_string(logs(
+            "api-b",
+            &[
+                (2, "INFO listening on :8080"),
+                (3, "ERROR connection refused: redis:6379"),
+            ],
+        )),
+    )
+    .await;
+    mount_logs(
+        &mock_server,
+        "api-c",
+        ResponseTemplate::new(200).set_body_string(logs("api-c", &[(0, "WARN cache is cold")])),
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client.workload_logs(query(), false).await?;
+
+    assert_eq!(summary.total_lines, 5);
+    assert_eq!(summary.error_count, 1);
+
+    // Lines from all pods are interleaved by timestamp
+    let order: Vec<&str> = summary
+        .log_entries
+        .iter()
+        .map(|e| e.pod_name.as_deref().unwrap())
+        .collect();
+    assert_eq!(order, ["api-c", "api-a", "api-b", "api-b", "api-a"]);
+
+    let workload = summary.workload.expect("workload breakdown is set");
+    assert_eq!(workload.kind, "Deployment");
+    assert_eq!(workload.pods_found, 3);
+    assert_eq!(workload.lines_received, 5);
+    let replicas: Vec<(&str, ReplicaLogState)> = workload
+        .replicas
+        .iter()
+        .map(|r| (r.pod_name.as_str(), r.state))
+        .collect();
+    assert_eq!(
+        replicas,
+        [
+            ("api-b", ReplicaLogState::Erroring),
+            ("api-c", ReplicaLogState::Warning),
+            ("api-a", ReplicaLogState::Healthy),
+        ]
+    );
+    assert_eq!(
+        workload.replicas[0].health_status.as_deref(),
+        Some("Degraded")
+    );
+    assert_eq!(
+        workload.replicas[0].last_error.as_deref(),
+        Some("ERROR connection refused: redis:6379")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_workload_logs_errors_only_keeps_replica_counts() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server, &[("api-a", "Healthy"), ("api-b", "Healthy")]).await;
+    mount_logs(
+        &mock_server,
+        "api-a",
+        ResponseTemplate::new(200)
+            .set_body_string(logs("api-a", &[(1, "INFO ready"), (2, "INFO GET / 200")])),
+    )
+    .await;
+    mount_logs(
+        &mock_server,
+        "api-b",
+        ResponseTemplate::new(200).set_body_string(logs(
+            "api-b",
+            &[(1, "INFO ready"), (3, "FATAL out of memory")],
+        )),
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client.workload_logs(query(), true).await?;
+
+    assert!(summary.filtered);
+    assert_eq!(summary.total_lines, 1);
+    assert_eq!(summary.log_entries[0].pod_name.as_deref(), Some("api-b"));
+
+    let workload = summary.workload.expect("workload breakdown is set");
+    assert_eq!(workload.lines_received, 4);
+    assert_eq!(workload.replicas[1].pod_name, "api-a");
+    assert_eq!(workload.replicas[1].total_lines, 2);
+    assert_eq!(workload.replicas[1].state, ReplicaLogState::Healthy);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_workload_logs_reports_unavailable_pod() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server, &[("api-a", "Healthy"), ("api-b", "Healthy")]).await;
+    mount_logs(
+        &mock_server,
+        "api-a",
+        ResponseTemplate::new(200).set_body_string(logs("api-a", &[(1, "INFO ready")])),
+    )
+    .await;
+    mount_logs(
+        &mock_server,
+        "api-b",
+        ResponseTemplate::new(403).set_body_json(json!({
+            "error": "permission denied", "code": 7, "message": "permission denied: logs, get, default/shop"
+        })),
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let summary = client.workload_logs(query(), false).await?;
+
+    assert_eq!(summary.total_lines, 1);
+    let workload = summary.workload.expect("workload breakdown is set");
+    let unavailable = &workload.replicas[1];
+    assert_eq!(unavailable.pod_name, "api-b");
+    assert_eq!(unavailable.state, ReplicaLogState::Unavailable);
+    assert!(unavailable
+        .fetch_error
+        .as_deref()
+        .unwrap()
+        .contains("permission denied"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_workload_logs_fails_when_no_pod_can_be_read() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server, &[("api-a", "Healthy")]).await;
+    mount_logs(
+        &mock_server,
+        "api-a",
+        ResponseTemplate::new(403).set_body_json(json!({
+            "error": "permission denied", "code": 7, "message": "permission denied: logs, get, default/shop"
+        })),
+    )
+    .await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let error = client.workload_logs(query(), false).await.unwrap_err();
+
+    assert!(matches!(
+        ArgocdError::from_anyhow(&error),
+        Some(ArgocdError::PermissionDenied(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_workload_logs_without_pods() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server, &[]).await;
+
+    let client = ArgocdClient::new(mock_server.uri(), "test-token".to_string())?;
+    let error = client.workload_logs(query(), false).await.unwrap_err();
+
+    assert!(matches!(
+        ArgocdError::from_anyhow(&error),
+        Some(ArgocdError::NotFound(_))
+    ));
+    assert!(error.to_string().contains("no pods of Deployment 'api'"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_workload_logs_reports_pod_retries() -> Result<()> {
+    let mock_server = MockServer::start().await;
+    mount_tree(&mock_server, &[("api-a", "Healthy"), ("api-b", "Healthy")]).await;
+    // The first request for api-a hits an unavailable server
+    Mock::given(method("GET"))
+        .and(path("/api/v1/applications/shop/logs"))
+        .and(query_param("podName", "api-a"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    mount_logs(
+        &mock_server,
+        "api-a",
+        ResponseTemplate::new(200).set_body_string(logs("api-a", &[(1, "INFO ready")])),
+    )
+    .await;
+    mount_logs(
+        &mock_server,
+        "api-b",
+        ResponseTemplate::new(200).set_body_string(logs("api-b", &[(2, "INFO ready")])),
+    )
+    .await;
+
+    let client = ArgocdClient::with_options(
+        mock_server.uri(),
+        "test-token".to_string(),
+        ClientOptions {
+            retry: RetryPolicy {
+                max_retries: 2,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(5),
+                retry_mutations: false,
+            },
+            ..ClientOptions::default()
+        },
+    )?;
+    let (result, retries) = track_retries(client.workload_logs(query(), false)).await;
+
+    assert_eq!(result?.total_lines, 2);
+    assert_eq!(retries.len(), 1);
+    assert_eq!(retries[0].operation, "pod_logs");
+    assert_eq!(retries[0].reason, "unavailable");
+
+    Ok(())
+}